@@ -0,0 +1,309 @@
+// Goal explorer: renders the `(((GOAL EXPLORER)))` directive as a table of goals that
+// readers can search, filter, and sort. The index is embedded in the page as JSON by
+// mdbook-goals; see `rust_project_goals::explorer`. Installed by `mdbook-goals install`.
+(function () {
+    "use strict";
+
+    const STYLE = `
+.goal-explorer-controls { display: flex; flex-wrap: wrap; gap: 0.5em 1em; margin-bottom: 1em; }
+.goal-explorer-controls label { display: flex; flex-direction: column; font-size: 0.85em; }
+.goal-explorer-controls input, .goal-explorer-controls select {
+    background: var(--bg); color: var(--fg); border: 1px solid var(--table-border-color);
+    padding: 0.2em 0.4em; font-size: 1em;
+}
+.goal-explorer-controls .goal-explorer-search { flex: 1 1 100%; }
+.goal-explorer-count { font-size: 0.85em; color: var(--sidebar-fg); margin-bottom: 0.5em; }
+.goal-explorer table th[data-sort] { cursor: pointer; user-select: none; }
+.goal-explorer table th[data-sort]::after { content: " \\2195"; opacity: 0.4; }
+.goal-explorer table th[data-sort].ascending::after { content: " \\2191"; opacity: 1; }
+.goal-explorer table th[data-sort].descending::after { content: " \\2193"; opacity: 1; }
+.goal-explorer table td ul { margin: 0; padding-left: 1em; }
+`;
+
+    // Filters on team asks. When several are set, a single ask must match all of them,
+    // so "lang / Large / no champion" finds goals whose lang ask is Large and unchampioned.
+    const ASK_FILTERS = ["team", "level", "champion"];
+
+    function unique(values) {
+        return Array.from(new Set(values.filter((v) => v))).sort((a, b) =>
+            a.localeCompare(b)
+        );
+    }
+
+    function element(tag, attributes, children) {
+        const el = document.createElement(tag);
+        for (const [key, value] of Object.entries(attributes || {})) {
+            if (key === "text") {
+                el.textContent = value;
+            } else {
+                el.setAttribute(key, value);
+            }
+        }
+        for (const child of children || []) {
+            el.append(child);
+        }
+        return el;
+    }
+
+    function select(name, label, options) {
+        const input = element("select", { name }, [
+            element("option", { value: "", text: "Any" }),
+            ...options.map(([value, text]) => element("option", { value, text })),
+        ]);
+        return [element("label", {}, [label, input]), input];
+    }
+
+    function progressFraction(goal) {
+        const progress = goal.progress;
+        if (!progress) {
+            return -1;
+        }
+        if (progress.Tracked) {
+            const { completed, total } = progress.Tracked;
+            return total === 0 ? 0 : completed / total;
+        }
+        if (progress.Binary) {
+            return progress.Binary.is_closed ? 1 : 0;
+        }
+        return -1;
+    }
+
+    function progressText(goal) {
+        const progress = goal.progress;
+        if (!progress) {
+            return "";
+        }
+        if (progress.Tracked) {
+            return `${progress.Tracked.completed}/${progress.Tracked.total}`;
+        }
+        if (progress.Binary) {
+            return progress.Binary.is_closed ? "Done" : "In progress";
+        }
+        return "";
+    }
+
+    function askMatches(ask, filters) {
+        if (filters.team && ask.team !== filters.team) {
+            return false;
+        }
+        if (filters.level && ask.level !== filters.level) {
+            return false;
+        }
+        if (filters.champion === "has" && !ask.champion) {
+            return false;
+        }
+        if (filters.champion === "none" && ask.champion) {
+            return false;
+        }
+        return true;
+    }
+
+    function goalMatches(goal, filters) {
+        if (filters.status && goal.status !== filters.status) {
+            return false;
+        }
+        if (filters.roadmap && !goal.roadmaps.includes(filters.roadmap)) {
+            return false;
+        }
+        if (filters.need && !goal.needs.includes(filters.need)) {
+            return false;
+        }
+        if (filters.funding && goal.funding !== filters.funding) {
+            return false;
+        }
+        if (ASK_FILTERS.some((f) => filters[f])) {
+            if (!goal.team_asks.some((ask) => askMatches(ask, filters))) {
+                return false;
+            }
+        }
+        if (filters.search) {
+            const haystack = [
+                goal.title,
+                ...goal.point_of_contact,
+                ...Object.values(goal.champions),
+                ...goal.team_asks.map((ask) => ask.team),
+                ...goal.roadmaps,
+                ...goal.needs,
+            ]
+                .join(" ")
+                .toLowerCase();
+            if (!filters.search.every((word) => haystack.includes(word))) {
+                return false;
+            }
+        }
+        return true;
+    }
+
+    function render(container, index) {
+        const levels = index.support_levels;
+        const goals = index.goals;
+
+        const search = element("input", {
+            type: "search",
+            placeholder: "Search titles, people, teams, themes…",
+        });
+        const [statusLabel, status] = select(
+            "status",
+            "Status",
+            unique(goals.map((g) => g.status)).map((s) => [s, s])
+        );
+        const [teamLabel, team] = select(
+            "team",
+            "Team",
+            unique(goals.flatMap((g) => g.team_asks.map((a) => a.team))).map((t) => [t, t])
+        );
+        const [levelLabel, level] = select(
+            "level",
+            "Support level",
+            levels.map((l) => [l, l])
+        );
+        const [championLabel, champion] = select("champion", "Champion", [
+            ["has", "Has a champion"],
+            ["none", "No champion"],
+        ]);
+        const [roadmapLabel, roadmap] = select(
+            "roadmap",
+            "Roadmap",
+            unique(goals.flatMap((g) => g.roadmaps)).map((r) => [r, r])
+        );
+        const [needLabel, need] = select(
+            "need",
+            "Needs",
+            unique(goals.flatMap((g) => g.needs)).map((n) => [n, n])
+        );
+        const [fundingLabel, funding] = select(
+            "funding",
+            "Funding",
+            unique(goals.map((g) => g.funding)).map((f) => [f, f])
+        );
+
+        const inputs = { status, team, level, champion, roadmap, need, funding };
+        const controls = element("div", { class: "goal-explorer-controls" }, [
+            element("label", { class: "goal-explorer-search" }, ["Search", search]),
+            statusLabel,
+            teamLabel,
+            levelLabel,
+            championLabel,
+            roadmapLabel,
+            needLabel,
+            fundingLabel,
+        ]);
+
+        const columns = [
+            { name: "Goal", key: (g) => g.title.toLowerCase() },
+            { name: "Point of contact", key: (g) => g.point_of_contact.join(" ").toLowerCase() },
+            { name: "Team asks" },
+            {
+                name: "Size",
+                key: (g) => (g.support_level ? levels.indexOf(g.support_level) : -1),
+            },
+            { name: "Progress", key: progressFraction },
+        ];
+        const headerCells = columns.map((column, i) =>
+            element("th", column.key ? { "data-sort": i, text: column.name } : { text: column.name })
+        );
+        const body = element("tbody");
+        const table = element("table", {}, [
+            element("thead", {}, [element("tr", {}, headerCells)]),
+            body,
+        ]);
+        const count = element("div", { class: "goal-explorer-count" });
+
+        let sortColumn = null;
+        let sortDirection = 1;
+
+        function row(goal) {
+            const title = element("a", { href: goal.url, text: goal.title });
+            const asks = element(
+                "ul",
+                {},
+                goal.team_asks.map((ask) => {
+                    let text = ask.team;
+                    if (ask.level) {
+                        text += ` (${ask.level})`;
+                    } else if (ask.ask) {
+                        text += `: ${ask.ask}`;
+                    }
+                    if (ask.champion) {
+                        text += ` — ${ask.champion}`;
+                    }
+                    return element("li", { text });
+                })
+            );
+            return element("tr", {}, [
+                element("td", {}, [title]),
+                element("td", { text: goal.point_of_contact.join(", ") }),
+                element("td", {}, [asks]),
+                element("td", { text: goal.support_level || "" }),
+                element("td", { text: progressText(goal) }),
+            ]);
+        }
+
+        function update() {
+            const filters = { search: search.value.toLowerCase().split(/\s+/).filter((w) => w) };
+            for (const [name, input] of Object.entries(inputs)) {
+                filters[name] = input.value;
+            }
+
+            const shown = goals.filter((g) => goalMatches(g, filters));
+            if (sortColumn !== null) {
+                const key = columns[sortColumn].key;
+                shown.sort((a, b) => {
+                    const ka = key(a);
+                    const kb = key(b);
+                    return (ka < kb ? -1 : ka > kb ? 1 : 0) * sortDirection;
+                });
+            }
+
+            body.replaceChildren(...shown.map(row));
+            count.textContent = `Showing ${shown.length} of ${goals.length} goals`;
+            headerCells.forEach((cell, i) => {
+                cell.classList.toggle("ascending", i === sortColumn && sortDirection === 1);
+                cell.classList.toggle("descending", i === sortColumn && sortDirection === -1);
+            });
+        }
+
+        headerCells.forEach((cell, i) => {
+            if (!columns[i].key) {
+                return;
+            }
+            cell.addEventListener("click", () => {
+                if (sortColumn === i) {
+                    sortDirection = -sortDirection;
+                } else {
+                    sortColumn = i;
+                    sortDirection = 1;
+                }
+                update();
+            });
+        });
+        search.addEventListener("input", update);
+        for (const input of Object.values(inputs)) {
+            input.addEventListener("change", update);
+        }
+
+        container.replaceChildren(controls, count, table);
+        update();
+    }
+
+    function init() {
+        const containers = document.querySelectorAll(".goal-explorer");
+        if (containers.length === 0) {
+            return;
+        }
+        document.head.append(element("style", { text: STYLE }));
+        for (const container of containers) {
+            const script = container.querySelector("script.goal-explorer-index");
+            if (!script) {
+                continue;
+            }
+            render(container, JSON.parse(script.textContent));
+        }
+    }
+
+    if (document.readyState === "loading") {
+        document.addEventListener("DOMContentLoaded", init);
+    } else {
+        init();
+    }
+})();
@@ -0,0 +1,497 @@
+//! `mdbook-goals check-links`: runs the preprocessor over the book in-process and checks
+//! the links of the pages it produces, without rendering any HTML.
+//!
+//! Checked are links to other pages (and their anchors), links to other files in `src`,
+//! reference-style links without a definition, and the redirects of `book.toml`.
+//! External links are only fetched with `--online`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Component, Path, PathBuf};
+
+use mdbook_driver::MDBook;
+use mdbook_preprocessor::book::BookItem;
+use mdbook_preprocessor::{Preprocessor, PreprocessorContext};
+use pulldown_cmark::{BrokenLink, Event, LinkType, Options, Parser, Tag, TagEnd};
+use regex::Regex;
+use rust_project_goals::util;
+
+use crate::goal_preprocessor::GoalPreprocessor;
+
+/// The extensions mdbook enables.
+const OPTIONS: Options = Options::ENABLE_TABLES
+    .union(Options::ENABLE_FOOTNOTES)
+    .union(Options::ENABLE_STRIKETHROUGH)
+    .union(Options::ENABLE_TASKLISTS)
+    .union(Options::ENABLE_HEADING_ATTRIBUTES);
+
+pub fn check_links(online: bool) -> anyhow::Result<()> {
+    let root = std::env::current_dir()?;
+    let mdbook = MDBook::load(&root)?;
+    let src = root.join(&mdbook.config.book.src);
+
+    let ctx = PreprocessorContext::new(root.clone(), mdbook.config.clone(), "html".to_string());
+    let book = GoalPreprocessor.run(&ctx, mdbook.book.clone())?;
+
+    // The preprocessed text of each page, keyed by its path relative to `src`.
+    let mut contents = BTreeMap::new();
+    collect_pages(&book.items, &mut contents);
+    let pages = Pages {
+        src: &src,
+        anchors: contents
+            .iter()
+            .map(|(path, content)| (path.clone(), anchors(content)))
+            .collect(),
+    };
+
+    let mut problems = vec![];
+    // External links, with the places they appear.
+    let mut external: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+    for (path, content) in &contents {
+        let file = src.join(path);
+        let source = std::fs::read_to_string(&file).unwrap_or_default();
+        let links = links(content);
+        for reference in links.undefined {
+            problems.push(format!(
+                "{}: no definition for the reference link `[{}]`",
+                src.join(path).display(),
+                reference
+            ));
+        }
+        for destination in links.destinations {
+            // Badges and the like are embedded in the page
+            if destination.starts_with("data:") {
+                continue;
+            }
+            if is_external(&destination) {
+                let location = location(&file, &source, &destination);
+                external.entry(destination).or_default().push(location);
+            } else if let Err(problem) = pages.check(path, &destination) {
+                problems.push(format!(
+                    "{}: broken link `{}`: {}",
+                    src.join(path).display(),
+                    destination,
+                    problem
+                ));
+            }
+        }
+    }
+
+    let book_toml = root.join("book.toml");
+    let book_toml_source = std::fs::read_to_string(&book_toml)?;
+    for (from, to) in redirects(&book_toml_source)? {
+        // Redirects are relative to the page they redirect from.
+        let from_page = PathBuf::from(from.trim_start_matches('/')).with_extension("md");
+        if is_external(&to) {
+            let location = location(&book_toml, &book_toml_source, &to);
+            external.entry(to).or_default().push(location);
+        } else if let Err(problem) = pages.check(&from_page, &to) {
+            problems.push(format!(
+                "{}: redirect from `{}` to `{}`: {}",
+                book_toml.display(),
+                from,
+                to,
+                problem
+            ));
+        }
+    }
+
+    if online {
+        let client = reqwest::blocking::Client::builder()
+            .user_agent("rust-project-goals")
+            .timeout(std::time::Duration::from_secs(30))
+            .build()?;
+        let urls: Vec<&String> = external.keys().collect();
+        let results = util::par_map(&urls, |url| fetch(&client, url));
+        for (url, result) in urls.into_iter().zip(results) {
+            if let Err(problem) = result {
+                problems.push(format!(
+                    "{}: broken external link `{url}`: {problem}",
+                    places(&external[url])
+                ));
+            }
+        }
+    } else {
+        eprintln!(
+            "{} external links not checked (pass `--online` to fetch them):",
+            external.len()
+        );
+        for (url, locations) in &external {
+            eprintln!("  {}: {url}", places(locations));
+        }
+    }
+
+    for problem in &problems {
+        eprintln!("{problem}");
+    }
+    if !problems.is_empty() {
+        anyhow::bail!("found {} broken links", problems.len());
+    }
+    Ok(())
+}
+
+/// Collect the content of every chapter with a file, keyed by its path relative to `src`.
+fn collect_pages(items: &[BookItem], contents: &mut BTreeMap<PathBuf, String>) {
+    for item in items {
+        if let BookItem::Chapter(chapter) = item {
+            if let Some(path) = &chapter.path {
+                contents.insert(path.clone(), chapter.content.clone());
+            }
+            collect_pages(&chapter.sub_items, contents);
+        }
+    }
+}
+
+/// The pages of the book and the anchors each one defines.
+struct Pages<'a> {
+    src: &'a Path,
+    anchors: BTreeMap<PathBuf, BTreeSet<String>>,
+}
+
+impl Pages<'_> {
+    /// Check a link to `destination` from the page at `from` (both relative to `src`).
+    fn check(&self, from: &Path, destination: &str) -> Result<(), String> {
+        let (target, anchor) = match destination.split_once('#') {
+            Some((target, anchor)) => (target, Some(anchor)),
+            None => (destination, None),
+        };
+        let target = target.replace("%20", " ");
+
+        let page = if target.is_empty() {
+            from.to_path_buf()
+        } else {
+            let dir = if target.starts_with('/') {
+                Path::new("")
+            } else {
+                from.parent().unwrap_or(Path::new(""))
+            };
+            let Some(path) = normalize(&dir.join(target.trim_start_matches('/'))) else {
+                return Err("it points outside of the book".to_string());
+            };
+
+            match self.page(&path) {
+                Some(page) => page,
+                None if is_page_link(&path) => {
+                    return Err(format!("there is no page `{}`", path.display()));
+                }
+                None if self.src.join(&path).exists() => return Ok(()),
+                None => return Err(format!("there is no file `{}`", path.display())),
+            }
+        };
+
+        let Some(anchor) = anchor.filter(|anchor| !anchor.is_empty()) else {
+            return Ok(());
+        };
+        let Some(anchors) = self.anchors.get(&page) else {
+            return Ok(());
+        };
+        if anchors.contains(anchor) || anchors.contains(&anchor.to_lowercase()) {
+            Ok(())
+        } else {
+            Err(format!(
+                "there is no heading or anchor `#{}` in `{}`",
+                anchor,
+                page.display()
+            ))
+        }
+    }
+
+    /// The page rendered at `path`: `foo.md` and `foo.html` are both the page of `foo.md`,
+    /// and a directory or its `index.html` is the page of its `README.md` (or `index.md`).
+    fn page(&self, path: &Path) -> Option<PathBuf> {
+        let is_index =
+            path.as_os_str().is_empty() || path.file_name() == Some("index.html".as_ref());
+        let candidates = if is_index {
+            let dir = if path.file_name() == Some("index.html".as_ref()) {
+                path.parent().unwrap_or(Path::new(""))
+            } else {
+                path
+            };
+            vec![dir.join("README.md"), dir.join("index.md")]
+        } else {
+            vec![path.with_extension("md")]
+        };
+
+        let has_page_extension = path.extension().is_none()
+            || path.extension() == Some("md".as_ref())
+            || path.extension() == Some("html".as_ref());
+        if !is_index && !has_page_extension {
+            return None;
+        }
+        candidates
+            .into_iter()
+            .find(|candidate| self.anchors.contains_key(candidate))
+    }
+}
+
+/// Whether `path` can only be a link to a page (rather than, say, an image).
+fn is_page_link(path: &Path) -> bool {
+    path.extension() == Some("md".as_ref()) || path.extension() == Some("html".as_ref())
+}
+
+/// Where the link to `destination` appears: `file:line` if it is written in `source`, the
+/// text of `file`, and just `file` if the plugin added it to the page.
+fn location(file: &Path, source: &str, destination: &str) -> String {
+    match source.find(destination) {
+        Some(offset) => format!(
+            "{}:{}",
+            file.display(),
+            source[..offset].matches('\n').count() + 1
+        ),
+        None => format!("{} (added by the plugin)", file.display()),
+    }
+}
+
+/// The first of `locations`, and how many others there are.
+fn places(locations: &[String]) -> String {
+    match locations {
+        [] => String::new(),
+        [location] => location.clone(),
+        [location, rest @ ..] => format!("{location} (and {} more)", rest.len()),
+    }
+}
+
+fn is_external(destination: &str) -> bool {
+    ["http://", "https://", "mailto:"]
+        .iter()
+        .any(|scheme| destination.starts_with(scheme))
+}
+
+/// Resolve `.` and `..` in `path` without touching the file system.
+/// Returns `None` if the path leaves the directory it is relative to.
+fn normalize(path: &Path) -> Option<PathBuf> {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    return None;
+                }
+            }
+            Component::Normal(name) => normalized.push(name),
+            Component::RootDir | Component::Prefix(_) => {}
+        }
+    }
+    Some(normalized)
+}
+
+/// The anchors a page defines: the ids mdbook gives its headings, plus any `id` or `name`
+/// attribute of its HTML.
+fn anchors(content: &str) -> BTreeSet<String> {
+    let html_id = Regex::new(r#"\b(?:id|name)\s*=\s*"([^"]*)""#).unwrap();
+
+    let mut anchors = BTreeSet::new();
+    let mut heading: Option<(Option<String>, String)> = None;
+    for event in Parser::new_ext(content, OPTIONS) {
+        match event {
+            Event::Start(Tag::Heading { id, .. }) => {
+                heading = Some((id.map(|id| id.to_string()), String::new()));
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some((id, text)) = heading.take() {
+                    let id = id.unwrap_or_else(|| unique_id(&normalize_id(&text), &anchors));
+                    anchors.insert(id);
+                }
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some((_, heading_text)) = &mut heading {
+                    heading_text.push_str(&text);
+                }
+            }
+            Event::Html(html) | Event::InlineHtml(html) => {
+                for captures in html_id.captures_iter(&html) {
+                    anchors.insert(captures[1].to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+    anchors
+}
+
+/// The id mdbook derives from the text of a heading.
+fn normalize_id(text: &str) -> String {
+    text.chars()
+        .filter_map(|c| {
+            if c.is_alphanumeric() || c == '_' || c == '-' {
+                Some(c.to_ascii_lowercase())
+            } else if c.is_whitespace() {
+                Some('-')
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Like mdbook, number repeated ids: `id`, `id-1`, `id-2`, ...
+fn unique_id(id: &str, existing: &BTreeSet<String>) -> String {
+    if !existing.contains(id) {
+        return id.to_string();
+    }
+    (1..)
+        .map(|n| format!("{id}-{n}"))
+        .find(|id| !existing.contains(id))
+        .unwrap()
+}
+
+/// The links of a page.
+struct Links {
+    /// Destinations of links and images.
+    destinations: Vec<String>,
+
+    /// References of `[text][reference]` and `[reference][]` links without a definition.
+    /// (A `[text]` without a definition is just text.)
+    undefined: Vec<String>,
+}
+
+fn links(content: &str) -> Links {
+    let html_link = Regex::new(r#"\b(?:href|src)\s*=\s*"([^"]*)""#).unwrap();
+
+    let mut undefined = vec![];
+    let parser = Parser::new_with_broken_link_callback(
+        content,
+        OPTIONS,
+        Some(|link: BrokenLink<'_>| {
+            if matches!(link.link_type, LinkType::Reference | LinkType::Collapsed) {
+                undefined.push(link.reference.to_string());
+            }
+            None
+        }),
+    );
+
+    let mut destinations = vec![];
+    for event in parser {
+        match event {
+            Event::Start(Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. }) => {
+                destinations.push(dest_url.to_string());
+            }
+            Event::Html(html) | Event::InlineHtml(html) => {
+                for captures in html_link.captures_iter(&html) {
+                    destinations.push(captures[1].to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Links {
+        destinations,
+        undefined,
+    }
+}
+
+/// The `[output.html.redirect]` entries of `book.toml`, given its text, from old page to new one.
+fn redirects(book_toml: &str) -> anyhow::Result<Vec<(String, String)>> {
+    let book: toml::Table = toml::from_str(book_toml)?;
+    let redirects = book
+        .get("output")
+        .and_then(|output| output.get("html"))
+        .and_then(|html| html.get("redirect"))
+        .and_then(|redirect| redirect.as_table());
+    Ok(redirects
+        .into_iter()
+        .flatten()
+        .filter_map(|(from, to)| Some((from.clone(), to.as_str()?.to_string())))
+        .collect())
+}
+
+/// Fetch `url`, falling back to `GET` for servers that don't support `HEAD`.
+fn fetch(client: &reqwest::blocking::Client, url: &str) -> Result<(), String> {
+    let status = match client.head(url).send() {
+        Ok(response) if response.status().is_success() => return Ok(()),
+        _ => client.get(url).send().map_err(|e| e.to_string())?.status(),
+    };
+    if status.is_success() {
+        Ok(())
+    } else {
+        Err(status.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pages(contents: &[(&str, &str)]) -> Pages<'static> {
+        Pages {
+            src: Path::new("/nonexistent"),
+            anchors: contents
+                .iter()
+                .map(|(path, content)| (PathBuf::from(path), anchors(content)))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn heading_anchors() {
+        let anchors = anchors(
+            "# The `Summary`\n\n## Notes\n\n## Notes\n\n## Custom {#custom-id}\n\n<a id=\"manual\"></a>\n",
+        );
+        let expected: BTreeSet<String> = ["the-summary", "notes", "notes-1", "custom-id", "manual"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(anchors, expected);
+    }
+
+    #[test]
+    fn page_links() {
+        let pages = pages(&[
+            ("README.md", "# Intro"),
+            ("2026/README.md", "# Goals"),
+            ("2026/foo.md", "# Foo\n\n## Summary"),
+        ]);
+        let from = Path::new("2026/foo.md");
+        assert_eq!(pages.check(from, "#summary"), Ok(()));
+        assert_eq!(pages.check(from, "./README.md#goals"), Ok(()));
+        assert_eq!(pages.check(from, "index.html"), Ok(()));
+        assert_eq!(pages.check(from, "../index.html#intro"), Ok(()));
+        assert_eq!(pages.check(from, "foo.html#foo"), Ok(()));
+        assert!(pages.check(from, "#motivation").is_err());
+        assert!(pages.check(from, "bar.md").is_err());
+        assert!(pages.check(from, "../../outside.md").is_err());
+        assert!(pages.check(from, "logo.png").is_err());
+    }
+
+    #[test]
+    fn external_link_locations() {
+        let file = Path::new("src/foo.md");
+        let source = "# Foo\n\nSee [the RFC](https://example.com/rfc).\n";
+        assert_eq!(
+            location(file, source, "https://example.com/rfc"),
+            "src/foo.md:3"
+        );
+        assert_eq!(
+            location(file, source, "https://github.com/rust-lang"),
+            "src/foo.md (added by the plugin)"
+        );
+        assert_eq!(
+            places(&[
+                "a.md:1".to_string(),
+                "b.md:2".to_string(),
+                "c.md".to_string()
+            ]),
+            "a.md:1 (and 2 more)"
+        );
+    }
+
+    #[test]
+    fn external_links() {
+        assert!(is_external("https://github.com/rust-lang/rust"));
+        assert!(is_external("http://example.com"));
+        assert!(is_external("mailto:someone@example.com"));
+        assert!(!is_external(
+            "data:image/svg+xml,%3Csvg%20xmlns%3D%22http%3A%2F%2F"
+        ));
+        assert!(!is_external("../README.md#https://"));
+    }
+
+    #[test]
+    fn undefined_references() {
+        let links = links("[a][], [b][c], [d], [e][]\n\n[e]: https://example.com\n");
+        assert_eq!(links.undefined, vec!["a".to_string(), "c".to_string()]);
+        assert_eq!(links.destinations, vec!["https://example.com".to_string()]);
+    }
+}
@@ -1,4 +1,5 @@
 use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Range;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
@@ -7,17 +8,20 @@ use chrono::{self, Datelike};
 use mdbook_preprocessor::book::{Book, BookItem, Chapter};
 use mdbook_preprocessor::{Preprocessor, PreprocessorContext};
 use regex::Regex;
-use rust_project_goals::config::{Configuration, GoalsConfig};
+use rust_project_goals::config::{describe_vocabulary, Configuration, GoalsConfig, Vocabulary};
+use rust_project_goals::explorer;
 use rust_project_goals::format_champions::format_champions;
 use rust_project_goals::format_team_ask::format_team_asks;
 use rust_project_goals::format_team_support::format_team_support;
 use rust_project_goals::markdown_processor::{MarkdownProcessor, MarkdownProcessorState};
+use rust_project_goals::opportunities::{self, Opportunity, OpportunityFilter};
+use rust_project_goals::table::{Cell, Table};
 use rust_project_goals::util;
-use rust_project_goals_cli::Order;
+use rust_project_goals_cli::updates::UpdatesFilter;
+use rust_project_goals_cli::{Order, UpdatesOptions};
 
-use rust_project_goals::spanned::Spanned;
 use rust_project_goals::{
-    goal::{self, GoalDocument, GoalSize, RoadmapDocument, TeamAsk, TeamInvolvement},
+    goal::{self, GoalDocument, RoadmapDocument, SupportLevel, TeamAsk, TeamInvolvement},
     re,
     team::TeamName,
 };
@@ -33,17 +37,6 @@ impl<T> IntoAnyhow<T> for rust_project_goals::spanned::Result<T> {
     }
 }
 
-/// Load goals configuration from book.toml using clean serde deserialization
-fn load_goals_config_from_book_toml(ctx: &PreprocessorContext) -> anyhow::Result<GoalsConfig> {
-    // Find book.toml in the source directory
-    let book_toml_path = ctx.root.join("book.toml");
-    if book_toml_path.exists() {
-        GoalsConfig::from_book_toml(book_toml_path)
-    } else {
-        Ok(GoalsConfig::default())
-    }
-}
-
 pub struct GoalPreprocessor;
 
 impl Preprocessor for GoalPreprocessor {
@@ -78,10 +71,38 @@ fn chapter_path<'a>(chapter: &'a Chapter, directive: &str) -> anyhow::Result<&'a
         .ok_or_else(|| anyhow::anyhow!("found `{directive}` but chapter has no path"))
 }
 
+/// Returns an error if `value`, the argument of `directive`, is not an entry of `vocabulary`.
+/// Without this check, a typo would silently render an empty list.
+fn check_vocabulary(
+    chapter: &Chapter,
+    directive: &str,
+    value: &str,
+    vocabulary: &Vocabulary,
+) -> anyhow::Result<()> {
+    if vocabulary.contains_key(value) {
+        return Ok(());
+    }
+
+    anyhow::bail!(
+        "{}: unknown argument `{}` to `{}`; expected one of:\n{}",
+        chapter
+            .path
+            .as_deref()
+            .unwrap_or(Path::new("<unknown>"))
+            .display(),
+        value,
+        directive,
+        describe_vocabulary(vocabulary),
+    )
+}
+
 impl<'c> GoalPreprocessorWithContext<'c> {
     pub fn new(ctx: &'c PreprocessorContext) -> anyhow::Result<Self> {
-        // Extract goals configuration using clean parsing
-        let goals_config = load_goals_config_from_book_toml(ctx)?;
+        // The `[preprocessor.goals]` settings of the book, layered into the configuration
+        let book_goals: Option<toml::Table> = ctx.config.get("preprocessor.goals")?;
+        Configuration::set(Configuration::load_for_book(&ctx.root, book_goals).into_anyhow()?)
+            .into_anyhow()?;
+        let goals_config = GoalsConfig::from_configuration(Configuration::get())?;
 
         // Create the shared markdown processor
         let markdown_processor = MarkdownProcessor::new(goals_config);
@@ -107,6 +128,11 @@ impl<'c> GoalPreprocessorWithContext<'c> {
                 self.replace_valid_team_asks(chapter)?;
                 self.replace_goal_lists(chapter)?;
                 self.replace_roadmap_goal_rows(chapter)?;
+                self.replace_roadmap_gantts(chapter)?;
+                self.replace_sponsors(chapter)?;
+                self.replace_help_wanted_lists(chapter)?;
+                self.replace_goal_explorer(chapter)?;
+                self.replace_updates(chapter)?;
                 self.replace_goal_chapters(chapter)?;
                 self.replace_goal_count(chapter)?;
                 self.replace_roadmap_goal_count(chapter)?;
@@ -189,10 +215,8 @@ impl<'c> GoalPreprocessorWithContext<'c> {
             !goal.metadata.status.content.is_not_not_accepted()
         })?;
 
-        // Handle sized goal lists (Large, Medium, Small)
-        self.replace_sized_goal_list(chapter, &re::LARGE_GOALS_LIST, GoalSize::Large)?;
-        self.replace_sized_goal_list(chapter, &re::MEDIUM_GOALS_LIST, GoalSize::Medium)?;
-        self.replace_sized_goal_list(chapter, &re::SMALL_GOALS_LIST, GoalSize::Small)?;
+        // Handle sized goal lists, one per support level (e.g., LARGE GOALS)
+        self.replace_sized_goal_lists(chapter)?;
 
         // Handle filtered highlight goal lists
         self.replace_highlight_goal_lists_filtered(chapter)?;
@@ -224,6 +248,9 @@ impl<'c> GoalPreprocessorWithContext<'c> {
         // Handle funding legend
         self.replace_funding_legend(chapter)?;
 
+        // Handle dependency graphs
+        self.replace_dependency_graphs(chapter)?;
+
         Ok(())
     }
 
@@ -274,6 +301,44 @@ impl<'c> GoalPreprocessorWithContext<'c> {
         }
     }
 
+    /// Replace `(((ROADMAP GANTT: <name>)))` with a Mermaid Gantt chart of matching goals.
+    fn replace_roadmap_gantts(&mut self, chapter: &mut Chapter) -> anyhow::Result<()> {
+        loop {
+            let Some(m) = re::ROADMAP_GANTT_FILTERED.find(&chapter.content) else {
+                return Ok(());
+            };
+            let range = m.range();
+
+            let chapter_path = chapter_path(chapter, "(((ROADMAP GANTT: ...)))")?;
+
+            let filter_value = re::ROADMAP_GANTT_FILTERED
+                .captures(&chapter.content[range.clone()])
+                .and_then(|caps| caps.get(1))
+                .map(|m| m.as_str().trim())
+                .unwrap(); // Safe: regex always has a capture group
+
+            let goals = self.goal_documents(chapter_path)?;
+            let mut filtered_goals: Vec<&goal::GoalDocument> = goals
+                .iter()
+                .filter(|g| {
+                    g.metadata.status.content.is_not_not_accepted()
+                        && g.matches_roadmap_theme(filter_value)
+                })
+                .collect();
+
+            filtered_goals.sort_by_key(|g| {
+                (
+                    g.timespan().map(|t| t.first_day()),
+                    g.metadata.title.to_lowercase(),
+                )
+            });
+
+            let output = goal::format_roadmap_gantt(&filtered_goals, filter_value);
+
+            chapter.content.replace_range(range, &output);
+        }
+    }
+
     fn replace_highlight_goal_lists_filtered(
         &mut self,
         chapter: &mut Chapter,
@@ -282,6 +347,7 @@ impl<'c> GoalPreprocessorWithContext<'c> {
             chapter,
             &re::HIGHLIGHT_GOALS_LIST_FILTERED,
             "(((HIGHLIGHT GOALS: ...)))",
+            &Configuration::get().highlights,
             |g| &g.metadata.highlight,
         )
     }
@@ -301,6 +367,13 @@ impl<'c> GoalPreprocessorWithContext<'c> {
                 .map(|m| m.as_str().trim())
                 .unwrap();
 
+            check_vocabulary(
+                chapter,
+                "(((HIGHLIGHT TABLE: ...)))",
+                filter_value,
+                &Configuration::get().highlights,
+            )?;
+
             let goals = self.goal_documents(chapter_path)?;
             let mut filtered_goals: Vec<&GoalDocument> = goals
                 .iter()
@@ -333,6 +406,13 @@ impl<'c> GoalPreprocessorWithContext<'c> {
                 .map(|m| m.as_str().trim())
                 .unwrap();
 
+            check_vocabulary(
+                chapter,
+                "(((HIGHLIGHT CREDITS: ...)))",
+                filter_value,
+                &Configuration::get().highlights,
+            )?;
+
             let goals = self.goal_documents(chapter_path)?;
             let mut filtered_goals: Vec<&GoalDocument> = goals
                 .iter()
@@ -368,6 +448,13 @@ impl<'c> GoalPreprocessorWithContext<'c> {
                 .map(|m| m.as_str().trim())
                 .unwrap();
 
+            check_vocabulary(
+                chapter,
+                "(((GOALS WITH NEEDS: ...)))",
+                capture_value,
+                &Configuration::get().needs,
+            )?;
+
             let goals = self.goal_documents(chapter_path)?;
 
             let mut filtered_goals: Vec<&GoalDocument> = if capture_value == "Funding" {
@@ -557,6 +644,218 @@ impl<'c> GoalPreprocessorWithContext<'c> {
         Ok(())
     }
 
+    /// Replace `(((SPONSORS)))` with the goals funded by each sponsor in the registry,
+    /// across all milestones in the book.
+    fn replace_sponsors(&mut self, chapter: &mut Chapter) -> anyhow::Result<()> {
+        let Some(m) = re::SPONSORS.find(&chapter.content) else {
+            return Ok(());
+        };
+        let range = m.range();
+
+        let chapter_path = chapter_path(chapter, "(((SPONSORS)))")?;
+
+        // Links in the output are relative to the book's `src` directory.
+        let depth = chapter_path.components().count().saturating_sub(1);
+        let link_root = PathBuf::from("../".repeat(depth));
+
+        let milestone_regex = Regex::new(util::MILESTONE_REGEX)?;
+        let mut milestones = vec![];
+        for entry in std::fs::read_dir(&self.ctx.config.book.src)? {
+            let entry = entry?;
+            if !entry.file_type()?.is_dir() {
+                continue;
+            }
+            let Some(name) = entry.file_name().to_str().map(|s| s.to_string()) else {
+                continue;
+            };
+            if milestone_regex.is_match(&name) {
+                milestones.push(name);
+            }
+        }
+        milestones.sort();
+
+        let mut milestone_goals = vec![];
+        for milestone in &milestones {
+            milestone_goals.push((
+                milestone.as_str(),
+                self.milestone_goal_documents(Path::new(milestone))?,
+            ));
+        }
+
+        let goal_refs: Vec<(&str, &GoalDocument)> = milestone_goals
+            .iter()
+            .flat_map(|(milestone, goals)| goals.iter().map(move |g| (*milestone, g)))
+            .filter(|(_, g)| g.metadata.status.content.is_not_not_accepted())
+            .collect();
+
+        let output = goal::format_sponsors(&goal_refs, &link_root);
+        chapter.content.replace_range(range, &output);
+        Ok(())
+    }
+
+    /// Replace `(((HELP WANTED)))` and `(((HELP WANTED: <filter>)))` with a table of the
+    /// milestone's contributor opportunities, from goal documents and status updates.
+    fn replace_help_wanted_lists(&mut self, chapter: &mut Chapter) -> anyhow::Result<()> {
+        loop {
+            let Some(m) = re::HELP_WANTED_LIST.find(&chapter.content) else {
+                return Ok(());
+            };
+            let range = m.range();
+
+            let chapter_path = chapter_path(chapter, "(((HELP WANTED)))")?;
+
+            let filter = re::HELP_WANTED_LIST
+                .captures(&chapter.content[range.clone()])
+                .and_then(|caps| caps.get(1))
+                .map(|m| m.as_str())
+                .unwrap_or("");
+            let filter = OpportunityFilter::parse(filter).into_anyhow()?;
+
+            let Some(milestone) = chapter_path
+                .parent()
+                .and_then(|p| p.file_name())
+                .and_then(|n| n.to_str())
+                .map(|n| n.to_string())
+            else {
+                anyhow::bail!("found `(((HELP WANTED)))` outside of a milestone directory");
+            };
+
+            let goals = self.goal_documents(chapter_path)?;
+            let goal_refs: Vec<&GoalDocument> = goals
+                .iter()
+                .filter(|g| g.metadata.status.content.is_not_not_accepted())
+                .collect();
+
+            // Requests from status updates need GitHub; without it, fall back to the goal documents.
+            let issues = self
+                .get_or_load_milestone_issues(&milestone)
+                .unwrap_or_else(|e| {
+                    eprintln!("⚠️ Skipping help wanted requests from status updates: {e}");
+                    Arc::new(vec![])
+                });
+
+            let mut all = opportunities::opportunities(&milestone, &goal_refs, &issues);
+            all.sort_by_key(|o| o.goal_title.to_lowercase());
+            let matching: Vec<&Opportunity> = all.iter().filter(|o| filter.matches(o)).collect();
+
+            let output = opportunities::format_opportunities(&matching);
+            chapter.content.replace_range(range, &output);
+        }
+    }
+
+    /// Replace `(((GOAL EXPLORER)))` with the JSON index of the milestone's goals that
+    /// `goal-explorer.js` turns into a filterable, sortable table.
+    fn replace_goal_explorer(&mut self, chapter: &mut Chapter) -> anyhow::Result<()> {
+        let Some(m) = re::GOAL_EXPLORER.find(&chapter.content) else {
+            return Ok(());
+        };
+        let range = m.range();
+
+        let chapter_path = chapter_path(chapter, "(((GOAL EXPLORER)))")?;
+
+        let Some(milestone) = chapter_path
+            .parent()
+            .and_then(|p| p.file_name())
+            .and_then(|n| n.to_str())
+            .map(|n| n.to_string())
+        else {
+            anyhow::bail!("found `(((GOAL EXPLORER)))` outside of a milestone directory");
+        };
+
+        let goals = self.goal_documents(chapter_path)?;
+        let goal_refs: Vec<&GoalDocument> = goals
+            .iter()
+            .filter(|g| g.metadata.status.content.is_not_not_accepted())
+            .collect();
+
+        // Progress comes from the tracking issues; without GitHub, leave it out.
+        let issues = self
+            .get_or_load_milestone_issues(&milestone)
+            .unwrap_or_else(|e| {
+                eprintln!("⚠️ Skipping progress in the goal explorer: {e}");
+                Arc::new(vec![])
+            });
+
+        let index = explorer::goal_index(&milestone, &goal_refs, &issues);
+        let output = explorer::format_goal_explorer(&index);
+        chapter.content.replace_range(range, &output);
+        Ok(())
+    }
+
+    /// Replace `(((UPDATES)))` and `(((UPDATES: <options>)))` with the status updates of the
+    /// milestone's goals, as in the monthly blog post, e.g.
+    /// `(((UPDATES: from=2026-03-01, to=2026-04-01, group=champion, team=lang)))`.
+    fn replace_updates(&mut self, chapter: &mut Chapter) -> anyhow::Result<()> {
+        loop {
+            let Some(m) = re::UPDATES.find(&chapter.content) else {
+                return Ok(());
+            };
+            let range = m.range();
+
+            let chapter_path = chapter_path(chapter, "(((UPDATES)))")?;
+
+            let options = re::UPDATES
+                .captures(&chapter.content[range.clone()])
+                .and_then(|caps| caps.get(1))
+                .map(|m| m.as_str())
+                .unwrap_or("");
+            let (options, start_date, end_date) = UpdatesOptions::parse(options).into_anyhow()?;
+
+            let Some(milestone) = chapter_path
+                .parent()
+                .and_then(|p| p.file_name())
+                .and_then(|n| n.to_str())
+                .map(|n| n.to_string())
+            else {
+                anyhow::bail!("found `(((UPDATES)))` outside of a milestone directory");
+            };
+
+            let repository =
+                rust_project_goals::gh::issue_id::Repository::new("rust-lang", "goals");
+            let issues = self.get_or_load_milestone_issues(&milestone)?;
+
+            let output = rust_project_goals_cli::render_updates(
+                &issues,
+                &repository,
+                &milestone,
+                start_date.as_ref(),
+                end_date.as_ref(),
+                &options,
+                false,
+            )
+            .map_err(|e| anyhow::anyhow!("Failed to generate updates: {}", e))?;
+            chapter.content.replace_range(range, &output);
+        }
+    }
+
+    /// Replace `(((DEPENDENCY GRAPH)))` and `(((DEPENDENCY GRAPH: <theme>)))` with
+    /// a Mermaid diagram of the `Depends on` / `Blocks` rows of the milestone's goals.
+    fn replace_dependency_graphs(&mut self, chapter: &mut Chapter) -> anyhow::Result<()> {
+        loop {
+            let Some(m) = re::DEPENDENCY_GRAPH.find(&chapter.content) else {
+                return Ok(());
+            };
+            let range = m.range();
+
+            let chapter_path = chapter_path(chapter, "(((DEPENDENCY GRAPH)))")?;
+
+            let filter_value = re::DEPENDENCY_GRAPH
+                .captures(&chapter.content[range.clone()])
+                .and_then(|caps| caps.get(1))
+                .map(|m| m.as_str().trim().to_string());
+
+            let goals = self.goal_documents(chapter_path)?;
+
+            // Pass every goal so that references to not-accepted goals still resolve;
+            // the formatter leaves them out of the diagram.
+            let goal_refs: Vec<&GoalDocument> = goals.iter().collect();
+
+            let output =
+                goal::format_dependency_graph(&goal_refs, filter_value.as_deref()).into_anyhow()?;
+            chapter.content.replace_range(range, &output);
+        }
+    }
+
     /// Shared helper for replacing themed goal list directives (HIGHLIGHT GOALS, GOALS WITH NEEDS).
     /// Filters goals by a `Themes` field extracted via `get_themes`, then formats as heading sections.
     fn replace_themed_goal_list(
@@ -564,6 +863,7 @@ impl<'c> GoalPreprocessorWithContext<'c> {
         chapter: &mut Chapter,
         regex: &Regex,
         directive_name: &str,
+        vocabulary: &Vocabulary,
         get_themes: impl Fn(&GoalDocument) -> &goal::Themes,
     ) -> anyhow::Result<()> {
         loop {
@@ -580,6 +880,8 @@ impl<'c> GoalPreprocessorWithContext<'c> {
                 .map(|m| m.as_str().trim())
                 .unwrap(); // Safe: regex always has a capture group
 
+            check_vocabulary(chapter, directive_name, capture_value, vocabulary)?;
+
             let goals = self.goal_documents(chapter_path)?;
             let mut filtered_goals: Vec<&GoalDocument> = goals
                 .iter()
@@ -608,20 +910,24 @@ impl<'c> GoalPreprocessorWithContext<'c> {
         }
     }
 
-    /// Replace sized goal list markers (LARGE GOALS, MEDIUM GOALS, SMALL GOALS)
+    /// Replace a sized goal list marker (e.g., LARGE GOALS for the `Large` support level)
     /// with tables grouped by primary team.
-    fn replace_sized_goal_list(
-        &mut self,
-        chapter: &mut Chapter,
-        regex: &Regex,
-        size: GoalSize,
-    ) -> anyhow::Result<()> {
-        let Some(m) = regex.find(&chapter.content) else {
+    fn replace_sized_goal_lists(&mut self, chapter: &mut Chapter) -> anyhow::Result<()> {
+        // Only directives naming a configured support level are sized goal lists;
+        // the others (e.g. `(((OTHER GOALS)))`) are handled elsewhere.
+        let lists: Vec<(Range<usize>, SupportLevel)> = re::SIZED_GOALS_LIST
+            .captures_iter(&chapter.content)
+            .filter_map(|c| {
+                let level = SupportLevel::named(&c["level"])?;
+                Some((c.get(0).unwrap().range(), level))
+            })
+            .collect();
+        if lists.is_empty() {
             return Ok(());
-        };
-        let range = m.range();
+        }
 
-        let chapter_path = chapter_path(chapter, &format!("{regex}"))?;
+        let directive = chapter.content[lists[0].0.clone()].to_string();
+        let chapter_path = chapter_path(chapter, &directive)?;
 
         let goals = self.goal_documents(chapter_path)?;
         let goals_with_status: Vec<&GoalDocument> = goals
@@ -629,8 +935,11 @@ impl<'c> GoalPreprocessorWithContext<'c> {
             .filter(|g| g.metadata.status.content.is_not_not_accepted())
             .collect();
 
-        let output = goal::format_sized_goal_table(&goals_with_status, size).into_anyhow()?;
-        chapter.content.replace_range(range, &output);
+        // Replace from the end so the earlier ranges stay valid
+        for (range, size) in lists.into_iter().rev() {
+            let output = goal::format_sized_goal_table(&goals_with_status, size).into_anyhow()?;
+            chapter.content.replace_range(range, &output);
+        }
 
         Ok(())
     }
@@ -876,20 +1185,15 @@ impl<'c> GoalPreprocessorWithContext<'c> {
             return Ok(());
         }
         let config = Configuration::get();
-        let rows = std::iter::once(vec![
-            Spanned::here("Ask".to_string()),
-            Spanned::here("aka".to_string()),
-            Spanned::here("Description".to_string()),
-        ])
-        .chain(config.team_asks.iter().map(|(name, details)| {
-            vec![
-                Spanned::here(format!("{name:?}")),
-                Spanned::here(details.short.to_string()),
-                Spanned::here(details.about.to_string()),
-            ]
-        }))
-        .collect::<Vec<Vec<Spanned<String>>>>();
-        let table = util::format_table(&rows);
+        let mut table = Table::new(["Ask", "aka", "Description"]);
+        for (name, details) in &config.team_asks {
+            table.push_row(vec![
+                Cell::text(format!("{name:?}")),
+                Cell::markdown(&details.short),
+                Cell::markdown(&details.about),
+            ]);
+        }
+        let table = table.to_markdown();
         let new_content = re::VALID_TEAM_ASKS.replace_all(&chapter.content, table);
         chapter.content = new_content.to_string();
         Ok(())
@@ -902,6 +1206,14 @@ impl<'c> GoalPreprocessorWithContext<'c> {
             anyhow::bail!("cannot get goal documents from `{chapter_path:?}`")
         };
 
+        self.milestone_goal_documents(milestone_path)
+    }
+
+    /// Find the goal documents in `milestone_path`, relative to the book's `src` directory.
+    fn milestone_goal_documents(
+        &mut self,
+        milestone_path: &Path,
+    ) -> anyhow::Result<Arc<Vec<GoalDocument>>> {
         if let Some(goals) = self.goal_document_map.get(milestone_path) {
             return Ok(goals.clone());
         }
@@ -1003,6 +1315,31 @@ impl<'c> GoalPreprocessorWithContext<'c> {
         }
     }
 
+    /// Replace the value of the first table row whose first cell is `key` (case-insensitive).
+    /// Does nothing if there is no such row.
+    fn replace_metadata_row_value(content: &mut String, key: &str, value: &str) {
+        let mut offset = 0;
+        let mut found = None;
+        for line in content.split_inclusive('\n') {
+            let row = line.trim_end_matches('\n');
+            if let Some((name, _)) = row
+                .trim_start()
+                .strip_prefix('|')
+                .and_then(|r| r.split_once('|'))
+            {
+                if name.trim().eq_ignore_ascii_case(key) {
+                    found = Some((offset..offset + row.len(), format!("|{name}| {value} |")));
+                    break;
+                }
+            }
+            offset += line.len();
+        }
+
+        if let Some((range, replacement)) = found {
+            content.replace_range(range, &replacement);
+        }
+    }
+
     /// Automatically inject team names and task owners into the metadata table.
     /// This replaces the need for manual placeholders and combines the logic
     /// to avoid duplicate table parsing.
@@ -1053,6 +1390,43 @@ impl<'c> GoalPreprocessorWithContext<'c> {
 
             let task_owners: Vec<String> = goal.task_owners.iter().cloned().collect();
 
+            // Show the status as its badge
+            Self::replace_metadata_row_value(
+                &mut chapter.content,
+                "Status",
+                &Cell::badge(goal.metadata.status.content.as_str()).to_markdown(),
+            );
+
+            // Normalize the Zulip channel and other tracking issue rows into links
+            if !goal.metadata.zulip_channels.is_empty() {
+                let channels: Vec<String> = goal
+                    .metadata
+                    .zulip_channels
+                    .iter()
+                    .map(|channel| channel.markdown_link())
+                    .collect();
+                Self::replace_metadata_row_value(
+                    &mut chapter.content,
+                    "Zulip channel",
+                    &channels.join(", "),
+                );
+            }
+
+            if !goal.metadata.other_tracking_issues.is_empty() {
+                // `[org/repo#N]` is turned into a link by the linkifiers
+                let issues: Vec<String> = goal
+                    .metadata
+                    .other_tracking_issues
+                    .iter()
+                    .map(|issue_id| issue_id.to_string())
+                    .collect();
+                Self::replace_metadata_row_value(
+                    &mut chapter.content,
+                    "Other tracking issues",
+                    &issues.join(", "),
+                );
+            }
+
             let task_owners_text = if task_owners.is_empty() {
                 "(none)".to_string()
             } else {
@@ -1060,10 +1434,14 @@ impl<'c> GoalPreprocessorWithContext<'c> {
             };
 
             if let Some(table_end) = Self::find_markdown_table_end(&chapter.content) {
-                let insertion_text = format!(
+                let mut insertion_text = format!(
                     "| Teams            | {} |\n| Task owners      | {} |\n",
                     teams_text, task_owners_text
                 );
+                if let Some(progress) = goal.progress() {
+                    insertion_text
+                        .push_str(&format!("| Progress         | {} |\n", progress.html()));
+                }
                 chapter.content.insert_str(table_end, &insertion_text);
             }
         } else if let Some(roadmap) = self
@@ -1284,9 +1662,11 @@ impl<'c> GoalPreprocessorWithContext<'c> {
             milestone,
             Some(&start_date),
             Some(&end_date),
-            None,
+            &UpdatesOptions {
+                order: Order::OldestFirst,
+                ..Default::default()
+            },
             false,
-            Order::OldestFirst,
         )
         .map_err(|e| anyhow::anyhow!("Failed to generate blog post content: {}", e))?;
 
@@ -1320,9 +1700,15 @@ impl<'c> GoalPreprocessorWithContext<'c> {
             milestone,
             Some(&start_date),
             Some(&end_date),
-            Some(team_name),
+            &UpdatesOptions {
+                order: Order::NewestFirst,
+                filter: UpdatesFilter {
+                    with_champion_from: Some(team_name.to_string()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
             false,
-            Order::NewestFirst,
         )
         .map_err(|e| anyhow::anyhow!("Failed to generate champion report content: {}", e))?;
 
@@ -3,7 +3,9 @@ use goal_preprocessor::GoalPreprocessor;
 use mdbook_preprocessor::Preprocessor;
 use semver::{Version, VersionReq};
 use std::io;
+use std::path::{Path, PathBuf};
 
+mod check_links;
 mod goal_preprocessor;
 
 #[derive(clap::Parser, Debug)]
@@ -18,6 +20,21 @@ struct Opt {
 enum Command {
     /// Command used by mdbook to check if the preprocessor supports a renderer
     Supports { renderer: String },
+
+    /// Preprocess the book in the current directory and check its internal links,
+    /// anchors, reference links, and redirects
+    CheckLinks {
+        /// Also fetch external links
+        #[arg(long)]
+        online: bool,
+    },
+
+    /// Write the scripts used by the book (e.g., the goal explorer) into the book's directory
+    Install {
+        /// Directory containing `book.toml`
+        #[arg(default_value = ".")]
+        dir: PathBuf,
+    },
 }
 
 fn main() -> anyhow::Result<()> {
@@ -31,6 +48,42 @@ fn main() -> anyhow::Result<()> {
         Command::Supports { renderer } => {
             handle_supports(&GoalPreprocessor, renderer)?;
         }
+
+        Command::CheckLinks { online } => {
+            check_links::check_links(*online)?;
+        }
+
+        Command::Install { dir } => {
+            install(dir)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// The goal explorer script, see `rust_project_goals::explorer`.
+const GOAL_EXPLORER_JS: &str = include_str!("../assets/goal-explorer.js");
+
+fn install(dir: &Path) -> anyhow::Result<()> {
+    let script = rust_project_goals::explorer::SCRIPT_NAME;
+    let path = dir.join(script);
+    std::fs::write(&path, GOAL_EXPLORER_JS)?;
+    eprintln!("Wrote {}", path.display());
+
+    // Like mermaid, the script is loaded through `output.html.additional-js`.
+    let book_toml = dir.join("book.toml");
+    let book: toml::Value = toml::from_str(&std::fs::read_to_string(&book_toml)?)?;
+    let listed = book
+        .get("output")
+        .and_then(|o| o.get("html"))
+        .and_then(|h| h.get("additional-js"))
+        .and_then(|js| js.as_array())
+        .is_some_and(|js| js.iter().any(|j| j.as_str() == Some(script)));
+    if !listed {
+        eprintln!(
+            "⚠️ Add \"{script}\" to `output.html.additional-js` in {}",
+            book_toml.display()
+        );
     }
 
     Ok(())
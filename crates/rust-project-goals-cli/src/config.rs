@@ -0,0 +1,31 @@
+//! `cargo rpg config`: inspect the layered configuration (see [`Configuration`]).
+
+use rust_project_goals::config::Configuration;
+use rust_project_goals::spanned::Result;
+
+/// Print the effective configuration, with all layers applied, as TOML.
+pub fn show() -> Result<()> {
+    let configuration = Configuration::load()?;
+    print!("{}", configuration.to_toml()?);
+    Ok(())
+}
+
+/// Check that the configuration loads and is consistent, e.g. in CI.
+pub fn validate() -> Result<()> {
+    let configuration = Configuration::load()?;
+
+    let problems = configuration.problems();
+    if !problems.is_empty() {
+        rust_project_goals::spanned::bail_here!(
+            "invalid configuration:\n{}",
+            problems
+                .iter()
+                .map(|problem| format!("* {problem}"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+    }
+
+    eprintln!("Configuration is valid.");
+    Ok(())
+}
@@ -5,6 +5,7 @@ use rust_project_goals::{
     gh::issue_id::Repository,
     goal,
     spanned::{Result, Spanned},
+    table::{Cell, Table},
     team::TeamName,
 };
 
@@ -52,63 +53,34 @@ fn champions(repository: &Repository, milestone: &str) -> Result<()> {
         })
         .collect();
 
-    // Write header row
-    write_csv_row(|cell| {
-        cell.write_cell("Title");
-        cell.write_cell("POC(s)");
-        for team in &all_teams {
-            cell.write_cell(&format!("{team}"));
-        }
-        cell.write_cell("URL");
-    });
+    let mut table = Table::new(
+        std::iter::once("Title".to_string())
+            .chain(std::iter::once("POC(s)".to_string()))
+            .chain(all_teams.iter().map(|team| format!("{team}")))
+            .chain(std::iter::once("URL".to_string())),
+    );
 
-    // Write data rows
     for row in &rows {
-        write_csv_row(|cell| {
-            cell.write_cell(&row.title);
-            cell.write_cell(&row.pocs);
+        let mut cells = vec![Cell::text(&row.title), Cell::text(&row.pocs)];
 
-            for team in &all_teams {
-                if row.teams_with_asks.contains(team) {
-                    // Team has an ask - check if there's a champion
-                    if let Some(champion) = row.champions.get(team) {
-                        cell.write_cell(champion);
-                    } else {
-                        cell.write_cell("!");
-                    }
-                } else {
-                    // Team has no ask for this goal
-                    cell.write_cell("-");
+        for team in &all_teams {
+            cells.push(if row.teams_with_asks.contains(team) {
+                // Team has an ask - check if there's a champion
+                match row.champions.get(team) {
+                    Some(champion) => Cell::text(&champion.content),
+                    None => Cell::text("!"),
                 }
-            }
+            } else {
+                // Team has no ask for this goal
+                Cell::text("-")
+            });
+        }
 
-            cell.write_cell(&row.url);
-        });
+        cells.push(Cell::text(&row.url));
+        table.push_row(cells);
     }
 
-    Ok(())
-}
-
-trait WriteCell {
-    fn write_cell(&mut self, s: &str);
-}
-
-impl WriteCell for String {
-    fn write_cell(&mut self, s: &str) {
-        let mut s = s.replace(r#"""#, r#"\""#);
-        s = s.replace("\n", "\\n");
+    print!("{}", table.to_csv());
 
-        if !self.is_empty() {
-            self.push(',');
-        }
-        self.push('"');
-        self.push_str(&s);
-        self.push('"');
-    }
-}
-
-fn write_csv_row(op: impl FnOnce(&mut dyn WriteCell)) {
-    let mut s = String::new();
-    op(&mut s);
-    println!("{s}");
+    Ok(())
 }
@@ -0,0 +1,37 @@
+//! Write the Atom feeds of a milestone's goal updates into the book output.
+
+use std::path::{Path, PathBuf};
+
+use rust_project_goals::{
+    feeds,
+    gh::{issue_id::Repository, issues::list_issues_in_milestone},
+    goal::{self, GoalDocument},
+    spanned::{Context as _, Result},
+};
+
+pub fn feeds(repository: &Repository, milestone: &str, output_dir: &Path) -> Result<()> {
+    let milestone_path = PathBuf::from("src").join(milestone);
+    let goals = goal::goals_in_dir(&milestone_path)?;
+    let goals: Vec<&GoalDocument> = goals
+        .iter()
+        .filter(|g| g.metadata.status.content.is_not_not_accepted())
+        .collect();
+    let issues = list_issues_in_milestone(repository, milestone)?;
+
+    let now = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+    let feeds = feeds::update_feeds(milestone, &goals, &issues, &now);
+
+    for feed in &feeds {
+        let path = output_dir.join(milestone).join(&feed.path);
+        let dir = path.parent().unwrap();
+        std::fs::create_dir_all(dir).with_path_context(dir, "failed to create directory")?;
+        std::fs::write(&path, feed.feed.render()).with_path_context(&path, "failed to write")?;
+    }
+    eprintln!(
+        "Wrote {} feeds to {}",
+        feeds.len(),
+        output_dir.join(milestone).join("feeds").display()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,444 @@
+//! Generate a funding ledger across one or more milestones.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use rust_project_goals::{
+    gh::issue_id::Repository,
+    goal::{self, FundingCost},
+    spanned::{Context as _, Result},
+    table::{Cell, Table},
+};
+use serde::Serialize;
+
+/// Output format of the funding ledger.
+#[derive(clap::ValueEnum, Copy, Clone, Debug, Default)]
+pub enum FundingFormat {
+    #[default]
+    Markdown,
+    Html,
+    Csv,
+    Json,
+}
+
+/// Bucket used for goals that are not part of any roadmap.
+const NO_ROADMAP: &str = "Other goals";
+
+/// Bucket used for funding items without a named sponsor.
+const NO_SPONSOR: &str = "Unsponsored";
+
+#[derive(Serialize, Debug)]
+struct Ledger {
+    milestones: Vec<String>,
+    items: Vec<LedgerItem>,
+    totals: LedgerTotals,
+}
+
+/// A single row of a goal's `## Funding` table.
+#[derive(Serialize, Debug)]
+struct LedgerItem {
+    milestone: String,
+    goal: String,
+    url: String,
+    roadmaps: Vec<String>,
+    purpose: String,
+    /// The cost as written, e.g. `€40,000–€60,000`, `TBD`, or `Ask`.
+    cost: String,
+    /// The currency of the cost, if it is a known amount.
+    currency: Option<String>,
+    /// Lower and upper bound of the cost in USD, if it is a known amount.
+    low_usd: Option<u64>,
+    high_usd: Option<u64>,
+    status: &'static str,
+    sponsors: Vec<String>,
+    point_of_contact: String,
+    #[serde(skip)]
+    original_cost: FundingCost,
+}
+
+#[derive(Serialize, Debug, Default)]
+struct LedgerTotals {
+    overall: Total,
+    by_roadmap: BTreeMap<String, Total>,
+    by_status: BTreeMap<&'static str, Total>,
+    by_sponsor: BTreeMap<String, Total>,
+    by_point_of_contact: BTreeMap<String, Total>,
+}
+
+/// Sum of the costs of a set of funding items, normalized to USD.
+/// Items whose cost is `TBD` or `Ask` are counted separately.
+#[derive(Serialize, Debug, Default, Clone, Copy)]
+struct Total {
+    items: usize,
+    low_usd: u64,
+    high_usd: u64,
+    tbd: usize,
+    ask: usize,
+}
+
+impl Total {
+    fn add(&mut self, item: &LedgerItem) {
+        self.items += 1;
+        match (&item.original_cost, item.low_usd, item.high_usd) {
+            (_, Some(low), Some(high)) => {
+                self.low_usd += low;
+                self.high_usd += high;
+            }
+            (FundingCost::Ask, _, _) => self.ask += 1,
+            _ => self.tbd += 1,
+        }
+    }
+
+    fn display_usd(&self) -> String {
+        FundingCost::Amount {
+            currency: "USD".to_string(),
+            low: self.low_usd,
+            high: self.high_usd,
+        }
+        .display()
+    }
+}
+
+pub fn funding(
+    repository: &Repository,
+    milestones: &[String],
+    format: FundingFormat,
+    output_file: Option<&Path>,
+) -> Result<()> {
+    let ledger = build_ledger(repository, milestones)?;
+
+    let output = match format {
+        FundingFormat::Markdown => format_markdown(&ledger),
+        FundingFormat::Html => format_html(&ledger),
+        FundingFormat::Csv => format_csv(&ledger),
+        FundingFormat::Json => serde_json::to_string_pretty(&ledger)?,
+    };
+
+    if let Some(output_file) = output_file {
+        std::fs::write(output_file, output).with_path_context(output_file, "failed to write")?;
+    } else {
+        println!("{output}");
+    }
+
+    Ok(())
+}
+
+fn build_ledger(repository: &Repository, milestones: &[String]) -> Result<Ledger> {
+    let mut items = vec![];
+
+    for milestone in milestones {
+        let milestone_path = PathBuf::from("src").join(milestone);
+        if !milestone_path.exists() {
+            rust_project_goals::spanned::bail_here!(
+                "milestone directory `{}` does not exist",
+                milestone_path.display()
+            );
+        }
+
+        let goals = goal::goals_in_dir(&milestone_path)?;
+        for goal in goals
+            .iter()
+            .filter(|g| g.metadata.status.content.is_not_not_accepted())
+        {
+            let roadmaps: Vec<String> = goal
+                .task_tree
+                .all_roadmap_themes()
+                .iter()
+                .map(|t| t.trim().to_string())
+                .collect();
+
+            for item in &goal.funding {
+                let (currency, low_usd, high_usd) = match (&item.cost, item.cost.to_usd()) {
+                    (
+                        FundingCost::Amount { currency, .. },
+                        Some(FundingCost::Amount { low, high, .. }),
+                    ) => (Some(currency.clone()), Some(low), Some(high)),
+                    _ => (None, None, None),
+                };
+
+                items.push(LedgerItem {
+                    milestone: milestone.clone(),
+                    goal: goal.metadata.title.to_string(),
+                    url: format!(
+                        "https://github.com/{org}/{repo}/blob/main/{path}",
+                        org = repository.org,
+                        repo = repository.repo,
+                        path = goal.path.display()
+                    ),
+                    roadmaps: roadmaps.clone(),
+                    purpose: item.purpose.trim().to_string(),
+                    cost: item.cost.display(),
+                    currency,
+                    low_usd,
+                    high_usd,
+                    status: item.status.label(),
+                    sponsors: item
+                        .canonical_sponsors()
+                        .iter()
+                        .map(|s| s.to_string())
+                        .collect(),
+                    point_of_contact: goal.funding_point_of_contact().to_string(),
+                    original_cost: item.cost.clone(),
+                });
+            }
+        }
+    }
+
+    // Items with several roadmaps or sponsors count towards each of them.
+    let mut totals = LedgerTotals::default();
+    for item in &items {
+        totals.overall.add(item);
+        totals.by_status.entry(item.status).or_default().add(item);
+        totals
+            .by_point_of_contact
+            .entry(item.point_of_contact.clone())
+            .or_default()
+            .add(item);
+
+        if item.roadmaps.is_empty() {
+            totals
+                .by_roadmap
+                .entry(NO_ROADMAP.to_string())
+                .or_default()
+                .add(item);
+        }
+        for roadmap in &item.roadmaps {
+            totals
+                .by_roadmap
+                .entry(roadmap.clone())
+                .or_default()
+                .add(item);
+        }
+
+        if item.sponsors.is_empty() {
+            totals
+                .by_sponsor
+                .entry(NO_SPONSOR.to_string())
+                .or_default()
+                .add(item);
+        }
+        for sponsor in &item.sponsors {
+            totals
+                .by_sponsor
+                .entry(sponsor.clone())
+                .or_default()
+                .add(item);
+        }
+    }
+
+    Ok(Ledger {
+        milestones: milestones.to_vec(),
+        items,
+        totals,
+    })
+}
+
+/// The breakdowns of the ledger totals, with a heading for each.
+fn breakdowns(totals: &LedgerTotals) -> Vec<(&'static str, Vec<(&str, &Total)>)> {
+    vec![
+        (
+            "Roadmap",
+            totals
+                .by_roadmap
+                .iter()
+                .map(|(k, v)| (k.as_str(), v))
+                .collect(),
+        ),
+        (
+            "Funding status",
+            totals.by_status.iter().map(|(k, v)| (*k, v)).collect(),
+        ),
+        (
+            "Sponsor",
+            totals
+                .by_sponsor
+                .iter()
+                .map(|(k, v)| (k.as_str(), v))
+                .collect(),
+        ),
+        (
+            "Funding contact",
+            totals
+                .by_point_of_contact
+                .iter()
+                .map(|(k, v)| (k.as_str(), v))
+                .collect(),
+        ),
+    ]
+}
+
+fn format_markdown(ledger: &Ledger) -> String {
+    let mut output = String::new();
+
+    writeln!(output, "# {}\n", title(ledger)).unwrap();
+    writeln!(output, "**Total:** {}\n", total(ledger)).unwrap();
+
+    for (heading, table) in tables(ledger) {
+        writeln!(output, "## {heading}\n").unwrap();
+        writeln!(output, "{}", table.to_markdown()).unwrap();
+    }
+
+    output
+}
+
+fn format_html(ledger: &Ledger) -> String {
+    let mut output = String::new();
+
+    writeln!(output, "<h1>{}</h1>", title(ledger)).unwrap();
+    writeln!(output, "<p><strong>Total:</strong> {}</p>", total(ledger)).unwrap();
+
+    for (heading, table) in tables(ledger) {
+        writeln!(output, "<h2>{heading}</h2>").unwrap();
+        output.push_str(&table.to_html());
+    }
+
+    output
+}
+
+fn title(ledger: &Ledger) -> String {
+    format!("Funding ledger ({})", ledger.milestones.join(", "))
+}
+
+fn total(ledger: &Ledger) -> String {
+    let overall = &ledger.totals.overall;
+    format!(
+        "{} across {} items ({} TBD, {} Ask)",
+        overall.display_usd(),
+        overall.items,
+        overall.tbd,
+        overall.ask,
+    )
+}
+
+/// The tables of the markdown and HTML ledger, with their headings: one per breakdown
+/// of the totals, then the items.
+fn tables(ledger: &Ledger) -> Vec<(String, Table)> {
+    let mut tables = vec![];
+
+    for (heading, rows) in breakdowns(&ledger.totals) {
+        let mut table = Table::new([heading, "Items", "Total (USD)", "TBD", "Ask"]);
+        for (key, total) in rows {
+            table.push_row(vec![
+                Cell::markdown(key),
+                Cell::text(total.items.to_string()),
+                Cell::text(total.display_usd()),
+                Cell::text(total.tbd.to_string()),
+                Cell::text(total.ask.to_string()),
+            ]);
+        }
+        tables.push((format!("By {}", heading.to_lowercase()), table));
+    }
+
+    let mut table = Table::new([
+        "Milestone",
+        "Goal",
+        "Purpose",
+        "Cost",
+        "Funded",
+        "Sponsor(s)",
+        "Funding contact",
+    ]);
+    for item in &ledger.items {
+        let cost = match (&item.currency, item.low_usd, item.high_usd) {
+            (Some(currency), Some(low), Some(high)) if currency != "USD" => format!(
+                "{} (≈{})",
+                item.cost,
+                FundingCost::Amount {
+                    currency: "USD".to_string(),
+                    low,
+                    high,
+                }
+                .display()
+            ),
+            _ => item.cost.clone(),
+        };
+        table.push_row(vec![
+            Cell::text(&item.milestone),
+            Cell::link(&item.goal, &item.url),
+            Cell::markdown(&item.purpose),
+            Cell::text(cost),
+            Cell::text(item.status),
+            Cell::markdown(item.sponsors.join(", ")),
+            Cell::markdown(&item.point_of_contact),
+        ]);
+    }
+    tables.push(("Items".to_string(), table));
+
+    tables
+}
+
+/// Items and totals share one table; the `Kind` column tells them apart
+/// and the columns that do not apply to a row are left empty.
+fn format_csv(ledger: &Ledger) -> String {
+    let mut table = Table::new([
+        "Kind",
+        "Milestone",
+        "Goal",
+        "Roadmap(s)",
+        "Purpose",
+        "Cost",
+        "Currency",
+        "Low (USD)",
+        "High (USD)",
+        "Funded",
+        "Sponsor(s)",
+        "Funding contact",
+        "URL",
+        "Breakdown",
+        "Key",
+        "Items",
+        "TBD",
+        "Ask",
+    ]);
+    for item in &ledger.items {
+        table.push_row(vec![
+            Cell::text("item"),
+            Cell::text(&item.milestone),
+            Cell::text(&item.goal),
+            Cell::text(item.roadmaps.join(", ")),
+            Cell::text(&item.purpose),
+            Cell::text(&item.cost),
+            Cell::text(item.currency.as_deref().unwrap_or("")),
+            Cell::text(item.low_usd.map(|n| n.to_string()).unwrap_or_default()),
+            Cell::text(item.high_usd.map(|n| n.to_string()).unwrap_or_default()),
+            Cell::text(item.status),
+            Cell::text(item.sponsors.join(", ")),
+            Cell::text(&item.point_of_contact),
+            Cell::text(&item.url),
+            Cell::text(""),
+            Cell::text(""),
+            Cell::text(""),
+            Cell::text(""),
+            Cell::text(""),
+        ]);
+    }
+
+    let overall = [("Total", vec![("", &ledger.totals.overall)])];
+    for (heading, rows) in overall.into_iter().chain(breakdowns(&ledger.totals)) {
+        for (key, total) in rows {
+            table.push_row(vec![
+                Cell::text("total"),
+                Cell::text(""),
+                Cell::text(""),
+                Cell::text(""),
+                Cell::text(""),
+                Cell::text(""),
+                Cell::text(""),
+                Cell::text(total.low_usd.to_string()),
+                Cell::text(total.high_usd.to_string()),
+                Cell::text(""),
+                Cell::text(""),
+                Cell::text(""),
+                Cell::text(""),
+                Cell::text(heading),
+                Cell::text(key),
+                Cell::text(total.items.to_string()),
+                Cell::text(total.tbd.to_string()),
+                Cell::text(total.ask.to_string()),
+            ]);
+        }
+    }
+
+    table.to_csv()
+}
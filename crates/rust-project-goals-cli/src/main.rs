@@ -1,21 +1,26 @@
-use clap::Parser;
+use clap::{CommandFactory, Parser};
 use regex::Regex;
 use rust_project_goals::{
     gh::issue_id::Repository,
     spanned::{Context as _, Result, Spanned},
     util::MILESTONE_REGEX,
 };
+use rust_project_goals_cli::updates;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::process::{Command as ProcessCommand, Stdio};
 use walkdir::WalkDir;
 
 mod cfp;
+mod config;
 mod csv_reports;
+mod feeds;
+mod funding;
+mod opportunities;
 mod review;
 mod rfc;
+mod scaffold;
 mod team_repo;
-mod updates;
 
 #[derive(clap::Parser, Debug)]
 #[structopt(about = "Project goal preprocessor")]
@@ -51,6 +56,44 @@ enum Command {
         dry_run: bool,
     },
 
+    /// Create a new goal document in a milestone from `src/TEMPLATE.md`
+    New {
+        /// Milestone directory for the goal (e.g., `2026`)
+        milestone: String,
+
+        /// File name of the goal, without `.md` (e.g., `cargo-script`)
+        slug: String,
+
+        /// Title of the goal
+        #[arg(long)]
+        title: String,
+
+        /// Point of contact, a GitHub username like `@ghost`
+        #[arg(long)]
+        poc: String,
+
+        /// Roadmap theme the goal belongs to; can be repeated
+        #[arg(long = "roadmap")]
+        roadmaps: Vec<String>,
+
+        /// Team ask written as `team` or `team=level` (e.g., `lang=medium`); can be repeated.
+        /// The support level defaults to small.
+        #[arg(long = "team", required = true)]
+        team_asks: Vec<String>,
+
+        /// Break the work items into a `####` subgoal with this title; can be repeated
+        #[arg(long = "subgoal")]
+        subgoals: Vec<String>,
+
+        /// Include a `## Help wanted` section
+        #[arg(long)]
+        help_wanted: bool,
+
+        /// Include a `## Funding` section
+        #[arg(long)]
+        funding: bool,
+    },
+
     /// Use `gh` CLI tool to create issues on the rust-lang/goals repository
     Issues {
         path: PathBuf,
@@ -77,7 +120,12 @@ enum Command {
     },
 
     /// Checks that the goal documents are well-formed, intended for use within CI
-    Check {},
+    Check {
+        /// Also compare the task statuses in each goal document against
+        /// the checkboxes of its tracking issue (requires `gh`).
+        #[arg(long)]
+        online: bool,
+    },
 
     /// Generate markdown with the list of updates for each tracking issue.
     /// Collects goal updates.
@@ -90,13 +138,21 @@ enum Command {
         milestone: String,
 
         /// Open the generated summary in vscode.
-        #[arg(long)]
+        #[arg(long, conflicts_with = "output_dir")]
         vscode: bool,
 
         /// If specified, write the output into the given file.
-        #[arg(long)]
+        #[arg(long, conflicts_with = "output_dir")]
         output_file: Option<PathBuf>,
 
+        /// Output format.
+        #[arg(long, value_enum, default_value_t)]
+        format: updates::UpdatesFormat,
+
+        /// With `--format email`, the directory to write the `.eml` digests into.
+        #[arg(long, required_if_eq("format", "email"), requires = "format")]
+        output_dir: Option<PathBuf>,
+
         /// Start date for comments.
         /// This is the first date from which comments will be picked up.
         /// If not given, defaults to 1 week before the start of this month.
@@ -107,9 +163,27 @@ enum Command {
         /// If not given, no end date.
         end_date: Option<chrono::NaiveDate>,
 
-        /// Filter to only include goals that have a champion from the specified team.
-        #[arg(long)]
-        with_champion_from: Option<String>,
+        #[command(flatten)]
+        options: updates::UpdatesOptions,
+    },
+
+    /// Write Atom feeds of the updates on the milestone's tracking issues into the book
+    /// output: one per goal, per team (the goals it champions), per roadmap theme, and one
+    /// for all goals.
+    Feeds {
+        /// Milestone whose goals to include (e.g., `2026`).
+        milestone: String,
+
+        /// Directory of the rendered book; feeds are written to `<milestone>/feeds` within it.
+        #[arg(long, default_value = "book/html")]
+        output_dir: PathBuf,
+    },
+
+    /// Inspect the configuration, layered from `rust-project-goals.toml`, the
+    /// `[preprocessor.goals]` table of `book.toml`, and `RUST_PROJECT_GOALS_CONFIG`
+    Config {
+        #[command(subcommand)]
+        cmd: ConfigCommand,
     },
 
     /// Generate various CSV reports
@@ -118,6 +192,41 @@ enum Command {
         cmd: CSVReports,
     },
 
+    /// Generate a ledger of the funding asks of goals across one or more milestones,
+    /// with totals by roadmap, funding status, sponsor, and funding contact.
+    Funding {
+        /// Milestones to include (e.g., `2025h2 2026`).
+        #[arg(required = true)]
+        milestones: Vec<String>,
+
+        /// Output format.
+        #[arg(long, value_enum, default_value_t)]
+        format: funding::FundingFormat,
+
+        /// If specified, write the output into the given file.
+        #[arg(long)]
+        output_file: Option<PathBuf>,
+    },
+
+    /// Export the tasks that goals of a milestone are looking for help with,
+    /// from their `## Help wanted` sections and from status updates.
+    Opportunities {
+        /// Milestone whose goals to include (e.g., `2026`).
+        milestone: String,
+
+        /// Only include matching opportunities, e.g. `level=beginner, max-weeks=4`.
+        #[arg(long)]
+        filter: Option<String>,
+
+        /// Output format.
+        #[arg(long, value_enum, default_value_t)]
+        format: opportunities::OpportunitiesFormat,
+
+        /// If specified, write the output into the given file.
+        #[arg(long)]
+        output_file: Option<PathBuf>,
+    },
+
     /// Generate a markdown summary for a team to review their goals
     Review {
         /// The team name (e.g., "lang", "compiler", "cargo")
@@ -129,6 +238,15 @@ enum Command {
     },
 }
 
+#[derive(clap::Subcommand, Debug)]
+enum ConfigCommand {
+    /// Print the effective configuration as TOML
+    Show,
+
+    /// Check that the configuration is valid, intended for use within CI
+    Validate,
+}
+
 #[derive(clap::Subcommand, Debug)]
 #[allow(dead_code)]
 enum CSVReports {
@@ -154,14 +272,36 @@ fn main() -> Result<()> {
             cfp::create_cfp(timeframe, *force, *dry_run)?;
         }
 
-        Command::Check {} => {
-            check()?;
+        Command::Check { online } => {
+            check(&opt.repository, *online)?;
         }
 
         Command::RFC { path } => {
             rfc::generate_rfc(&path)?;
         }
 
+        Command::New {
+            milestone,
+            slug,
+            title,
+            poc,
+            roadmaps,
+            team_asks,
+            subgoals,
+            help_wanted,
+            funding,
+        } => scaffold::new_goal(
+            milestone,
+            slug,
+            title,
+            poc,
+            roadmaps,
+            team_asks,
+            subgoals,
+            *help_wanted,
+            *funding,
+        )?,
+
         Command::Issues {
             path,
             commit,
@@ -185,9 +325,11 @@ fn main() -> Result<()> {
             milestone,
             vscode,
             output_file,
+            format: updates::UpdatesFormat::Markdown,
+            output_dir: None,
             start_date,
             end_date,
-            with_champion_from,
+            options,
         } => generate_updates(
             &opt.repository,
             milestone,
@@ -195,18 +337,77 @@ fn main() -> Result<()> {
             start_date.as_ref(),
             end_date.as_ref(),
             *vscode,
-            with_champion_from.as_deref(),
+            options,
         )?,
 
+        Command::Updates {
+            format: updates::UpdatesFormat::Markdown,
+            output_dir: Some(_),
+            ..
+        } => Opt::command()
+            .error(
+                clap::error::ErrorKind::ArgumentConflict,
+                "`--output-dir` can only be used with `--format email`",
+            )
+            .exit(),
+
+        Command::Updates {
+            milestone,
+            format: updates::UpdatesFormat::Email,
+            output_dir,
+            start_date,
+            end_date,
+            options,
+            ..
+        } => generate_email_digests(
+            &opt.repository,
+            milestone,
+            output_dir
+                .as_deref()
+                .expect("clap requires `--output-dir` with `--format email`"),
+            start_date.as_ref(),
+            end_date.as_ref(),
+            options,
+        )?,
+
+        Command::Feeds {
+            milestone,
+            output_dir,
+        } => feeds::feeds(&opt.repository, milestone, output_dir)?,
+
+        Command::Config { cmd } => match cmd {
+            ConfigCommand::Show => config::show()?,
+            ConfigCommand::Validate => config::validate()?,
+        },
+
         Command::CSV { cmd } => csv_reports::csv(&opt.repository, cmd)?,
 
+        Command::Funding {
+            milestones,
+            format,
+            output_file,
+        } => funding::funding(&opt.repository, milestones, *format, output_file.as_deref())?,
+
+        Command::Opportunities {
+            milestone,
+            filter,
+            format,
+            output_file,
+        } => opportunities::export(
+            &opt.repository,
+            milestone,
+            filter.as_deref(),
+            *format,
+            output_file.as_deref(),
+        )?,
+
         Command::Review { team, milestone } => review::review(team, milestone.as_deref())?,
     }
 
     Ok(())
 }
 
-fn check() -> Result<()> {
+fn check(repository: &Repository, online: bool) -> Result<()> {
     // Let's find directories named like goal periods (`2024h2` or `2026`), and load goals from
     // them.
     let regex = Regex::new(MILESTONE_REGEX)?;
@@ -230,6 +431,17 @@ fn check() -> Result<()> {
         let roadmaps = rust_project_goals::goal::roadmaps_in_dir(entry.path())?;
         rust_project_goals::goal::validate_username_consistency(&goals)?;
         rust_project_goals::goal::validate_roadmap_references(&goals, &roadmaps)?;
+        rust_project_goals::goal::validate_dependencies(&goals)?;
+        rust_project_goals::goal::validate_timespans(&goals)?;
+        rust_project_goals::goal::validate_sponsors(&goals)?;
+        rust_project_goals::goal::validate_highlights_and_needs(&goals)?;
+        rust_project_goals::goal::validate_champions(&goals)?;
+
+        if online {
+            let issues =
+                rust_project_goals::gh::issues::list_issues_in_milestone(repository, name)?;
+            rust_project_goals::goal::validate_progress_against_issues(&goals, &issues)?;
+        }
     }
 
     Ok(())
@@ -242,7 +454,7 @@ fn generate_updates(
     start_date: Option<&chrono::NaiveDate>,
     end_date: Option<&chrono::NaiveDate>,
     vscode: bool,
-    with_champion_from: Option<&str>,
+    options: &updates::UpdatesOptions,
 ) -> Result<()> {
     if output_file.is_none() && !vscode {
         rust_project_goals::spanned::bail_here!(
@@ -255,14 +467,7 @@ fn generate_updates(
 
     // Generate the updates content using the library function with progress bar
     let output = updates::render_updates(
-        &issues,
-        repository,
-        milestone,
-        start_date,
-        end_date,
-        with_champion_from,
-        true,
-        updates::Order::default(),
+        &issues, repository, milestone, start_date, end_date, options, true,
     )?;
 
     // Make sure the `<pre>` code blocks have at least one empty line
@@ -298,3 +503,31 @@ fn generate_updates(
 
     Ok(())
 }
+
+fn generate_email_digests(
+    repository: &Repository,
+    milestone: &str,
+    output_dir: &Path,
+    start_date: Option<&chrono::NaiveDate>,
+    end_date: Option<&chrono::NaiveDate>,
+    options: &updates::UpdatesOptions,
+) -> Result<()> {
+    let issues = rust_project_goals::gh::issues::list_issues_in_milestone(repository, milestone)?;
+    let digests = updates::render_email_digests(
+        &issues, repository, milestone, start_date, end_date, options, true,
+    )?;
+
+    std::fs::create_dir_all(output_dir)
+        .with_path_context(output_dir, "failed to create directory")?;
+    for (file_name, email) in &digests {
+        let path = output_dir.join(file_name);
+        std::fs::write(&path, email).with_path_context(&path, "failed to write")?;
+    }
+    eprintln!(
+        "Wrote {} digests to {}",
+        digests.len(),
+        output_dir.display()
+    );
+
+    Ok(())
+}
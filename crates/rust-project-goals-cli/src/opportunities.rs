@@ -0,0 +1,60 @@
+//! Export the contributor opportunities of a milestone.
+
+use std::path::{Path, PathBuf};
+
+use rust_project_goals::{
+    gh::{issue_id::Repository, issues::list_issues_in_milestone},
+    goal::{self, GoalDocument},
+    opportunities::{self, Opportunity, OpportunityFilter},
+    spanned::{Context as _, Result},
+};
+
+/// Output format of the opportunities export.
+#[derive(clap::ValueEnum, Copy, Clone, Debug, Default)]
+pub enum OpportunitiesFormat {
+    #[default]
+    Markdown,
+    Json,
+    Atom,
+}
+
+pub fn export(
+    repository: &Repository,
+    milestone: &str,
+    filter: Option<&str>,
+    format: OpportunitiesFormat,
+    output_file: Option<&Path>,
+) -> Result<()> {
+    let filter = OpportunityFilter::parse(filter.unwrap_or(""))?;
+
+    let milestone_path = PathBuf::from("src").join(milestone);
+    let goals = goal::goals_in_dir(&milestone_path)?;
+    let goals: Vec<&GoalDocument> = goals
+        .iter()
+        .filter(|g| g.metadata.status.content.is_not_not_accepted())
+        .collect();
+    let issues = list_issues_in_milestone(repository, milestone)?;
+
+    let mut all = opportunities::opportunities(milestone, &goals, &issues);
+    all.sort_by_key(|o| o.goal_title.to_lowercase());
+    let matching: Vec<&Opportunity> = all.iter().filter(|o| filter.matches(o)).collect();
+
+    let output = match format {
+        OpportunitiesFormat::Markdown => opportunities::format_opportunities(&matching),
+        OpportunitiesFormat::Json => {
+            serde_json::to_string_pretty(&opportunities::opportunities_json(milestone, &matching))?
+        }
+        OpportunitiesFormat::Atom => {
+            let now = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+            opportunities::opportunities_feed(milestone, &matching, &now).render()
+        }
+    };
+
+    if let Some(output_file) = output_file {
+        std::fs::write(output_file, output).with_path_context(output_file, "failed to write")?;
+    } else {
+        println!("{output}");
+    }
+
+    Ok(())
+}
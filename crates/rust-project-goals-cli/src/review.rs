@@ -8,6 +8,7 @@ use regex::Regex;
 use rust_project_goals::{
     goal::{GoalDocument, SupportLevel},
     spanned::Result,
+    table::{Cell, Table},
     team::{get_team_name, TeamName},
     util::MILESTONE_REGEX,
 };
@@ -194,10 +195,8 @@ fn format_review(
 
         // Team champion for this team specifically
         let needs_champion = goal.team_involvement.as_support().map_or(false, |s| {
-            s.iter().any(|s| {
-                s.team == team
-                    && matches!(s.support_level, SupportLevel::Large | SupportLevel::Medium)
-            })
+            s.iter()
+                .any(|s| s.team == team && s.support_level.requires_champion())
         });
         let champion = goal.metadata.champions.get(team);
         if let Some(champion) = champion {
@@ -237,8 +236,6 @@ fn format_team_table(
     team: &'static TeamName,
     milestone: &str,
 ) -> Result<String> {
-    let mut output = String::new();
-
     // Collect entries
     let mut entries: Vec<(&GoalDocument, SupportLevel)> = goals
         .iter()
@@ -248,9 +245,7 @@ fn format_team_table(
     // Sort by support level (Large first)
     entries.sort_by_key(|(_, level)| std::cmp::Reverse(*level));
 
-    // Write the table
-    writeln!(output, "| Goal | Level | Champion | Notes |")?;
-    writeln!(output, "| :--- | :---- | :------- | :---- |")?;
+    let mut table = Table::new(["Goal", "Level", "Champion", "Notes"]);
 
     for (goal, level) in entries {
         let champion = goal
@@ -268,18 +263,18 @@ fn format_team_table(
             notes
         };
 
-        writeln!(
-            output,
-            "| [{}]({}) | {} | {} | {} |",
-            goal.metadata.short_title.content,
-            goal_url(goal, milestone),
-            level,
-            champion,
-            notes_display,
-        )?;
+        table.push_row(vec![
+            Cell::link(
+                &goal.metadata.short_title.content,
+                goal_url(goal, milestone),
+            ),
+            Cell::text(level.to_string()),
+            Cell::markdown(champion),
+            Cell::markdown(notes_display),
+        ]);
     }
 
-    Ok(output)
+    Ok(table.to_markdown())
 }
 
 /// Normalize a roadmap name to (display_name, slug).
@@ -378,8 +373,6 @@ fn format_by_champion_table(
 ) -> Result<String> {
     use std::collections::BTreeMap;
 
-    let mut output = String::new();
-
     // Group goals by champion
     let mut by_champion: BTreeMap<String, Vec<(&GoalDocument, SupportLevel)>> = BTreeMap::new();
 
@@ -404,9 +397,7 @@ fn format_by_champion_table(
         goals.sort_by_key(|(_, level)| std::cmp::Reverse(*level));
     }
 
-    // Write the table
-    writeln!(output, "| Champion | Goal | Level |")?;
-    writeln!(output, "| :------- | :--- | :---- |")?;
+    let mut table = Table::new(["Champion", "Goal", "Level"]);
 
     // Sort champions: "(no champion)" last, then alphabetically
     let mut champions: Vec<_> = by_champion.keys().collect();
@@ -420,20 +411,24 @@ fn format_by_champion_table(
         let goals = &by_champion[champion];
         for (i, (goal, level)) in goals.iter().enumerate() {
             // Only show champion name on first row for that champion
-            let champion_cell = if i == 0 { champion.as_str() } else { "" };
+            let champion_cell = if i == 0 {
+                Cell::markdown(champion)
+            } else {
+                Cell::Empty
+            };
 
-            writeln!(
-                output,
-                "| {} | [{}]({}) | {} |",
+            table.push_row(vec![
                 champion_cell,
-                goal.metadata.short_title.content,
-                goal_url(goal, milestone),
-                level,
-            )?;
+                Cell::link(
+                    &goal.metadata.short_title.content,
+                    goal_url(goal, milestone),
+                ),
+                Cell::text(level.to_string()),
+            ]);
         }
     }
 
-    Ok(output)
+    Ok(table.to_markdown())
 }
 
 /// Get the support level for a specific team from a goal.
@@ -12,16 +12,20 @@ use rust_project_goals::{
     gh::{
         issue_id::{IssueId, Repository},
         issues::{
-            change_milestone, change_title, create_comment, create_issue, fetch_issue,
-            list_issues_in_milestone, lock_issue, sync_assignees, sync_labels, update_issue_body,
-            CONTINUING_GOAL_PREFIX, LOCK_TEXT, ROADMAP_LABEL,
+            add_sub_issue, change_milestone, change_title, close_issue, create_comment,
+            create_issue, fetch_issue, list_issues, list_issues_in_milestone, lock_issue,
+            sync_assignees, sync_labels, update_issue_body, ExistingGithubIssue,
+            CONTINUING_GOAL_PREFIX, GOOD_FIRST_ISSUE_LABEL, HELP_WANTED_LABEL, LOCK_TEXT,
+            ROADMAP_LABEL,
         },
         labels::GhLabel,
     },
-    goal::{self, GoalDocument, GoalPlan, ParsedOwners},
+    goal::{self, ExperienceLevel, GoalDocument, GoalPlan, HelpWantedItem, ParsedOwners},
     spanned::{self, Context, Error, Result, Spanned},
     team::{get_person_data, TeamName},
+    util::slug,
 };
+use rust_project_goals_json::GithubIssueState;
 
 fn validate_path(path: &Path) -> Result<String> {
     if !path.is_dir() {
@@ -315,6 +319,11 @@ pub fn generate_issues(
         let teams_with_asks = teams_with_asks(&goal_documents);
         let mut actions = initialize_labels(repository, &teams_with_asks)?;
         actions.extend(initialize_issues(repository, &timeframe, &goal_documents)?);
+        actions.extend(initialize_help_wanted_issues(
+            repository,
+            &timeframe,
+            &goal_documents,
+        )?);
 
         if actions.is_empty() {
             return Ok(());
@@ -384,6 +393,17 @@ pub struct GithubIssue<'doc> {
     pub goal_document: &'doc GoalDocument,
 }
 
+/// An issue for one row of a goal's `## Help wanted` section.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HelpWantedIssue {
+    pub title: String,
+    pub body: String,
+    pub labels: Vec<String>,
+
+    /// The tracking issue of the goal; the help wanted issue is made a sub-issue of it.
+    pub tracking_issue: u64,
+}
+
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 enum GithubAction<'doc> {
     CreateLabel {
@@ -394,6 +414,15 @@ enum GithubAction<'doc> {
         issue: GithubIssue<'doc>,
     },
 
+    CreateHelpWantedIssue {
+        issue: HelpWantedIssue,
+    },
+
+    CloseIssue {
+        number: u64,
+        comment: String,
+    },
+
     ChangeTitle {
         number: u64,
         title: String,
@@ -462,6 +491,17 @@ fn initialize_labels(
         color: "5319E7".to_string(),
     });
 
+    // These are GitHub's default labels, with their default colors.
+    desired_labels.insert(GhLabel {
+        name: HELP_WANTED_LABEL.to_string(),
+        color: "008672".to_string(),
+    });
+
+    desired_labels.insert(GhLabel {
+        name: GOOD_FIRST_ISSUE_LABEL.to_string(),
+        color: "7057ff".to_string(),
+    });
+
     for existing_label in GhLabel::list(repository)? {
         desired_labels.remove(&existing_label);
     }
@@ -578,28 +618,7 @@ fn initialize_issues<'doc>(
                     });
                 }
 
-                // Compare labels - convert existing issue labels to strings for comparison
-                let existing_label_names: BTreeSet<String> = existing_issue
-                    .labels
-                    .iter()
-                    .map(|label| label.name.clone())
-                    .collect();
-                let desired_label_names: BTreeSet<String> =
-                    desired_issue.labels.iter().cloned().collect();
-
-                if existing_label_names != desired_label_names {
-                    actions.insert(GithubAction::SyncLabels {
-                        number: existing_issue.number,
-                        remove_labels: existing_label_names
-                            .difference(&desired_label_names)
-                            .cloned()
-                            .collect(),
-                        add_labels: desired_label_names
-                            .difference(&existing_label_names)
-                            .cloned()
-                            .collect(),
-                    });
-                }
+                actions.extend(sync_labels_action(&existing_issue, &desired_issue.labels));
 
                 if existing_issue.title != desired_issue.title {
                     actions.insert(GithubAction::ChangeTitle {
@@ -659,6 +678,250 @@ fn initialize_issues<'doc>(
     Ok(actions)
 }
 
+/// Returns the action to bring the labels of `existing_issue` in line with `desired_labels`, if any.
+/// Only the labels this tool assigns are added or removed; labels added by hand are kept.
+fn sync_labels_action(
+    existing_issue: &ExistingGithubIssue,
+    desired_labels: &[String],
+) -> Option<GithubAction<'static>> {
+    // Compare labels - convert existing issue labels to strings for comparison
+    let existing_label_names: BTreeSet<String> = existing_issue
+        .labels
+        .iter()
+        .map(|label| label.name.clone())
+        .filter(|name| is_managed_label(name))
+        .collect();
+    let desired_label_names: BTreeSet<String> = desired_labels.iter().cloned().collect();
+
+    if existing_label_names == desired_label_names {
+        return None;
+    }
+
+    Some(GithubAction::SyncLabels {
+        number: existing_issue.number,
+        remove_labels: existing_label_names
+            .difference(&desired_label_names)
+            .cloned()
+            .collect(),
+        add_labels: desired_label_names
+            .difference(&existing_label_names)
+            .cloned()
+            .collect(),
+    })
+}
+
+/// Whether `name` is one of the labels that this tool assigns to the issues it creates.
+fn is_managed_label(name: &str) -> bool {
+    name.starts_with("T-")
+        || [
+            "C-tracking-issue",
+            ROADMAP_LABEL,
+            HELP_WANTED_LABEL,
+            GOOD_FIRST_ISSUE_LABEL,
+        ]
+        .contains(&name)
+}
+
+/// Start of the hidden marker that identifies the help wanted issues we created.
+const HELP_WANTED_MARKER_PREFIX: &str = "<!-- rust-project-goals help wanted: ";
+
+/// Creates a `help wanted` issue for each row of the goals' `## Help wanted` sections,
+/// keeps their titles and labels in sync, and closes the ones whose row was removed.
+///
+/// The issues are not added to the milestone, so that they are not mistaken for
+/// tracking issues; instead, each is made a sub-issue of its goal's tracking issue.
+/// Issues that were closed (e.g., because the task was done) are left alone.
+fn initialize_help_wanted_issues<'doc>(
+    repository: &Repository,
+    timeframe: &str,
+    goal_documents: &'doc [GoalDocument],
+) -> Result<BTreeSet<GithubAction<'doc>>> {
+    let existing_issues = list_issues(repository, &[("-l", HELP_WANTED_LABEL)])?;
+    Ok(help_wanted_actions(
+        timeframe,
+        goal_documents,
+        &existing_issues,
+    ))
+}
+
+/// The actions that bring `existing_issues` in line with the goals' help wanted rows.
+///
+/// Each row is matched to the issue carrying its marker. If a goal is left with exactly
+/// one unmatched row and one open issue whose row is gone, the row was reworded: that issue
+/// gets the new title and body. Otherwise, unmatched rows get new issues and the issues
+/// of removed rows are closed.
+fn help_wanted_actions<'doc>(
+    timeframe: &str,
+    goal_documents: &'doc [GoalDocument],
+    existing_issues: &[ExistingGithubIssue],
+) -> BTreeSet<GithubAction<'doc>> {
+    let mut actions = BTreeSet::new();
+
+    // Issues that belong to a current row, and so must not be closed.
+    let mut kept_issues = BTreeSet::new();
+
+    for document in goal_documents {
+        // A goal without a tracking issue gets one first; its help wanted issues
+        // are created on the next iteration, once the tracking issue is linked.
+        let Some(tracking_issue) = &document.metadata.tracking_issue else {
+            continue;
+        };
+
+        let desired_issues: Vec<(String, HelpWantedIssue)> = document
+            .help_wanted
+            .iter()
+            .map(|item| {
+                (
+                    help_wanted_marker(timeframe, document, item),
+                    help_wanted_issue(timeframe, document, tracking_issue, item),
+                )
+            })
+            .collect();
+
+        let mut unmatched_issues = vec![];
+        for (marker, desired_issue) in desired_issues.iter() {
+            let Some(existing_issue) = existing_issues
+                .iter()
+                .find(|issue| issue.body.contains(marker))
+            else {
+                unmatched_issues.push(desired_issue);
+                continue;
+            };
+
+            kept_issues.insert(existing_issue.number);
+            if existing_issue.state == GithubIssueState::Closed {
+                continue;
+            }
+
+            if existing_issue.title != desired_issue.title {
+                actions.insert(GithubAction::ChangeTitle {
+                    number: existing_issue.number,
+                    title: desired_issue.title.clone(),
+                });
+            }
+
+            actions.extend(sync_labels_action(existing_issue, &desired_issue.labels));
+        }
+
+        let goal_prefix = help_wanted_goal_prefix(timeframe, document);
+        let removed_issues: Vec<&ExistingGithubIssue> = existing_issues
+            .iter()
+            .filter(|issue| {
+                issue.state == GithubIssueState::Open
+                    && issue.body.contains(&goal_prefix)
+                    && !kept_issues.contains(&issue.number)
+            })
+            .collect();
+
+        match (&unmatched_issues[..], &removed_issues[..]) {
+            ([desired_issue], [existing_issue]) => {
+                kept_issues.insert(existing_issue.number);
+                if existing_issue.title != desired_issue.title {
+                    actions.insert(GithubAction::ChangeTitle {
+                        number: existing_issue.number,
+                        title: desired_issue.title.clone(),
+                    });
+                }
+                actions.insert(GithubAction::UpdateIssueBody {
+                    number: existing_issue.number,
+                    body: desired_issue.body.clone(),
+                });
+                actions.extend(sync_labels_action(existing_issue, &desired_issue.labels));
+            }
+            _ => {
+                for desired_issue in unmatched_issues {
+                    actions.insert(GithubAction::CreateHelpWantedIssue {
+                        issue: desired_issue.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    let timeframe_prefix = format!("{HELP_WANTED_MARKER_PREFIX}{timeframe}/");
+    for existing_issue in existing_issues {
+        if existing_issue.state == GithubIssueState::Open
+            && existing_issue.body.contains(&timeframe_prefix)
+            && !kept_issues.contains(&existing_issue.number)
+        {
+            actions.insert(GithubAction::CloseIssue {
+                number: existing_issue.number,
+                comment: "This task is no longer listed in the goal's help wanted section."
+                    .to_string(),
+            });
+        }
+    }
+
+    actions
+}
+
+/// Start of the markers of all help wanted issues of `document`.
+fn help_wanted_goal_prefix(timeframe: &str, document: &GoalDocument) -> String {
+    format!(
+        "{HELP_WANTED_MARKER_PREFIX}{timeframe}/{goal_file}#",
+        goal_file = document.link_path.display(),
+    )
+}
+
+/// Hidden marker that identifies the help wanted issue of a row of the goal's
+/// `## Help wanted` table by a slug of its task, e.g.
+/// `<!-- rust-project-goals help wanted: 2026/rtn.md#port-the-lint -->`.
+/// Unlike the row's position, the slug does not change when other rows are added or removed.
+fn help_wanted_marker(timeframe: &str, document: &GoalDocument, item: &HelpWantedItem) -> String {
+    format!(
+        "{prefix}{task} -->",
+        prefix = help_wanted_goal_prefix(timeframe, document),
+        task = slug(&item.task),
+    )
+}
+
+fn help_wanted_issue(
+    timeframe: &str,
+    document: &GoalDocument,
+    tracking_issue: &IssueId,
+    item: &HelpWantedItem,
+) -> HelpWantedIssue {
+    let task = item.task.trim();
+
+    let mut labels = vec![HELP_WANTED_LABEL.to_string()];
+    if item.experience_level == ExperienceLevel::Beginner {
+        labels.push(GOOD_FIRST_ISSUE_LABEL.to_string());
+    }
+    for team in document.teams_with_asks() {
+        labels.push(team.gh_label());
+    }
+
+    let body = format!(
+        r##"{task}
+
+| Metadata         | |
+| --------         | --- |
+| Goal             | {goaldocument} |
+| Tracking issue   | #{tracking_issue} |
+| Point of contact | {poc} |
+| Experience level | {experience_level} |
+| Time investment  | {time_investment} |
+
+This task is listed in the "Help wanted" section of the goal document. If you would like to work on it, please reach out to the point of contact.
+
+{marker}
+"##,
+        goaldocument = goal_document_link(timeframe, document),
+        tracking_issue = tracking_issue.number,
+        poc = &document.metadata.owner_usernames().join(", "),
+        experience_level = item.experience_level,
+        time_investment = item.time_investment,
+        marker = help_wanted_marker(timeframe, document, item),
+    );
+
+    HelpWantedIssue {
+        title: format!("{}: {}", document.metadata.title.content, task),
+        body,
+        labels,
+        tracking_issue: tracking_issue.number,
+    }
+}
+
 fn issue<'doc>(timeframe: &str, document: &'doc GoalDocument) -> Result<GithubIssue<'doc>> {
     let mut assignees = BTreeSet::default();
     for username in document.metadata.owner_usernames() {
@@ -784,6 +1047,16 @@ impl Display for GithubAction<'_> {
             GithubAction::CreateIssue { issue } => {
                 write!(f, "create issue \"{}\"", issue.title,)
             }
+            GithubAction::CreateHelpWantedIssue { issue } => {
+                write!(
+                    f,
+                    "create help wanted issue \"{}\" under #{}",
+                    issue.title, issue.tracking_issue
+                )
+            }
+            GithubAction::CloseIssue { number, comment: _ } => {
+                write!(f, "close issue #{}", number)
+            }
             GithubAction::ChangeMilestone { number, milestone } => {
                 write!(f, "update issue #{} milestone to \"{}\"", number, milestone)
             }
@@ -794,7 +1067,7 @@ impl Display for GithubAction<'_> {
                 write!(f, "post comment on issue #{}: \"{}\"", number, body)
             }
             GithubAction::UpdateIssueBody { number, body: _ } => {
-                write!(f, "update the body on issue #{} for new milestone", number)
+                write!(f, "update the body on issue #{}", number)
             }
             GithubAction::SyncAssignees {
                 number,
@@ -856,14 +1129,42 @@ impl GithubAction<'_> {
                         goal_document,
                     },
             } => {
-                let issue_id =
-                    create_issue(repository, &body, &title, &labels, &assignees, timeframe)?;
+                let issue_id = create_issue(
+                    repository,
+                    &body,
+                    &title,
+                    &labels,
+                    &assignees,
+                    Some(timeframe),
+                )?;
 
                 goal_document.link_issue(issue_id)?;
 
                 Ok(())
             }
 
+            GithubAction::CreateHelpWantedIssue {
+                issue:
+                    HelpWantedIssue {
+                        title,
+                        body,
+                        labels,
+                        tracking_issue,
+                    },
+            } => {
+                let issue_id =
+                    create_issue(repository, &body, &title, &labels, &BTreeSet::new(), None)?;
+
+                add_sub_issue(repository, tracking_issue, issue_id.number)?;
+
+                Ok(())
+            }
+
+            GithubAction::CloseIssue { number, comment } => {
+                close_issue(repository, number, &comment)?;
+                Ok(())
+            }
+
             GithubAction::ChangeMilestone { number, milestone } => {
                 change_milestone(repository, number, &milestone)?;
                 Ok(())
@@ -908,3 +1209,105 @@ impl GithubAction<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rust_project_goals::goal::TimeInvestment;
+
+    use super::*;
+
+    fn goal(tasks: &[&str]) -> GoalDocument {
+        let mut goal = GoalDocument::for_test("2026/rtn.md", "Return type notation");
+        goal.metadata.tracking_issue = Some(IssueId::new(Repository::new("rust-lang", "goals"), 7));
+        goal.help_wanted = tasks
+            .iter()
+            .map(|task| HelpWantedItem {
+                task: task.to_string(),
+                experience_level: ExperienceLevel::Intermediate,
+                time_investment: TimeInvestment::Unspecified,
+            })
+            .collect();
+        goal
+    }
+
+    /// The issues as the previous run created them for the rows of `goal`, numbered from 100.
+    fn existing_issues(goal: &GoalDocument) -> Vec<ExistingGithubIssue> {
+        let tracking_issue = goal.metadata.tracking_issue.as_ref().unwrap();
+        goal.help_wanted
+            .iter()
+            .zip(100..)
+            .map(|(item, number)| {
+                let issue = help_wanted_issue("2026", goal, tracking_issue, item);
+                ExistingGithubIssue {
+                    number,
+                    title: issue.title,
+                    assignees: BTreeSet::new(),
+                    comments: vec![],
+                    body: issue.body,
+                    state: GithubIssueState::Open,
+                    labels: issue
+                        .labels
+                        .into_iter()
+                        .map(|name| GhLabel {
+                            name,
+                            color: String::new(),
+                        })
+                        .collect(),
+                    milestone: None,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn help_wanted_row_removed() {
+        let existing = existing_issues(&goal(&["Write docs", "Port the lint", "Add tests"]));
+        let goals = [goal(&["Write docs", "Add tests"])];
+        let actions = help_wanted_actions("2026", &goals, &existing);
+        assert_eq!(
+            actions,
+            BTreeSet::from([GithubAction::CloseIssue {
+                number: 101,
+                comment: "This task is no longer listed in the goal's help wanted section."
+                    .to_string(),
+            }])
+        );
+    }
+
+    #[test]
+    fn help_wanted_row_reworded() {
+        let existing = existing_issues(&goal(&["Write docs", "Port the lint"]));
+        let goals = [goal(&["Write docs", "Port the lint to the new API"])];
+        let desired = existing_issues(&goals[0]);
+        let actions = help_wanted_actions("2026", &goals, &existing);
+        assert_eq!(
+            actions,
+            BTreeSet::from([
+                GithubAction::ChangeTitle {
+                    number: 101,
+                    title: "Return type notation: Port the lint to the new API".to_string(),
+                },
+                GithubAction::UpdateIssueBody {
+                    number: 101,
+                    body: desired[1].body.clone(),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn help_wanted_row_added() {
+        let existing = existing_issues(&goal(&["Write docs"]));
+        let goals = [goal(&["Port the lint", "Write docs"])];
+        let actions = help_wanted_actions("2026", &goals, &existing);
+        let [GithubAction::CreateHelpWantedIssue { issue }] =
+            &actions.into_iter().collect::<Vec<_>>()[..]
+        else {
+            panic!("expected a single new issue");
+        };
+        assert_eq!(issue.title, "Return type notation: Port the lint");
+        assert!(issue
+            .body
+            .contains("<!-- rust-project-goals help wanted: 2026/rtn.md#port-the-lint -->"));
+    }
+}
@@ -0,0 +1,517 @@
+//! The `new` command: create a goal document from `src/TEMPLATE.md`.
+
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+use rust_project_goals::{
+    goal::{self, GoalDocument, SupportLevel},
+    re,
+    spanned::{self, Context as _, Result, Spanned},
+    team,
+    util::{commas, MILESTONE_REGEX},
+};
+
+const TEMPLATE_PATH: &str = "src/TEMPLATE.md";
+const SUMMARY_PATH: &str = "src/SUMMARY.md";
+
+/// What to fill into the template.
+#[derive(Debug)]
+pub struct NewGoal {
+    pub title: String,
+
+    /// GitHub username, including the `@`
+    pub point_of_contact: String,
+
+    pub roadmaps: Vec<String>,
+
+    /// `(team, support level)` pairs, e.g. `("lang", "Medium")`
+    pub team_asks: Vec<(String, String)>,
+
+    /// Titles of `####` subgoals; if empty, the goal has a single task table
+    pub subgoals: Vec<String>,
+
+    pub help_wanted: bool,
+    pub funding: bool,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn new_goal(
+    milestone: &str,
+    slug: &str,
+    title: &str,
+    point_of_contact: &str,
+    roadmaps: &[String],
+    team_asks: &[String],
+    subgoals: &[String],
+    help_wanted: bool,
+    funding: bool,
+) -> Result<()> {
+    let milestone_regex = Regex::new(MILESTONE_REGEX)?;
+    if !milestone_regex.is_match(milestone) {
+        spanned::bail_here!(
+            "invalid milestone `{}`, expected something like `2026` or `2025h2`",
+            milestone
+        );
+    }
+
+    let milestone_path = PathBuf::from("src").join(milestone);
+    if !milestone_path.is_dir() {
+        spanned::bail_here!(
+            "milestone directory `{}` does not exist; use `cargo rpg cfp` to set it up",
+            milestone_path.display()
+        );
+    }
+
+    if slug.is_empty()
+        || !slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        spanned::bail_here!(
+            "invalid goal name `{}`, expected lowercase letters, digits, and dashes (e.g., `cargo-script`)",
+            slug
+        );
+    }
+    if slug.starts_with("roadmap-") {
+        spanned::bail_here!("goal names cannot start with `roadmap-`, which is used for roadmaps");
+    }
+
+    let goal_path = milestone_path.join(format!("{slug}.md"));
+    if goal_path.exists() {
+        spanned::bail_here!("`{}` already exists", goal_path.display());
+    }
+
+    let point_of_contact = if point_of_contact.starts_with('@') {
+        point_of_contact.to_string()
+    } else {
+        format!("@{point_of_contact}")
+    };
+    if !re::is_just(&re::USERNAME, &point_of_contact) {
+        spanned::bail_here!(
+            "point of contact must be a single GitHub username, found `{}`",
+            point_of_contact
+        );
+    }
+
+    let new_goal = NewGoal {
+        title: title.trim().to_string(),
+        point_of_contact,
+        roadmaps: validate_roadmaps(&milestone_path, roadmaps)?,
+        team_asks: validate_team_asks(team_asks)?,
+        subgoals: subgoals.iter().map(|s| s.trim().to_string()).collect(),
+        help_wanted,
+        funding,
+    };
+
+    let template = std::fs::read_to_string(TEMPLATE_PATH)
+        .with_path_context(Path::new(TEMPLATE_PATH), "reading goal template")?;
+    let content = text_processing::instantiate_template(&template, &new_goal);
+
+    std::fs::write(&goal_path, content).with_path_context(&goal_path, "failed to write")?;
+
+    // Make sure the result parses, so authors start from a valid document.
+    let link_path = PathBuf::from(format!("{slug}.md"));
+    match GoalDocument::load(&goal_path, &link_path) {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            std::fs::remove_file(&goal_path)?;
+            spanned::bail_here!(
+                "generated goal has no metadata table; is `{}` up to date?",
+                TEMPLATE_PATH
+            );
+        }
+        Err(e) => {
+            std::fs::remove_file(&goal_path)?;
+            return Err(e.wrap_str(Spanned::here(
+                "generated goal document is not valid".to_string(),
+            )));
+        }
+    }
+
+    println!("Created {}", goal_path.display());
+
+    // Milestones whose `goals.md` uses `(((GOAL CHAPTERS)))` list their goals in the book
+    // automatically; others need an entry in `SUMMARY.md`.
+    let goals_md = std::fs::read_to_string(milestone_path.join("goals.md")).unwrap_or_default();
+    if !re::GOAL_CHAPTERS.is_match(&goals_md) {
+        let summary = std::fs::read_to_string(SUMMARY_PATH)
+            .with_path_context(Path::new(SUMMARY_PATH), "reading summary")?;
+        match text_processing::add_goal_to_summary(&summary, milestone, slug, &new_goal.title) {
+            Some(summary) => {
+                std::fs::write(SUMMARY_PATH, summary)
+                    .with_path_context(Path::new(SUMMARY_PATH), "failed to write")?;
+                println!("Added the goal to {SUMMARY_PATH}");
+            }
+            None => eprintln!(
+                "warning: could not find `./{milestone}/goals.md` in {SUMMARY_PATH}; \
+                 add the goal to it by hand"
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that each roadmap is the short title of a `roadmap-*.md` file in the milestone.
+fn validate_roadmaps(milestone_path: &Path, roadmaps: &[String]) -> Result<Vec<String>> {
+    if roadmaps.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let titles: Vec<String> = goal::roadmaps_in_dir(milestone_path)?
+        .iter()
+        .map(|r| r.short_title.content.trim().to_string())
+        .collect();
+
+    for roadmap in roadmaps {
+        if !titles.iter().any(|t| t == roadmap.trim()) {
+            spanned::bail_here!(
+                "roadmap `{}` does not match any `roadmap-*.md` short title; available roadmaps: {}",
+                roadmap,
+                titles.join(", "),
+            );
+        }
+    }
+
+    Ok(roadmaps.iter().map(|r| r.trim().to_string()).collect())
+}
+
+/// Parse team asks written as `team` or `team=level` (e.g. `lang=medium`),
+/// checking the team against the Rust teams. The level defaults to the smallest
+/// configured support level.
+fn validate_team_asks(team_asks: &[String]) -> Result<Vec<(String, String)>> {
+    let mut result = vec![];
+
+    for team_ask in team_asks {
+        let (team_name, level) = match team_ask.split_once('=') {
+            Some((team_name, level)) => (team_name, Some(level)),
+            None => (team_ask.as_str(), None),
+        };
+        let team_name = team_name
+            .trim()
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .to_lowercase();
+
+        if team::get_team_name(&team_name)?.is_none() {
+            spanned::bail_here!(
+                "no Rust team named `{}` found (valid names are {})",
+                team_name,
+                commas(team::get_team_names()?),
+            );
+        }
+
+        let level = match level {
+            Some(level) => match SupportLevel::named(level) {
+                Some(level) => level,
+                None => spanned::bail_here!(
+                    "unrecognized support level `{}` for team `{}`, expected one of: {}",
+                    level.trim().to_lowercase(),
+                    team_name,
+                    SupportLevel::all()
+                        .map(|level| level.as_str().to_lowercase())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            },
+            None => match SupportLevel::all().next() {
+                Some(level) => level,
+                None => spanned::bail_here!("no support levels are configured"),
+            },
+        };
+
+        result.push((team_name, level.to_string()));
+    }
+
+    Ok(result)
+}
+
+/// Pure functions for producing the goal document and `SUMMARY.md` entry.
+pub mod text_processing {
+    use std::fmt::Write as _;
+
+    use super::*;
+
+    /// Fill the goal template in. The instructions at the top are dropped, the tables are
+    /// replaced with ones built from `goal`, and the `Help wanted` and `Funding` sections
+    /// are kept only if requested.
+    pub fn instantiate_template(template: &str, goal: &NewGoal) -> String {
+        let mut output = String::new();
+        let mut section = "";
+        let mut lines = template.lines().peekable();
+
+        while let Some(line) = lines.next() {
+            if line.starts_with("# ") {
+                writeln!(output, "# {}", goal.title).unwrap();
+                continue;
+            }
+
+            if let Some(heading) = line.strip_prefix("## ") {
+                section = heading.trim();
+            }
+
+            let keep = match section {
+                // The instructions before the first section are about copying the template.
+                "" => !line.starts_with('>'),
+                "Help wanted" => goal.help_wanted,
+                "Funding" => goal.funding,
+                _ => true,
+            };
+            if !keep {
+                continue;
+            }
+
+            if line.starts_with('|') {
+                let mut table = vec![line];
+                while let Some(row) = lines.next_if(|l| l.starts_with('|')) {
+                    table.push(row);
+                }
+                output.push_str(&replace_table(&table, goal));
+                continue;
+            }
+
+            output.push_str(line);
+            output.push('\n');
+        }
+
+        collapse_blank_lines(&output)
+    }
+
+    /// Build the table to use in place of the template table `table`,
+    /// based on its first header cell.
+    fn replace_table(table: &[&str], goal: &NewGoal) -> String {
+        let headers: Vec<&str> = table[0]
+            .trim_matches('|')
+            .split('|')
+            .map(|h| h.trim())
+            .collect();
+
+        let row = |cells: &[&str]| -> Vec<String> { cells.iter().map(|c| c.to_string()).collect() };
+
+        match headers.as_slice() {
+            ["Metadata", ..] => {
+                let mut rows = vec![
+                    row(&["Metadata", ""]),
+                    row(&["Point of contact", &goal.point_of_contact]),
+                    row(&["Status", "Proposed"]),
+                    row(&[goal::TRACKING_ISSUE_ROW, ""]),
+                    row(&["Zulip channel", "N/A"]),
+                ];
+                for roadmap in &goal.roadmaps {
+                    rows.push(row(&["Roadmap", roadmap]));
+                }
+                format_table(&rows)
+            }
+
+            ["Task", "Owner(s)", ..] => {
+                let task_table = format_table(&[
+                    row(&["Task", "Owner(s)", "Notes"]),
+                    row(&["Do the work", &goal.point_of_contact, ""]),
+                ]);
+
+                if goal.subgoals.is_empty() {
+                    return task_table;
+                }
+
+                goal.subgoals
+                    .iter()
+                    .map(|subgoal| format!("#### {subgoal}\n\n{task_table}"))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+
+            ["Team", "Support level", ..] => {
+                let mut rows = vec![row(&["Team", "Support level", "Notes"])];
+                for (team, level) in &goal.team_asks {
+                    rows.push(row(&[&format!("[{team}]"), level, ""]));
+                }
+                format_table(&rows)
+            }
+
+            ["Task", "Experience level", ..] => format_table(&[
+                row(&["Task", "Experience level", "Time investment"]),
+                row(&["*Describe the task*", "TBD", "TBD"]),
+            ]),
+
+            ["Purpose", "Cost", ..] => format_table(&[
+                row(&["Purpose", "Cost", "Funded", "Sponsor(s)"]),
+                row(&["*Describe what the funding would cover*", "TBD", "No", ""]),
+            ]),
+
+            // Unknown tables are copied as they are.
+            _ => table.iter().map(|l| format!("{l}\n")).collect(),
+        }
+    }
+
+    /// Format a markdown table whose first row is the header, padding the columns
+    /// to line up like the tables in the template.
+    fn format_table(rows: &[Vec<String>]) -> String {
+        let mut widths = vec![0; rows[0].len()];
+        for row in rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count()).max(3);
+            }
+        }
+
+        let mut output = String::new();
+        let mut write_row = |cells: Vec<String>| {
+            for (cell, width) in cells.iter().zip(&widths) {
+                write!(output, "| {cell:<width$} ").unwrap();
+            }
+            output.push_str("|\n");
+        };
+
+        write_row(rows[0].clone());
+        write_row(widths.iter().map(|w| "-".repeat(*w)).collect());
+        for row in &rows[1..] {
+            write_row(row.clone());
+        }
+
+        output
+    }
+
+    /// Replace runs of blank lines with a single blank line.
+    fn collapse_blank_lines(text: &str) -> String {
+        let mut output = String::new();
+        let mut last_was_empty = false;
+        for line in text.lines() {
+            let is_empty = line.trim().is_empty();
+            if !is_empty || !last_was_empty {
+                output.push_str(line);
+                output.push('\n');
+            }
+            last_was_empty = is_empty;
+        }
+        output
+    }
+
+    /// Add a `- [title](./milestone/slug.md)` entry beneath the milestone's `goals.md` entry
+    /// (after any existing entries there). Returns `None` if there is no such entry.
+    pub fn add_goal_to_summary(
+        summary: &str,
+        milestone: &str,
+        slug: &str,
+        title: &str,
+    ) -> Option<String> {
+        let lines: Vec<&str> = summary.lines().collect();
+        let goals_link = format!("(./{milestone}/goals.md)");
+
+        let goals_index = lines.iter().position(|l| l.contains(&goals_link))?;
+        let goals_line = lines[goals_index];
+        let indent = &goals_line[..goals_line.len() - goals_line.trim_start().len()];
+        let bullet = goals_line.trim_start().chars().next().unwrap_or('-');
+
+        // Skip over the entries that are already nested beneath `goals.md`.
+        let mut insert_at = goals_index + 1;
+        while insert_at < lines.len() {
+            let line = lines[insert_at];
+            let line_indent = line.len() - line.trim_start().len();
+            if line.trim().is_empty() || line_indent <= indent.len() {
+                break;
+            }
+            insert_at += 1;
+        }
+
+        let entry = format!("{indent}    {bullet} [{title}](./{milestone}/{slug}.md)");
+
+        let mut output: Vec<&str> = lines[..insert_at].to_vec();
+        output.push(&entry);
+        output.extend(&lines[insert_at..]);
+
+        let mut output = output.join("\n");
+        if summary.ends_with('\n') {
+            output.push('\n');
+        }
+        Some(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::text_processing::*;
+    use super::NewGoal;
+
+    fn new_goal() -> NewGoal {
+        NewGoal {
+            title: "Cargo script".to_string(),
+            point_of_contact: "@ghost".to_string(),
+            roadmaps: vec![],
+            team_asks: vec![("cargo".to_string(), "Medium".to_string())],
+            subgoals: vec![],
+            help_wanted: false,
+            funding: false,
+        }
+    }
+
+    const TEMPLATE: &str = "# TEMPLATE (replace with title of your goal)\n\n\
+        > **Instructions:** Copy this template.\n\n\
+        | Metadata         |      |\n\
+        | :--------------- | ---- |\n\
+        | Point of contact | *@ghost* |\n\n\
+        ## Summary\n\n*Short description.*\n\n\
+        ### Work items over the next year\n\n\
+        | Task        | Owner(s) | Notes |\n\
+        | ----------- | -------- | ----- |\n\
+        | Do the work | *owner*  |       |\n\n\
+        ## Team asks\n\n\
+        | Team    | Support level | Notes |\n\
+        | ------- | ------------- | ----- |\n\
+        | [cargo] |               |       |\n\n\
+        ## Help wanted\n\n\
+        | Task | Experience level | Time investment |\n\
+        |------|-----------------|-----------------|\n\
+        | *e.g., Implement the feature* | *e.g., Intermediate Rust* | *e.g., 3 months part-time* |\n\n\
+        ## Frequently asked questions\n";
+
+    #[test]
+    fn test_instantiate_template() {
+        let result = instantiate_template(TEMPLATE, &new_goal());
+
+        assert!(result.starts_with("# Cargo script\n\n| Metadata"));
+        assert!(!result.contains("Instructions"));
+        assert!(result.contains("| Point of contact | @ghost "));
+        assert!(result.contains("| Do the work | @ghost "));
+        assert!(result.contains("| [cargo] | Medium "));
+        assert!(!result.contains("## Help wanted"));
+        assert!(result.contains("## Frequently asked questions"));
+        assert!(!result.contains("\n\n\n"));
+    }
+
+    #[test]
+    fn test_instantiate_template_with_subgoals() {
+        let goal = NewGoal {
+            subgoals: vec!["Stabilize".to_string(), "Document".to_string()],
+            help_wanted: true,
+            ..new_goal()
+        };
+        let result = instantiate_template(TEMPLATE, &goal);
+
+        let stabilize = result.find("#### Stabilize\n\n| Task").unwrap();
+        let document = result.find("#### Document\n\n| Task").unwrap();
+        assert!(stabilize < document);
+        assert!(result.contains("## Help wanted"));
+        assert!(result.contains("| *Describe the task* | TBD "));
+    }
+
+    #[test]
+    fn test_add_goal_to_summary() {
+        let summary = "# Summary\n\n\
+            - [Overview](./2027/README.md)\n\
+            - [Goals](./2027/goals.md)\n    \
+            - [Existing](./2027/existing.md)\n\
+            - [Goals not accepted](./2027/not_accepted.md)\n";
+        let result = add_goal_to_summary(summary, "2027", "cargo-script", "Cargo script").unwrap();
+
+        assert_eq!(
+            result,
+            "# Summary\n\n\
+             - [Overview](./2027/README.md)\n\
+             - [Goals](./2027/goals.md)\n    \
+             - [Existing](./2027/existing.md)\n    \
+             - [Cargo script](./2027/cargo-script.md)\n\
+             - [Goals not accepted](./2027/not_accepted.md)\n"
+        );
+
+        assert!(add_goal_to_summary(summary, "2028", "cargo-script", "Cargo script").is_none());
+    }
+}
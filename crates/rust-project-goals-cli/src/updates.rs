@@ -1,33 +1,201 @@
 use chrono::{Datelike, NaiveDate};
 use regex::Regex;
+use rust_project_goals::config::Configuration;
+use rust_project_goals::goal::GoalDocument;
+use rust_project_goals::opportunities::help_wanted_requests;
 use rust_project_goals::re::{HELP_WANTED, TLDR};
 use rust_project_goals::spanned::{Result, Span, Spanned};
-use rust_project_goals::util::{comma, MILESTONE_REGEX};
+use rust_project_goals::team::TeamName;
+use rust_project_goals::update_items::{update_items, UpdateItemKind};
+use rust_project_goals::util::{comma, slug, MILESTONE_REGEX};
 use rust_project_goals::{goal, markwaydown, spanned, team};
 use rust_project_goals_json::GithubIssueState;
+use std::collections::BTreeSet;
 use std::path::PathBuf;
 
+mod email;
 mod templates;
+use email::Email;
 use rust_project_goals::gh::issues::ExistingGithubIssue;
 use rust_project_goals::gh::{
     issue_id::{IssueId, Repository},
     issues::{checkboxes, ExistingGithubComment},
 };
-use templates::{HelpWanted, UpdatesGoal};
+use templates::{HelpWanted, Templates, UpdateItem, UpdatesGoal};
 
-#[derive(Copy, Clone, Default)]
 /// Order in which GitHub comments for each goal are displayed.
+#[derive(clap::ValueEnum, Copy, Clone, Debug, Default)]
 pub enum Order {
     #[default]
     /// Chronological order: the oldest comments show up first.
     /// Mirrors the order on the corresponding GitHub issue.
+    #[value(name = "oldest")]
     OldestFirst,
 
     /// Reverse chronological order: the most recent comments will show up first.
-    #[allow(unused)]
+    #[value(name = "newest")]
     NewestFirst,
 }
 
+/// How goals are grouped into sections.
+#[derive(clap::ValueEnum, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum GroupBy {
+    /// Roadmap goals by theme, then the goals looking for help, then the others
+    #[default]
+    Theme,
+
+    /// By the teams championing each goal
+    Champion,
+
+    /// By point of contact
+    #[value(name = "poc")]
+    PointOfContact,
+
+    /// By the largest level of support asked of a team
+    #[value(name = "level")]
+    SupportLevel,
+}
+
+/// State of a goal's tracking issue.
+#[derive(clap::ValueEnum, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GoalStatus {
+    Open,
+    Closed,
+}
+
+/// Which goals to include in the updates. Each option can be given several times, in
+/// which case goals matching any of the values are included; goals must match all of the
+/// options that are given.
+#[derive(clap::Args, Clone, Debug, Default)]
+pub struct UpdatesFilter {
+    /// Only include goals that have a champion from the specified team.
+    #[arg(long)]
+    pub with_champion_from: Option<String>,
+
+    /// Only include goals that involve the specified team.
+    #[arg(long = "team")]
+    pub teams: Vec<String>,
+
+    /// Only include goals with the specified point of contact (e.g., `@nikomatsakis`).
+    #[arg(long = "poc")]
+    pub points_of_contact: Vec<String>,
+
+    /// Only include goals in the specified roadmap theme.
+    #[arg(long = "theme")]
+    pub themes: Vec<String>,
+
+    /// Only include the specified goal, given by file name, title, or tracking issue number.
+    #[arg(long = "goal")]
+    pub goals: Vec<String>,
+
+    /// Only include goals whose tracking issue is open or closed.
+    #[arg(long = "status", value_enum)]
+    pub statuses: Vec<GoalStatus>,
+}
+
+/// Options controlling the goals in the updates and how they are laid out.
+#[derive(clap::Args, Clone, Debug, Default)]
+pub struct UpdatesOptions {
+    /// Order of the comments of each goal.
+    #[arg(long, value_enum, default_value_t)]
+    pub order: Order,
+
+    /// How goals are grouped into sections.
+    #[arg(long, value_enum, default_value_t)]
+    pub group_by: GroupBy,
+
+    #[command(flatten)]
+    pub filter: UpdatesFilter,
+
+    /// Directory of Handlebars templates replacing the built-in ones with the same name
+    /// (e.g., `goal_card.hbs`), or adding partials.
+    #[arg(long)]
+    pub templates: Option<PathBuf>,
+}
+
+impl UpdatesOptions {
+    /// Parse the options of `(((UPDATES: ...)))`: comma-separated `key=value` pairs, e.g.
+    /// `from=2026-01-01, order=newest, group=champion, team=lang|compiler, status=open`.
+    /// Multiple values for `team`, `poc`, `theme`, `goal` and `status` are separated by `|`.
+    /// `from` and `to` are the start date (inclusive) and end date (exclusive) of comments,
+    /// which are returned separately.
+    pub fn parse(s: &str) -> Result<(Self, Option<NaiveDate>, Option<NaiveDate>)> {
+        let mut options = UpdatesOptions::default();
+        let mut start_date = None;
+        let mut end_date = None;
+
+        for pair in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let Some((key, value)) = pair.split_once('=') else {
+                spanned::bail_here!("expected `key=value` in updates options, found `{}`", pair);
+            };
+            let (key, value) = (key.trim(), value.trim());
+            let values = || value.split('|').map(|v| v.trim().to_string());
+            match key {
+                "from" => start_date = Some(parse_date(value)?),
+                "to" => end_date = Some(parse_date(value)?),
+                "order" => options.order = parse_value_enum(key, value)?,
+                "group" => options.group_by = parse_value_enum(key, value)?,
+                "champion" => options.filter.with_champion_from = Some(value.to_string()),
+                "team" => options.filter.teams.extend(values()),
+                "poc" => options.filter.points_of_contact.extend(values()),
+                "theme" => options.filter.themes.extend(values()),
+                "goal" => options.filter.goals.extend(values()),
+                "status" => {
+                    for status in values() {
+                        options
+                            .filter
+                            .statuses
+                            .push(parse_value_enum(key, &status)?);
+                    }
+                }
+                key => {
+                    spanned::bail_here!(
+                        "unknown updates option `{}`, expected `from`, `to`, `order`, `group`, `champion`, `team`, `poc`, `theme`, `goal`, or `status`",
+                        key
+                    );
+                }
+            }
+        }
+
+        Ok((options, start_date, end_date))
+    }
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate> {
+    match NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        Ok(date) => Ok(date),
+        Err(_) => spanned::bail_here!("expected a date like `2026-01-31`, found `{}`", value),
+    }
+}
+
+fn parse_value_enum<E: clap::ValueEnum>(key: &str, value: &str) -> Result<E> {
+    match E::from_str(value, true) {
+        Ok(value) => Ok(value),
+        Err(_) => spanned::bail_here!(
+            "unknown value `{}` for `{}`, expected one of: {}",
+            value,
+            key,
+            E::value_variants()
+                .iter()
+                .filter_map(|v| v.to_possible_value())
+                .map(|v| format!("`{}`", v.get_name()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// Output format of the `updates` command.
+#[derive(clap::ValueEnum, Copy, Clone, Debug, Default)]
+pub enum UpdatesFormat {
+    /// Markdown for the blog post
+    #[default]
+    Markdown,
+
+    /// `.eml` digests: one for all goals, one per team, and one per point of contact
+    Email,
+}
+
 /// Library function that renders updates as a string without side effects.
 /// This is suitable for use from the mdbook preprocessor.
 pub fn render_updates(
@@ -36,10 +204,164 @@ pub fn render_updates(
     milestone: &str,
     start_date: Option<&NaiveDate>,
     end_date: Option<&NaiveDate>,
-    with_champion_from: Option<&str>,
+    options: &UpdatesOptions,
     use_progress_bar: bool,
-    comment_order: Order,
 ) -> Result<String> {
+    let updates = milestone_updates(
+        cached_issues,
+        repository,
+        milestone,
+        start_date,
+        end_date,
+        options,
+        use_progress_bar,
+    )?;
+    let updates = templates::Updates::new(
+        milestone.to_string(),
+        updates.roadmap_goals,
+        updates.other_goals,
+        options.group_by,
+    );
+
+    // Render the output using handlebars and return it
+    let templates = Templates::with_overrides(options.templates.as_deref())?;
+    updates.render(&templates)
+}
+
+/// Render the updates as email digests: one for all goals, one for each team with the
+/// goals it champions, and one for each point of contact with the latest update of their
+/// goals and the goals missing an update. Returns the file name and contents of each.
+pub fn render_email_digests(
+    cached_issues: &[ExistingGithubIssue],
+    repository: &Repository,
+    milestone: &str,
+    start_date: Option<&NaiveDate>,
+    end_date: Option<&NaiveDate>,
+    options: &UpdatesOptions,
+    use_progress_bar: bool,
+) -> Result<Vec<(String, String)>> {
+    let updates = milestone_updates(
+        cached_issues,
+        repository,
+        milestone,
+        start_date,
+        end_date,
+        options,
+        use_progress_bar,
+    )?;
+    let templates = Templates::with_overrides(options.templates.as_deref())?;
+    let date = chrono::Utc::now().to_rfc2822();
+    let since = updates.start_date;
+
+    let mut digests = vec![];
+
+    let email = updates_digest(
+        milestone,
+        &updates,
+        &templates,
+        options.group_by,
+        Some(Configuration::get().email.to.clone()),
+        format!("Rust project goals {milestone}: updates since {since}"),
+        |_| true,
+    )?;
+    digests.push(("all.eml".to_string(), email.render(&date)));
+
+    let teams: BTreeSet<&String> = updates
+        .goals()
+        .flat_map(|goal| &goal.champion_teams)
+        .collect();
+    for team in teams {
+        let email = updates_digest(
+            milestone,
+            &updates,
+            &templates,
+            options.group_by,
+            None,
+            format!("Rust project goals {milestone}: updates on goals championed by {team} since {since}"),
+            |goal| goal.champion_teams.contains(team),
+        )?;
+        digests.push((format!("team-{}.eml", slug(team)), email.render(&date)));
+    }
+
+    let usernames: BTreeSet<&String> = updates
+        .goals()
+        .flat_map(|goal| &goal.point_of_contact_usernames)
+        .collect();
+    for username in usernames {
+        let goals: Vec<&UpdatesGoal> = updates
+            .goals()
+            .filter(|goal| goal.point_of_contact_usernames.contains(username))
+            .collect();
+        let digest = templates::PointOfContactDigest::new(milestone, username, since, &goals);
+        let text = digest.render(&templates)?;
+        let email = Email {
+            from: Configuration::get().email.from.clone(),
+            to: None,
+            subject: format!("Rust project goals {milestone}: updates on your goals since {since}"),
+            html: templates::markdown_to_html_document(&text)?,
+            text,
+        };
+        digests.push((format!("poc-{}.eml", slug(username)), email.render(&date)));
+    }
+
+    Ok(digests)
+}
+
+/// Email digest of the updates on the goals matching `filter`.
+fn updates_digest(
+    milestone: &str,
+    updates: &MilestoneUpdates,
+    templates: &Templates<'_>,
+    group_by: GroupBy,
+    to: Option<String>,
+    subject: String,
+    filter: impl Fn(&UpdatesGoal) -> bool,
+) -> Result<Email> {
+    let only = |goals: &[UpdatesGoal]| {
+        let mut goals: Vec<UpdatesGoal> = goals.iter().filter(|&g| filter(g)).cloned().collect();
+        mark_separators(&mut goals);
+        goals
+    };
+    let updates = templates::Updates::new(
+        milestone.to_string(),
+        only(&updates.roadmap_goals),
+        only(&updates.other_goals),
+        group_by,
+    );
+    let html = templates::markdown_to_html_document(&updates.render_as(templates, "updates")?)?;
+    let text = updates.render_as(templates, "updates_text")?;
+    Ok(Email {
+        from: Configuration::get().email.from.clone(),
+        to,
+        subject,
+        text,
+        html,
+    })
+}
+
+/// The updates of a milestone's goals, before they are rendered.
+struct MilestoneUpdates {
+    /// First day of the updates (inclusive)
+    start_date: NaiveDate,
+    roadmap_goals: Vec<UpdatesGoal>,
+    other_goals: Vec<UpdatesGoal>,
+}
+
+impl MilestoneUpdates {
+    fn goals(&self) -> impl Iterator<Item = &UpdatesGoal> {
+        self.roadmap_goals.iter().chain(&self.other_goals)
+    }
+}
+
+fn milestone_updates(
+    cached_issues: &[ExistingGithubIssue],
+    repository: &Repository,
+    milestone: &str,
+    start_date: Option<&NaiveDate>,
+    end_date: Option<&NaiveDate>,
+    options: &UpdatesOptions,
+    use_progress_bar: bool,
+) -> Result<MilestoneUpdates> {
     let milestone_re = Regex::new(MILESTONE_REGEX).unwrap();
     if !milestone_re.is_match(milestone) {
         spanned::bail_here!(
@@ -70,43 +392,65 @@ pub fn render_updates(
         map
     };
 
-    // Create mappings for ownership information
-    let issue_point_of_contact: std::collections::HashMap<u64, String> = goal_documents
+    // Issues in other repositories that contribute to each goal's progress
+    let issue_other_tracking_issues: std::collections::HashMap<u64, &[IssueId]> = goal_documents
         .iter()
         .filter_map(|doc| {
             doc.metadata
                 .tracking_issue
                 .as_ref()
-                .map(|issue| (issue.number, doc.point_of_contact_for_goal_list()))
+                .map(|issue| (issue.number, &doc.metadata.other_tracking_issues[..]))
         })
         .collect();
 
-    let issue_team_champions: std::collections::HashMap<u64, String> = goal_documents
+    // Create mappings for ownership information
+    let issue_point_of_contact: std::collections::HashMap<u64, String> = goal_documents
         .iter()
         .filter_map(|doc| {
-            doc.metadata.tracking_issue.as_ref().map(|issue| {
-                let teams = doc.team_involvement.teams();
-
-                let team_champions: Vec<String> = teams
-                    .into_iter()
-                    .filter_map(|team| {
-                        doc.metadata
-                            .champions
-                            .get(team)
-                            .map(|champion| format!("{} ({})", team, champion.content))
-                    })
-                    .collect();
+            doc.metadata
+                .tracking_issue
+                .as_ref()
+                .map(|issue| (issue.number, doc.point_of_contact_for_goal_list()))
+        })
+        .collect();
 
-                let team_champions_str = if team_champions.is_empty() {
-                    "(none)".to_string()
-                } else {
-                    team_champions.join(", ")
-                };
+    // Teams championing each goal and their champions, for the goal cards and the
+    // per-team email digests
+    let issue_team_champions: std::collections::HashMap<u64, Vec<(&'static TeamName, String)>> =
+        goal_documents
+            .iter()
+            .filter_map(|doc| {
+                doc.metadata.tracking_issue.as_ref().map(|issue| {
+                    let team_champions = doc
+                        .team_involvement
+                        .teams()
+                        .into_iter()
+                        .filter_map(|team| {
+                            let champion = doc.metadata.champions.get(team)?;
+                            Some((team, champion.content.clone()))
+                        })
+                        .collect();
+                    (issue.number, team_champions)
+                })
+            })
+            .collect();
 
-                (issue.number, team_champions_str)
+    // Usernames of the points of contact, for their personal email digests
+    let issue_point_of_contact_usernames: std::collections::HashMap<u64, Vec<String>> =
+        goal_documents
+            .iter()
+            .filter_map(|doc| {
+                doc.metadata.tracking_issue.as_ref().map(|issue| {
+                    let usernames = doc
+                        .metadata
+                        .owner_usernames()
+                        .into_iter()
+                        .map(|u| u.to_string())
+                        .collect();
+                    (issue.number, usernames)
+                })
             })
-        })
-        .collect();
+            .collect();
 
     let issue_task_owners: std::collections::HashMap<u64, String> = goal_documents
         .iter()
@@ -127,31 +471,31 @@ pub fn render_updates(
         })
         .collect();
 
-    // Filter issues by champion team if specified
-    let filtered_issues: Vec<ExistingGithubIssue> = if let Some(team_name) = with_champion_from {
-        let team_name = team::get_team_name(team_name)?
-            .ok_or_else(|| spanned::Error::str(format!("unknown team: {}", team_name)))?;
+    // Support level of each goal, for grouping by support level
+    let issue_support_level: std::collections::HashMap<u64, String> = goal_documents
+        .iter()
+        .filter_map(|doc| {
+            let issue = doc.metadata.tracking_issue.as_ref()?;
+            let level = doc.max_support_level()?;
+            Some((issue.number, level.as_str().to_string()))
+        })
+        .collect();
 
-        // Create a set of issue numbers for goals that have a champion from the specified team
-        let champion_issue_numbers: std::collections::HashSet<u64> = goal_documents
-            .iter()
-            .filter(|doc| doc.metadata.champions.contains_key(team_name))
-            .filter_map(|doc| {
+    // Keep the issues of the goals matching the filter
+    let goal_filter = GoalFilter::new(&options.filter)?;
+    let filtered_issues: Vec<ExistingGithubIssue> = issues
+        .iter()
+        .filter(|issue| {
+            let doc = goal_documents.iter().find(|doc| {
                 doc.metadata
                     .tracking_issue
                     .as_ref()
-                    .map(|issue| issue.number)
-            })
-            .collect();
-
-        issues
-            .iter()
-            .filter(|issue| champion_issue_numbers.contains(&issue.number))
-            .cloned()
-            .collect()
-    } else {
-        issues.to_vec()
-    };
+                    .is_some_and(|i| i.number == issue.number)
+            });
+            goal_filter.matches(issue, doc)
+        })
+        .cloned()
+        .collect();
 
     let filter = Filter {
         start_date: match start_date {
@@ -176,11 +520,14 @@ pub fn render_updates(
         &filter,
         true,
         use_progress_bar,
-        comment_order,
+        options.order,
         &issue_themes,
         &issue_point_of_contact,
         &issue_team_champions,
         &issue_task_owners,
+        &issue_other_tracking_issues,
+        &issue_point_of_contact_usernames,
+        &issue_support_level,
     )?;
     let other_goals = prepare_goals(
         repository,
@@ -188,20 +535,25 @@ pub fn render_updates(
         &filter,
         false,
         use_progress_bar,
-        comment_order,
+        options.order,
         &issue_themes,
         &issue_point_of_contact,
         &issue_team_champions,
         &issue_task_owners,
+        &issue_other_tracking_issues,
+        &issue_point_of_contact_usernames,
+        &issue_support_level,
     )?;
-    let updates = templates::Updates::new(milestone.to_string(), roadmap_goals, other_goals);
 
     if use_progress_bar {
         progress_bar::finalize_progress_bar();
     }
 
-    // Render the output using handlebars and return it
-    updates.render()
+    Ok(MilestoneUpdates {
+        start_date: filter.start_date,
+        roadmap_goals,
+        other_goals,
+    })
 }
 
 fn prepare_goals(
@@ -213,8 +565,11 @@ fn prepare_goals(
     comment_order: Order,
     issue_themes: &std::collections::HashMap<u64, Vec<String>>,
     issue_point_of_contact: &std::collections::HashMap<u64, String>,
-    issue_team_champions: &std::collections::HashMap<u64, String>,
+    issue_team_champions: &std::collections::HashMap<u64, Vec<(&'static TeamName, String)>>,
     issue_task_owners: &std::collections::HashMap<u64, String>,
+    issue_other_tracking_issues: &std::collections::HashMap<u64, &[IssueId]>,
+    issue_point_of_contact_usernames: &std::collections::HashMap<u64, Vec<String>>,
+    issue_support_level: &std::collections::HashMap<u64, String>,
 ) -> Result<Vec<UpdatesGoal>> {
     let mut result = vec![];
     // We process roadmap and regular goals in two passes, and capture comments differently for roadmap goals.
@@ -239,12 +594,18 @@ fn prepare_goals(
             );
         }
 
-        let progress = checkboxes(&issue);
+        let other_tracking_issues = issue_other_tracking_issues
+            .get(&issue.number)
+            .copied()
+            .unwrap_or_default();
+        let progress = checkboxes(&issue, other_tracking_issues);
 
         let mut comments = issue.comments.clone();
         comments.sort_by_key(|c| c.created_at.clone());
         comments.retain(|c| !c.should_hide_from_reports() && filter.matches(c));
 
+        let items = GoalItems::from_comments(&comments);
+
         // We got the comments in the chronological order. Reverse it if desired.
         if matches!(comment_order, Order::NewestFirst) {
             comments.reverse();
@@ -261,6 +622,11 @@ fn prepare_goals(
 
         let why_this_goal = why_this_goal(&issue_id, issue)?;
 
+        let team_champions = issue_team_champions
+            .get(&issue.number)
+            .map(|team_champions| &team_champions[..])
+            .unwrap_or_default();
+
         let details_summary = match comments.len() {
             0 => String::from("No detailed updates available."),
             1 => String::from("1 detailed update available."),
@@ -286,14 +652,32 @@ fn prepare_goals(
                 .get(&issue.number)
                 .cloned()
                 .unwrap_or_else(|| "(unknown)".to_string()),
-            team_champions: issue_team_champions
+            team_champions: if team_champions.is_empty() {
+                "(none)".to_string()
+            } else {
+                team_champions
+                    .iter()
+                    .map(|(team, champion)| format!("{team} ({champion})"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            },
+            task_owners: issue_task_owners
                 .get(&issue.number)
                 .cloned()
                 .unwrap_or_else(|| "(none)".to_string()),
-            task_owners: issue_task_owners
+            champion_teams: team_champions
+                .iter()
+                .map(|(team, _)| team.as_str().to_string())
+                .collect(),
+            point_of_contact_usernames: issue_point_of_contact_usernames
                 .get(&issue.number)
                 .cloned()
-                .unwrap_or_else(|| "(none)".to_string()),
+                .unwrap_or_default(),
+            support_level: issue_support_level.get(&issue.number).cloned(),
+            blockers: items.blockers,
+            next_steps: items.next_steps,
+            decisions_needed: items.decisions_needed,
+            shipped: items.shipped,
         });
 
         if use_progress_bar {
@@ -304,12 +688,66 @@ fn prepare_goals(
     // Updates are in a random order, sort them.
     result.sort_by_cached_key(|update| update.title.to_lowercase());
 
-    // Mark the last entry as not needing a separator from its following sibling, it has none.
-    if let Some(last) = result.last_mut() {
+    mark_separators(&mut result);
+
+    Ok(result)
+}
+
+/// Mark the last entry as not needing a separator from its following sibling, it has none.
+fn mark_separators(goals: &mut [UpdatesGoal]) {
+    for goal in goals.iter_mut() {
+        goal.needs_separator = true;
+    }
+    if let Some(last) = goals.last_mut() {
         last.needs_separator = false;
     }
+}
 
-    Ok(result)
+/// The [structured items](`update_items`) of a goal's update comments.
+#[derive(Default)]
+struct GoalItems {
+    blockers: Vec<UpdateItem>,
+    next_steps: Vec<UpdateItem>,
+    decisions_needed: Vec<UpdateItem>,
+    shipped: Vec<UpdateItem>,
+}
+
+impl GoalItems {
+    /// Extract the items of `comments`, which are in chronological order. Blockers, next
+    /// steps and decisions needed describe the current state of the goal, so they come from
+    /// the most recent comment that lists any; everything that shipped is kept.
+    fn from_comments(comments: &[ExistingGithubComment]) -> Self {
+        let mut items = GoalItems::default();
+        for comment in comments {
+            let mut current = GoalItems::default();
+            for (kind, text) in update_items(&comment.body) {
+                let item = UpdateItem {
+                    text,
+                    url: comment.url.clone(),
+                    author: comment.author.clone(),
+                    created_at: comment.created_at_date().to_string(),
+                };
+                match kind {
+                    UpdateItemKind::Blocker => current.blockers.push(item),
+                    UpdateItemKind::NextStep => current.next_steps.push(item),
+                    UpdateItemKind::DecisionNeeded => current.decisions_needed.push(item),
+                    UpdateItemKind::Shipped => current.shipped.push(item),
+                }
+            }
+
+            if !current.blockers.is_empty() {
+                items.blockers = current.blockers;
+            }
+            if !current.next_steps.is_empty() {
+                items.next_steps = current.next_steps;
+            }
+            if !current.decisions_needed.is_empty() {
+                items.decisions_needed = current.decisions_needed;
+            }
+            items.shipped.extend(current.shipped);
+        }
+        items
+    }
 }
 
 /// Search for a TL;DR comment. If one is found, remove it and return the text.
@@ -330,41 +768,16 @@ fn help_wanted(
     tldr: Option<&str>,
     comments: &[ExistingGithubComment],
 ) -> Result<(bool, Vec<HelpWanted>)> {
-    use std::fmt::Write;
-
-    let mut help_wanted = vec![];
-
     let tldr_has_help_wanted = tldr
         .unwrap_or("")
         .lines()
         .any(|line| HELP_WANTED.is_match(line));
 
-    for comment in comments {
-        let mut lines = comment.body.split('\n').peekable();
-
-        // Look for a line that says "Help wanted" at the front.
-        // Then extract the rest of that line along with subsequent lines until we find a blank line.
-        while lines.peek().is_some() {
-            while let Some(line) = lines.next() {
-                if let Some(c) = HELP_WANTED.captures(line) {
-                    let text = c["text"].trim().to_string();
-                    if !text.is_empty() {
-                        help_wanted.push(HelpWanted { text });
-                        break;
-                    }
-                }
-            }
-
-            while let Some(line) = lines.next() {
-                if line.trim().is_empty() {
-                    break;
-                } else {
-                    let last = help_wanted.len() - 1;
-                    writeln!(&mut help_wanted[last].text, "{line}")?;
-                }
-            }
-        }
-    }
+    let help_wanted: Vec<HelpWanted> = comments
+        .iter()
+        .flat_map(|comment| help_wanted_requests(&comment.body))
+        .map(|text| HelpWanted { text })
+        .collect();
 
     Ok((tldr_has_help_wanted || !help_wanted.is_empty(), help_wanted))
 }
@@ -383,6 +796,113 @@ fn why_this_goal(issue_id: &IssueId, issue: &ExistingGithubIssue) -> Result<Stri
     return Ok("".to_string());
 }
 
+/// [`UpdatesFilter`] with the team names resolved.
+struct GoalFilter<'f> {
+    filter: &'f UpdatesFilter,
+    champion_team: Option<&'static TeamName>,
+    teams: Vec<&'static TeamName>,
+}
+
+impl<'f> GoalFilter<'f> {
+    fn new(filter: &'f UpdatesFilter) -> Result<Self> {
+        let team_name = |name: &str| {
+            team::get_team_name(name)?
+                .ok_or_else(|| spanned::Error::str(format!("unknown team: {}", name)))
+        };
+        Ok(GoalFilter {
+            filter,
+            champion_team: filter
+                .with_champion_from
+                .as_deref()
+                .map(team_name)
+                .transpose()?,
+            teams: filter
+                .teams
+                .iter()
+                .map(|name| team_name(name))
+                .collect::<Result<_>>()?,
+        })
+    }
+
+    /// True if the goal `doc`, whose tracking issue is `issue`, is included in the updates.
+    /// Issues without a goal document only match if the filter is about the issue alone.
+    fn matches(&self, issue: &ExistingGithubIssue, doc: Option<&GoalDocument>) -> bool {
+        let filter = self.filter;
+
+        if !filter.statuses.is_empty() {
+            let status = match issue.state {
+                GithubIssueState::Open => GoalStatus::Open,
+                GithubIssueState::Closed => GoalStatus::Closed,
+            };
+            if !filter.statuses.contains(&status) {
+                return false;
+            }
+        }
+
+        let about_documents = self.champion_team.is_some()
+            || !self.teams.is_empty()
+            || !filter.points_of_contact.is_empty()
+            || !filter.themes.is_empty()
+            || !filter.goals.is_empty();
+        if !about_documents {
+            return true;
+        }
+        let Some(doc) = doc else {
+            return false;
+        };
+
+        if let Some(team) = self.champion_team {
+            if !doc.metadata.champions.contains_key(team) {
+                return false;
+            }
+        }
+
+        if !self.teams.is_empty() {
+            let teams = doc.team_involvement.teams();
+            if !self.teams.iter().any(|team| teams.contains(team)) {
+                return false;
+            }
+        }
+
+        if !filter.points_of_contact.is_empty() {
+            let usernames = doc.metadata.owner_usernames();
+            let matches = filter.points_of_contact.iter().any(|poc| {
+                let poc = poc.trim_start_matches('@');
+                usernames
+                    .iter()
+                    .any(|u| u.trim_start_matches('@').eq_ignore_ascii_case(poc))
+            });
+            if !matches {
+                return false;
+            }
+        }
+
+        if !filter.themes.is_empty()
+            && !filter
+                .themes
+                .iter()
+                .any(|theme| doc.matches_roadmap_theme(theme.trim()))
+        {
+            return false;
+        }
+
+        if !filter.goals.is_empty() {
+            let stem = doc.link_path.file_stem().map(|s| s.to_string_lossy());
+            let matches = filter.goals.iter().any(|goal| {
+                let goal = goal.trim();
+                stem.as_deref().is_some_and(|stem| stem == goal)
+                    || doc.metadata.title.content.eq_ignore_ascii_case(goal)
+                    || issue.number.to_string() == goal.trim_start_matches('#')
+            });
+            if !matches {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
 struct Filter<'f> {
     start_date: NaiveDate,
     end_date: Option<&'f NaiveDate>,
@@ -405,3 +925,181 @@ fn default_start_date() -> NaiveDate {
     let start_of_month = NaiveDate::from_ymd_opt(date.year(), date.month(), 1).unwrap();
     start_of_month - chrono::Duration::days(7)
 }
+
+#[cfg(test)]
+mod tests {
+    use rust_project_goals::goal::{SupportLevel, TeamInvolvement, TeamSupport, Themes};
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    #[test]
+    fn parse_options() {
+        let (options, start_date, end_date) = UpdatesOptions::parse(
+            "from=2026-01-01, to=2026-02-01, order=newest, group=level, champion=lang, \
+             team=lang | compiler, poc=@alice, theme=Rust for Linux, goal=frobnicate|#2, \
+             status=open|closed",
+        )
+        .unwrap();
+        assert_eq!(start_date, NaiveDate::from_ymd_opt(2026, 1, 1));
+        assert_eq!(end_date, NaiveDate::from_ymd_opt(2026, 2, 1));
+        assert!(matches!(options.order, Order::NewestFirst));
+        assert_eq!(options.group_by, GroupBy::SupportLevel);
+        let filter = &options.filter;
+        assert_eq!(filter.with_champion_from.as_deref(), Some("lang"));
+        assert_eq!(filter.teams, ["lang", "compiler"]);
+        assert_eq!(filter.points_of_contact, ["@alice"]);
+        assert_eq!(filter.themes, ["Rust for Linux"]);
+        assert_eq!(filter.goals, ["frobnicate", "#2"]);
+        assert_eq!(filter.statuses, [GoalStatus::Open, GoalStatus::Closed]);
+    }
+
+    #[test]
+    fn parse_options_repeated_and_empty() {
+        let (options, start_date, end_date) =
+            UpdatesOptions::parse("team=lang, team=compiler,, ").unwrap();
+        assert_eq!(options.filter.teams, ["lang", "compiler"]);
+        assert_eq!((start_date, end_date), (None, None));
+        assert_eq!(options.group_by, GroupBy::Theme);
+    }
+
+    #[test]
+    fn parse_option_errors() {
+        for (options, error) in [
+            ("newest", "expected `key=value` in updates options, found `newest`"),
+            ("colour=red", "unknown updates option `colour`"),
+            ("from=January", "expected a date like `2026-01-31`, found `January`"),
+            (
+                "order=sideways",
+                "unknown value `sideways` for `order`, expected one of: `oldest`, `newest`",
+            ),
+            (
+                "group=team",
+                "unknown value `team` for `group`, expected one of: `theme`, `champion`, `poc`, `level`",
+            ),
+            ("status=open|maybe", "unknown value `maybe` for `status`"),
+        ] {
+            let Err(e) = UpdatesOptions::parse(options) else {
+                panic!("`{options}` should not parse");
+            };
+            let message = format!("{e:?}");
+            assert!(message.contains(error), "{options}: {message}");
+        }
+    }
+
+    /// Goal `frobnicate` (#1, open) asks `lang` and `compiler` for support, is championed
+    /// by `lang`, and is on the "Rust for Linux" roadmap; `quux` (#2, closed) asks
+    /// `compiler`, which champions it. Issue #3 has no goal document.
+    fn goals() -> Vec<(ExistingGithubIssue, Option<GoalDocument>)> {
+        let goal = |path, title, pocs: &str, teams: &[&str], champion| {
+            let mut goal = GoalDocument::for_test(path, title);
+            goal.metadata.pocs = pocs.to_string();
+            let level = SupportLevel::all().next().unwrap();
+            goal.team_involvement = TeamInvolvement::Support(
+                teams
+                    .iter()
+                    .map(|&team| TeamSupport {
+                        link_path: goal.link_path.clone(),
+                        team: TeamName::for_test(team),
+                        support_level: level,
+                        notes: String::new(),
+                        subgoal: None,
+                    })
+                    .collect(),
+            );
+            goal.metadata.champions = BTreeMap::from([(
+                TeamName::for_test(champion),
+                Spanned::here("@carol".to_string()),
+            )]);
+            goal
+        };
+        let issue = |number, state| ExistingGithubIssue {
+            number,
+            title: format!("Goal #{number}"),
+            assignees: Default::default(),
+            comments: vec![],
+            body: String::new(),
+            state,
+            labels: vec![],
+            milestone: None,
+        };
+
+        let mut frobnicate = goal(
+            "2026/frobnicate.md",
+            "Frobnicate",
+            "@alice",
+            &["lang", "compiler"],
+            "lang",
+        );
+        frobnicate.task_tree.roadmap = Themes::for_test(&["Rust for Linux"]);
+        let quux = goal("2026/quux.md", "Quux", "@bob", &["compiler"], "compiler");
+        vec![
+            (issue(1, GithubIssueState::Open), Some(frobnicate)),
+            (issue(2, GithubIssueState::Closed), Some(quux)),
+            (issue(3, GithubIssueState::Open), None),
+        ]
+    }
+
+    /// Numbers of the issues that `options` (as in `(((UPDATES: ...)))`) includes.
+    fn matching(options: &str) -> Vec<u64> {
+        let (options, ..) = UpdatesOptions::parse(options).unwrap();
+        let filter = GoalFilter {
+            filter: &options.filter,
+            champion_team: options
+                .filter
+                .with_champion_from
+                .as_deref()
+                .map(TeamName::for_test),
+            teams: options
+                .filter
+                .teams
+                .iter()
+                .map(|team| TeamName::for_test(team))
+                .collect(),
+        };
+        goals()
+            .iter()
+            .filter(|(issue, doc)| filter.matches(issue, doc.as_ref()))
+            .map(|(issue, _)| issue.number)
+            .collect()
+    }
+
+    #[test]
+    fn filter_with_champion_from() {
+        assert_eq!(matching(""), [1, 2, 3]);
+        assert_eq!(matching("champion=lang"), [1]);
+        assert_eq!(matching("champion=compiler"), [2]);
+        assert_eq!(matching("champion=cargo"), Vec::<u64>::new());
+
+        assert_eq!(matching("champion=lang, team=compiler"), [1]);
+        assert_eq!(matching("champion=compiler, team=compiler"), [2]);
+        assert_eq!(matching("champion=compiler, team=lang"), Vec::<u64>::new());
+
+        assert_eq!(matching("champion=lang, poc=alice"), [1]);
+        assert_eq!(matching("champion=lang, poc=@Bob"), Vec::<u64>::new());
+
+        assert_eq!(matching("champion=lang, theme=Rust for Linux"), [1]);
+        assert_eq!(
+            matching("champion=compiler, theme=Rust for Linux"),
+            Vec::<u64>::new()
+        );
+
+        assert_eq!(matching("champion=compiler, goal=frobnicate|#2"), [2]);
+        assert_eq!(matching("champion=lang, goal=quux"), Vec::<u64>::new());
+
+        assert_eq!(matching("champion=compiler, status=closed"), [2]);
+        assert_eq!(matching("champion=lang, status=closed"), Vec::<u64>::new());
+    }
+
+    #[test]
+    fn filter_issues_without_goal_document() {
+        // Filters on the issue alone include it...
+        assert_eq!(matching("status=open"), [1, 3]);
+        // ...but filters on the goal document leave it out.
+        assert_eq!(matching("team=compiler"), [1, 2]);
+        assert_eq!(matching("poc=bob"), [2]);
+        assert_eq!(matching("theme=Rust for Linux"), [1]);
+        assert_eq!(matching("goal=#3"), Vec::<u64>::new());
+        assert_eq!(matching("goal=Quux|#1"), [1, 2]);
+    }
+}
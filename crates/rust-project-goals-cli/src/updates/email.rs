@@ -0,0 +1,162 @@
+//! A minimal writer for multipart text+HTML emails, saved as `.eml` files that mail
+//! clients open as drafts.
+
+/// Separates the text and HTML parts. Quoted-printable output never contains `=_`,
+/// so the boundary cannot appear in the parts.
+const BOUNDARY: &str = "=_rust-project-goals-digest";
+
+pub struct Email {
+    /// Sender, if configured. Left for the mail client to fill in otherwise.
+    pub from: Option<String>,
+    /// Recipient, if known (e.g., a mailing list). Left for the sender to fill in otherwise.
+    pub to: Option<String>,
+    pub subject: String,
+    /// Plain text part, usually markdown.
+    pub text: String,
+    /// HTML part.
+    pub html: String,
+}
+
+impl Email {
+    /// Render the email in the Internet Message Format (RFC 5322). `date` is an RFC 2822
+    /// date, e.g. `Mon, 2 Mar 2026 12:00:00 +0000`.
+    pub fn render(&self, date: &str) -> String {
+        let mut output = String::new();
+        if let Some(from) = &self.from {
+            output.push_str(&format!("From: {from}\r\n"));
+        }
+        if let Some(to) = &self.to {
+            output.push_str(&format!("To: {to}\r\n"));
+        }
+        output.push_str(&format!("Subject: {}\r\n", encode_header(&self.subject)));
+        output.push_str(&format!("Date: {date}\r\n"));
+        output.push_str("MIME-Version: 1.0\r\n");
+        output.push_str("X-Unsent: 1\r\n");
+        output.push_str(&format!(
+            "Content-Type: multipart/alternative; boundary=\"{BOUNDARY}\"\r\n"
+        ));
+        output.push_str("\r\n");
+        for (content_type, content) in [("text/plain", &self.text), ("text/html", &self.html)] {
+            output.push_str(&format!("--{BOUNDARY}\r\n"));
+            output.push_str(&format!("Content-Type: {content_type}; charset=utf-8\r\n"));
+            output.push_str("Content-Transfer-Encoding: quoted-printable\r\n");
+            output.push_str("\r\n");
+            output.push_str(&quoted_printable(content));
+        }
+        output.push_str(&format!("--{BOUNDARY}--\r\n"));
+        output
+    }
+}
+
+/// Encode `text` as quoted-printable (RFC 2045), with CRLF line endings and lines of at
+/// most 76 characters.
+fn quoted_printable(text: &str) -> String {
+    let mut output = String::new();
+    for line in text.lines() {
+        let bytes = line.as_bytes();
+        let mut len = 0;
+        for (i, &b) in bytes.iter().enumerate() {
+            let encoded = match b {
+                // Trailing whitespace would be stripped in transit
+                b' ' | b'\t' if i + 1 == bytes.len() => format!("={b:02X}"),
+                b' ' | b'\t' | 33..=60 | 62..=126 => (b as char).to_string(),
+                _ => format!("={b:02X}"),
+            };
+            if len + encoded.len() > 75 {
+                output.push_str("=\r\n");
+                len = 0;
+            }
+            output.push_str(&encoded);
+            len += encoded.len();
+        }
+        output.push_str("\r\n");
+    }
+    output
+}
+
+/// Encode a header value with RFC 2047 encoded-words if it is not plain ASCII.
+fn encode_header(text: &str) -> String {
+    if text.is_ascii() {
+        return text.to_string();
+    }
+
+    // Encoded-words are at most 75 characters, and cannot split a character.
+    const PREFIX: &str = "=?UTF-8?Q?";
+    const SUFFIX: &str = "?=";
+    let mut words = vec![];
+    let mut word = String::new();
+    for c in text.chars() {
+        let mut encoded = String::new();
+        match c {
+            ' ' => encoded.push('_'),
+            c if c.is_ascii_alphanumeric() => encoded.push(c),
+            c => {
+                let mut buf = [0; 4];
+                for b in c.encode_utf8(&mut buf).bytes() {
+                    encoded.push_str(&format!("={b:02X}"));
+                }
+            }
+        }
+        if PREFIX.len() + word.len() + encoded.len() + SUFFIX.len() > 75 {
+            words.push(std::mem::take(&mut word));
+        }
+        word.push_str(&encoded);
+    }
+    words.push(word);
+
+    words
+        .iter()
+        .map(|word| format!("{PREFIX}{word}{SUFFIX}"))
+        .collect::<Vec<_>>()
+        .join("\r\n ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_project_goals::config::EmailConfig;
+
+    #[test]
+    fn quoted_printable_escapes_and_wraps() {
+        assert_eq!(
+            quoted_printable("a=b \nnaïve"),
+            "a=3Db=20\r\nna=C3=AFve\r\n"
+        );
+
+        let long = "x".repeat(100);
+        let encoded = quoted_printable(&long);
+        assert_eq!(
+            encoded,
+            format!("{}=\r\n{}\r\n", "x".repeat(75), "x".repeat(25))
+        );
+    }
+
+    #[test]
+    fn headers_are_encoded_when_not_ascii() {
+        assert_eq!(encode_header("Updates for 2026"), "Updates for 2026");
+        assert_eq!(encode_header("Beyond the `&`"), "Beyond the `&`");
+        assert_eq!(encode_header("Café goals"), "=?UTF-8?Q?Caf=C3=A9_goals?=");
+        for word in encode_header(&"é".repeat(30)).split("\r\n ") {
+            assert!(word.len() <= 75, "{word}");
+        }
+    }
+
+    #[test]
+    fn render_multipart() {
+        let email = Email {
+            from: Some("Rust project goals team <goals-team@rust-lang.org>".to_string()),
+            to: Some(EmailConfig::default().to),
+            subject: "Updates".to_string(),
+            text: "Hello".to_string(),
+            html: "<p>Hello</p>".to_string(),
+        };
+        let rendered = email.render("Mon, 2 Mar 2026 12:00:00 +0000");
+        assert!(rendered.starts_with(
+            "From: Rust project goals team <goals-team@rust-lang.org>\r\nTo: all@rust-lang.org\r\nSubject: Updates\r\n"
+        ));
+        assert!(rendered.contains(
+            "Content-Type: text/html; charset=utf-8\r\nContent-Transfer-Encoding: quoted-printable\r\n\r\n<p>Hello</p>\r\n"
+        ));
+        assert!(rendered.ends_with("--=_rust-project-goals-digest--\r\n"));
+    }
+}
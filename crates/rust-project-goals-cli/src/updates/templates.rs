@@ -1,40 +1,95 @@
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+
 use handlebars::{
     Context, DirectorySourceOptions, Handlebars, Helper, HelperDef, HelperResult, Output,
-    RenderContext, RenderErrorReason,
+    RenderContext, RenderError, RenderErrorReason,
 };
-use rust_project_goals::config::GoalsConfig;
+use rust_project_goals::config::{Configuration, GoalsConfig};
 use rust_project_goals::gh::issues::ExistingGithubComment;
+use rust_project_goals::goal::SupportLevel;
 use rust_project_goals::markdown_processor::{MarkdownProcessor, MarkdownProcessorState};
 use serde::Serialize;
 
 use rust_project_goals::spanned::Result;
+use rust_project_goals::team;
 use rust_project_goals_json::Progress;
 
+use super::GroupBy;
+
+/// Names of the templates in `templates/`, the only ones that the configuration can replace.
+const BUILTIN_TEMPLATES: &[&str] = &[
+    "updates",
+    "goal_card",
+    "goal_comments",
+    "introduce_goal",
+    "progress",
+    "updates_text",
+    "goal_text",
+    "point_of_contact_digest",
+];
+
+/// The directory of the built-in templates, `templates/` at the root of the repository.
+fn builtin_templates_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../templates")
+}
+
 pub struct Templates<'h> {
     reg: Handlebars<'h>,
 }
 
 impl<'h> Templates<'h> {
-    pub fn new() -> Result<Self> {
-        let templates = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../templates");
-
-        // Load config from book.toml using clean approach
-        let book_toml_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../book.toml");
-        let goals_config = if book_toml_path.exists() {
-            GoalsConfig::from_book_toml(book_toml_path).map_err(|e| {
-                rust_project_goals::spanned::Error::str(format!(
-                    "Failed to load goals config: {}",
-                    e
-                ))
-            })?
-        } else {
-            GoalsConfig::default()
-        };
+    /// The built-in templates, replaced by those configured in `[preprocessor.goals.templates]`,
+    /// and then by the `.hbs` files in `dir`, if any, which can also add partials.
+    pub fn with_overrides(dir: Option<&Path>) -> Result<Self> {
+        let configuration = Configuration::get();
+
+        let goals_config = GoalsConfig::from_configuration(configuration).map_err(|e| {
+            rust_project_goals::spanned::Error::str(format!("Failed to load goals config: {}", e))
+        })?;
+
+        let mut templates = Self::from_templates_dir(builtin_templates_dir(), goals_config)?;
+
+        // Paths were made absolute from the file declaring them when loading the configuration
+        for (name, path) in &configuration.markdown.templates {
+            if !BUILTIN_TEMPLATES.contains(&name.as_str()) {
+                rust_project_goals::spanned::bail_here!(
+                    "unknown template `{name}` in `[preprocessor.goals.templates]`, expected one of: {}",
+                    BUILTIN_TEMPLATES
+                        .iter()
+                        .map(|name| format!("`{name}`"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+            }
+            templates
+                .reg
+                .register_template_file(name, path)
+                .map_err(|e| {
+                    rust_project_goals::spanned::Error::str(format!(
+                        "{}: failed to load template `{name}`: {e}",
+                        path.display()
+                    ))
+                })?;
+        }
+
+        if let Some(dir) = dir {
+            templates
+                .reg
+                .register_templates_directory(dir, DirectorySourceOptions::default())
+                .map_err(|e| {
+                    rust_project_goals::spanned::Error::str(format!(
+                        "{}: failed to load templates: {e}",
+                        dir.display()
+                    ))
+                })?;
+        }
 
-        Self::from_templates_dir(&templates, goals_config)
+        Ok(templates)
     }
 
     pub fn from_templates_dir(
@@ -54,9 +109,26 @@ impl<'h> Templates<'h> {
         let processor_arc = Arc::new(markdown_processor);
 
         // Register custom helper with processor
-        let markdown_helper = MarkdownToHtmlHelper::new(processor_arc);
+        let markdown_helper = MarkdownToHtmlHelper::new(processor_arc.clone());
         reg.register_helper("markdown_to_html", Box::new(markdown_helper));
         reg.register_helper("is_complete", Box::new(is_complete));
+        reg.register_helper("format_date", Box::new(format_date));
+        reg.register_helper("pluralize", Box::new(pluralize));
+        reg.register_helper("list_item", Box::new(list_item));
+        reg.register_helper(
+            "team_link",
+            Box::new(LinkHelper {
+                processor: processor_arc.clone(),
+                markdown: team_markdown,
+            }),
+        );
+        reg.register_helper(
+            "user_link",
+            Box::new(LinkHelper {
+                processor: processor_arc,
+                markdown: user_markdown,
+            }),
+        );
 
         Ok(Templates { reg })
     }
@@ -83,25 +155,139 @@ impl HelperDef for MarkdownToHtmlHelper {
         out: &mut dyn Output,
     ) -> HelperResult {
         if let Some(md) = h.param(0).and_then(|v| v.value().as_str()) {
-            // Create fresh state for this template invocation
-            let mut local_state = MarkdownProcessorState::default();
+            out.write(&render_markdown(&self.processor, md)?)?;
+        }
+        Ok(())
+    }
+}
 
-            // Process markdown with linking
-            let processed = self
-                .processor
-                .process_markdown(md, &mut local_state)
-                .map_err(|e| {
-                    RenderErrorReason::Other(format!("Markdown processing failed: {}", e))
-                })?;
+/// Process `md` with the markdown processor, which links users, teams, and so on,
+/// and convert it to HTML.
+fn render_markdown(
+    processor: &MarkdownProcessor,
+    md: &str,
+) -> std::result::Result<String, RenderError> {
+    // Create fresh state for this template invocation
+    let mut local_state = MarkdownProcessorState::default();
+
+    // Process markdown with linking
+    let processed = processor
+        .process_markdown(md, &mut local_state)
+        .map_err(|e| RenderErrorReason::Other(format!("Markdown processing failed: {}", e)))?;
+
+    // Convert to HTML
+    Ok(comrak::markdown_to_html(
+        &processed,
+        &comrak::ComrakOptions::default(),
+    ))
+}
 
-            // Convert to HTML
-            let html = comrak::markdown_to_html(&processed, &comrak::ComrakOptions::default());
-            out.write(&html)?;
-        }
+/// Helper linking to a team (`{{{team_link "lang"}}}`) or a user (`{{{user_link "@ghost"}}}`),
+/// like the markdown processor does for `[lang][]` and `@ghost` in goal documents.
+struct LinkHelper {
+    processor: Arc<MarkdownProcessor>,
+
+    /// The markdown for the helper's parameter
+    markdown: fn(&str) -> std::result::Result<String, RenderError>,
+}
+
+impl HelperDef for LinkHelper {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _: &'reg Handlebars<'reg>,
+        _: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let Some(name) = h.param(0).and_then(|v| v.value().as_str()) else {
+            return Err(RenderErrorReason::Other(format!("`{}` expects a name", h.name())).into());
+        };
+        let html = render_markdown(&self.processor, &(self.markdown)(name)?)?;
+
+        // A single paragraph, which we want inline
+        let html = html.trim_end();
+        let html = html
+            .strip_prefix("<p>")
+            .and_then(|html| html.strip_suffix("</p>"))
+            .unwrap_or(html);
+        out.write(html)?;
         Ok(())
     }
 }
 
+fn team_markdown(name: &str) -> std::result::Result<String, RenderError> {
+    let name = name.trim().trim_start_matches("T-");
+    match team::get_team_name(name) {
+        Ok(Some(team)) => Ok(team.name()),
+        Ok(None) => Err(RenderErrorReason::Other(format!("unknown team `{name}`")).into()),
+        Err(e) => Err(RenderErrorReason::Other(format!("failed to load teams: {e}")).into()),
+    }
+}
+
+fn user_markdown(name: &str) -> std::result::Result<String, RenderError> {
+    let name = name.trim();
+    Ok(if name.starts_with('@') {
+        name.to_string()
+    } else {
+        format!("@{name}")
+    })
+}
+
+// Format a date like `2026-03-01`, or a timestamp like `2026-03-01T12:00:00Z`, with a
+// `chrono` format string; the default gives `March 1, 2026`.
+handlebars::handlebars_helper!(format_date: |date: str, *args| {
+    // `args` are all the parameters, including `date`
+    let format = args.get(1).and_then(|v| v.as_str()).unwrap_or("%B %-d, %Y");
+    let parsed = NaiveDate::parse_from_str(date, "%Y-%m-%d").ok().or_else(|| {
+        chrono::DateTime::parse_from_rfc3339(date)
+            .ok()
+            .map(|d| d.date_naive())
+    });
+    match parsed {
+        Some(date) => date.format(format).to_string(),
+        None => date.to_string(),
+    }
+});
+
+// `{{pluralize count "update"}}` gives `1 update` or `3 updates`; an irregular plural can
+// be given as a third argument, like `{{pluralize count "entry" "entries"}}`.
+handlebars::handlebars_helper!(pluralize: |count: u64, singular: str, *args| {
+    if count == 1 {
+        format!("{count} {singular}")
+    } else {
+        match args.get(2).and_then(|v| v.as_str()) {
+            Some(plural) => format!("{count} {plural}"),
+            None => format!("{count} {singular}s"),
+        }
+    }
+});
+
+// `{{{list_item text}}}` gives markdown that can follow `* [goal](url): ` in a list.
+handlebars::handlebars_helper!(list_item: |text: str| list_item_text(text));
+
+/// The lines of `text` joined with spaces, as markdown renders them anyway, unless it has
+/// a code block. Then it is indented as a block of its own within the list item, and
+/// followed by an indented line for the rest of the item.
+fn list_item_text(text: &str) -> String {
+    if !text
+        .lines()
+        .any(|line| line.trim_start().starts_with("```"))
+    {
+        return text.lines().map(str::trim).collect::<Vec<_>>().join(" ");
+    }
+    let mut output = String::from("\n\n");
+    for line in text.lines() {
+        if !line.is_empty() {
+            output.push_str("  ");
+            output.push_str(line);
+        }
+        output.push('\n');
+    }
+    output.push_str("\n  ");
+    output
+}
+
 handlebars::handlebars_helper!(is_complete: |p: Progress| match p {
     Progress::Binary { is_closed } => is_closed,
     Progress::Tracked { completed, total } => completed == total,
@@ -116,6 +302,15 @@ pub struct Updates {
     pub other_goals: Vec<UpdatesGoal>,
     pub goal_count: usize,
     pub roadmap_goal_count: usize,
+
+    /// Sections of goals, unless the goals are grouped by theme
+    pub groups: Vec<GoalGroup>,
+
+    /// Blockers of all goals
+    pub blockers: Vec<GoalUpdateItem>,
+
+    /// Decisions needed by all goals
+    pub decisions_needed: Vec<GoalUpdateItem>,
 }
 
 #[derive(Serialize, Debug)]
@@ -124,11 +319,18 @@ pub struct ThemeSection {
     pub goals: Vec<UpdatesGoal>,
 }
 
+#[derive(Serialize, Debug)]
+pub struct GoalGroup {
+    pub name: String,
+    pub goals: Vec<UpdatesGoal>,
+}
+
 impl Updates {
     pub fn new(
         milestone: String,
         roadmap_goals: Vec<UpdatesGoal>,
         other_goals: Vec<UpdatesGoal>,
+        group_by: GroupBy,
     ) -> Self {
         // Group roadmap goals by theme
         let mut themes_map: std::collections::BTreeMap<String, Vec<UpdatesGoal>> =
@@ -155,20 +357,212 @@ impl Updates {
             .map(|(theme_name, goals)| ThemeSection { theme_name, goals })
             .collect();
 
+        let groups = goal_groups(roadmap_goals.iter().chain(&other_goals), group_by);
+
+        let across_goals = |items: fn(&UpdatesGoal) -> &Vec<UpdateItem>| {
+            roadmap_goals
+                .iter()
+                .chain(&other_goals)
+                .flat_map(|goal| {
+                    items(goal).iter().map(|item| GoalUpdateItem {
+                        title: goal.title.clone(),
+                        issue_url: goal.issue_url.clone(),
+                        team_champions: goal.team_champions.clone(),
+                        item: item.clone(),
+                    })
+                })
+                .collect::<Vec<_>>()
+        };
+        let blockers = across_goals(|goal| &goal.blockers);
+        let decisions_needed = across_goals(|goal| &goal.decisions_needed);
+
         Updates {
             milestone,
+            groups,
+            blockers,
+            decisions_needed,
             roadmap_goal_count: roadmap_goals.len(),
             goal_count: roadmap_goals.len() + other_goals.len(),
             roadmap_goals_by_theme,
             other_goals,
         }
     }
-    pub fn render(self) -> Result<String> {
-        let templates = Templates::new()?;
-        Ok(templates.reg.render("updates", &self)?)
+
+    pub fn render(self, templates: &Templates<'_>) -> Result<String> {
+        self.render_as(templates, "updates")
+    }
+
+    /// Render with the template `name`, e.g. `updates_text` for the text of email digests.
+    pub fn render_as(&self, templates: &Templates<'_>, name: &str) -> Result<String> {
+        Ok(templates.reg.render(name, self)?)
     }
 }
 
+/// Group goals into sections named after the champion team, point of contact, or support
+/// level. A goal appears in the section of each of its teams or points of contact. Goals
+/// grouped by theme are laid out by the template itself, so there are no groups.
+fn goal_groups<'g>(
+    goals: impl Iterator<Item = &'g UpdatesGoal>,
+    group_by: GroupBy,
+) -> Vec<GoalGroup> {
+    if group_by == GroupBy::Theme {
+        return vec![];
+    }
+
+    let mut groups: BTreeMap<String, Vec<UpdatesGoal>> = BTreeMap::new();
+    let mut ungrouped = vec![];
+
+    for goal in goals {
+        let names = match group_by {
+            GroupBy::Theme => vec![],
+            GroupBy::Champion => goal.champion_teams.clone(),
+            GroupBy::PointOfContact => goal.point_of_contact_usernames.clone(),
+            GroupBy::SupportLevel => goal.support_level.iter().cloned().collect(),
+        };
+        if names.is_empty() {
+            ungrouped.push(goal.clone());
+        }
+        for name in names {
+            groups.entry(name).or_default().push(goal.clone());
+        }
+    }
+
+    let mut groups: Vec<GoalGroup> = match group_by {
+        GroupBy::Champion => groups
+            .into_iter()
+            .map(|(team, goals)| GoalGroup {
+                name: format!("Championed by the {team} team"),
+                goals,
+            })
+            .collect(),
+        GroupBy::Theme | GroupBy::PointOfContact => groups
+            .into_iter()
+            .map(|(name, goals)| GoalGroup { name, goals })
+            .collect(),
+        // Largest support level first
+        GroupBy::SupportLevel => {
+            let mut levels: Vec<SupportLevel> = SupportLevel::all().collect();
+            levels.reverse();
+            levels
+                .into_iter()
+                .filter_map(|level| {
+                    let goals = groups.remove(level.as_str())?;
+                    Some(GoalGroup {
+                        name: format!("{} support", level.as_str()),
+                        goals,
+                    })
+                })
+                .collect()
+        }
+    };
+
+    if !ungrouped.is_empty() {
+        groups.push(GoalGroup {
+            name: match group_by {
+                GroupBy::Champion => "Without a champion",
+                GroupBy::PointOfContact => "Without a point of contact",
+                _ => "Without a support level",
+            }
+            .to_string(),
+            goals: ungrouped,
+        });
+    }
+
+    for group in &mut groups {
+        group
+            .goals
+            .sort_by_cached_key(|goal| goal.title.to_lowercase());
+        super::mark_separators(&mut group.goals);
+    }
+
+    groups
+}
+
+/// The parameters expected by the `point_of_contact_digest.hbs` template.
+#[derive(Serialize, Debug)]
+pub struct PointOfContactDigest {
+    pub milestone: String,
+
+    /// Username of the point of contact, like `@ghost`
+    pub username: String,
+
+    /// First day of the updates
+    pub start_date: String,
+
+    /// Goals with updates since `start_date`
+    pub updated_goals: Vec<DigestGoal>,
+
+    /// Open goals without updates since `start_date`
+    pub goals_missing_updates: Vec<DigestGoal>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct DigestGoal {
+    pub title: String,
+    pub issue_url: String,
+    pub tldr: Option<String>,
+
+    /// Most recent comment on the tracking issue since the start date
+    pub latest_update: Option<ExistingGithubComment>,
+}
+
+impl PointOfContactDigest {
+    pub fn new(
+        milestone: &str,
+        username: &str,
+        start_date: NaiveDate,
+        goals: &[&UpdatesGoal],
+    ) -> Self {
+        let mut updated_goals = vec![];
+        let mut goals_missing_updates = vec![];
+        for goal in goals {
+            let digest_goal = DigestGoal {
+                title: goal.title.clone(),
+                issue_url: goal.issue_url.clone(),
+                tldr: goal.tldr.clone(),
+                latest_update: goal.comments.iter().max_by_key(|c| &c.created_at).cloned(),
+            };
+            if digest_goal.latest_update.is_some() || digest_goal.tldr.is_some() {
+                updated_goals.push(digest_goal);
+            } else if !goal.is_closed {
+                goals_missing_updates.push(digest_goal);
+            }
+        }
+
+        PointOfContactDigest {
+            milestone: milestone.to_string(),
+            username: username.to_string(),
+            start_date: start_date.to_string(),
+            updated_goals,
+            goals_missing_updates,
+        }
+    }
+
+    pub fn render(&self, templates: &Templates<'_>) -> Result<String> {
+        Ok(templates.reg.render("point_of_contact_digest", self)?)
+    }
+}
+
+/// Render markdown, like the output of the `updates` template, as a standalone HTML
+/// document, e.g. for emails. Unlike the `markdown_to_html` helper, HTML is kept as is.
+pub fn markdown_to_html_document(markdown: &str) -> Result<String> {
+    let goals_config = GoalsConfig::from_configuration(Configuration::get()).map_err(|e| {
+        rust_project_goals::spanned::Error::str(format!("Failed to load goals config: {}", e))
+    })?;
+    let processed = MarkdownProcessor::new(goals_config)
+        .process_markdown(markdown, &mut MarkdownProcessorState::default())
+        .map_err(|e| {
+            rust_project_goals::spanned::Error::str(format!("Markdown processing failed: {}", e))
+        })?;
+
+    let mut options = comrak::ComrakOptions::default();
+    options.render.unsafe_ = true;
+    let html = comrak::markdown_to_html(&processed, &options);
+    Ok(format!(
+        "<!DOCTYPE html>\n<html>\n<body>\n{html}</body>\n</html>\n"
+    ))
+}
+
 /// Part of the parameters expected by the `updates.md` template.
 #[derive(Serialize, Debug, Clone)]
 pub struct UpdatesGoal {
@@ -227,9 +621,243 @@ pub struct UpdatesGoal {
 
     /// Task owners for this goal (individual contributors)
     pub task_owners: String,
+
+    /// Names of the teams that champion this goal (e.g., `["lang", "compiler"]`)
+    pub champion_teams: Vec<String>,
+
+    /// Usernames of the points of contact (e.g., `["@nikomatsakis"]`)
+    pub point_of_contact_usernames: Vec<String>,
+
+    /// Largest level of support the goal asks of a team (e.g., `"Large"`)
+    pub support_level: Option<String>,
+
+    /// What the goal is blocked on, from the most recent update that says
+    pub blockers: Vec<UpdateItem>,
+
+    /// Next steps, from the most recent update that lists any
+    pub next_steps: Vec<UpdateItem>,
+
+    /// Decisions the goal needs from teams, from the most recent update that lists any
+    pub decisions_needed: Vec<UpdateItem>,
+
+    /// What shipped, from all updates
+    pub shipped: Vec<UpdateItem>,
+}
+
+/// A [structured item](`rust_project_goals::update_items`) of an update comment.
+#[derive(Serialize, Debug, Clone)]
+pub struct UpdateItem {
+    /// Markdown text of the item
+    pub text: String,
+
+    /// URL of the comment
+    pub url: String,
+
+    pub author: String,
+
+    /// Date of the comment
+    pub created_at: String,
+}
+
+/// An update item along with the goal it is about, for sections aggregated across goals.
+#[derive(Serialize, Debug, Clone)]
+pub struct GoalUpdateItem {
+    pub title: String,
+    pub issue_url: String,
+
+    /// Team champions of the goal, like `UpdatesGoal::team_champions`
+    pub team_champions: String,
+
+    #[serde(flatten)]
+    pub item: UpdateItem,
 }
 
 #[derive(Serialize, Debug, Clone)]
 pub struct HelpWanted {
     pub text: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn goal(title: &str, champion_teams: &[&str], support_level: Option<&str>) -> UpdatesGoal {
+        UpdatesGoal {
+            title: title.to_string(),
+            issue_number: 1,
+            issue_assignees: String::new(),
+            issue_url: String::new(),
+            issue_link_text: String::new(),
+            is_closed: false,
+            has_help_wanted: false,
+            help_wanted: vec![],
+            comments: vec![],
+            details_summary: String::new(),
+            progress: Progress::Binary { is_closed: false },
+            tldr: None,
+            why_this_goal: String::new(),
+            needs_separator: false,
+            theme: vec![],
+            point_of_contact: String::new(),
+            team_champions: String::new(),
+            task_owners: String::new(),
+            champion_teams: champion_teams.iter().map(|t| t.to_string()).collect(),
+            point_of_contact_usernames: vec![],
+            support_level: support_level.map(str::to_string),
+            blockers: vec![],
+            next_steps: vec![],
+            decisions_needed: vec![],
+            shipped: vec![],
+        }
+    }
+
+    /// The name of each group, with the titles of its goals (sorted by title).
+    fn groups(goals: &[UpdatesGoal], group_by: GroupBy) -> Vec<(String, Vec<String>)> {
+        goal_groups(goals.iter(), group_by)
+            .into_iter()
+            .map(|group| {
+                let titles = group.goals.into_iter().map(|goal| goal.title).collect();
+                (group.name, titles)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn support_level_groups() {
+        let levels: Vec<&str> = SupportLevel::all().map(|level| level.as_str()).collect();
+        let (smallest, largest) = (levels[0], levels[levels.len() - 1]);
+        let goals = [
+            goal("Small one", &[], Some(smallest)),
+            goal("Unsized", &[], None),
+            goal("Large one", &[], Some(largest)),
+            goal("Another small one", &[], Some(smallest)),
+        ];
+        assert_eq!(
+            groups(&goals, GroupBy::SupportLevel),
+            [
+                (format!("{largest} support"), vec!["Large one".to_string()]),
+                (
+                    format!("{smallest} support"),
+                    vec!["Another small one".to_string(), "Small one".to_string()]
+                ),
+                (
+                    "Without a support level".to_string(),
+                    vec!["Unsized".to_string()]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn champion_groups() {
+        let goals = [
+            goal("Both", &["lang", "compiler"], None),
+            goal("Orphan", &[], None),
+            goal("Lang", &["lang"], None),
+        ];
+        assert_eq!(
+            groups(&goals, GroupBy::Champion),
+            [
+                (
+                    "Championed by the compiler team".to_string(),
+                    vec!["Both".to_string()]
+                ),
+                (
+                    "Championed by the lang team".to_string(),
+                    vec!["Both".to_string(), "Lang".to_string()]
+                ),
+                ("Without a champion".to_string(), vec!["Orphan".to_string()]),
+            ]
+        );
+        assert!(groups(&goals, GroupBy::Theme).is_empty());
+    }
+
+    /// Render `template` with the built-in helpers, and `@alice` configured as "Alice".
+    fn render(template: &str) -> std::result::Result<String, String> {
+        let mut config = GoalsConfig::default();
+        config
+            .users
+            .insert("@alice".to_string(), "Alice".to_string());
+        let templates = Templates::from_templates_dir(builtin_templates_dir(), config).unwrap();
+        templates
+            .reg
+            .render_template(template, &())
+            .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn format_date_helper() {
+        assert_eq!(
+            render(r#"{{format_date "2026-03-01"}}"#).unwrap(),
+            "March 1, 2026"
+        );
+        assert_eq!(
+            render(r#"{{format_date "2026-03-01T23:30:00-05:00"}}"#).unwrap(),
+            "March 1, 2026"
+        );
+        assert_eq!(
+            render(r#"{{format_date "2026-03-01T12:00:00Z" "%d %b"}}"#).unwrap(),
+            "01 Mar"
+        );
+        assert_eq!(render(r#"{{format_date "soon"}}"#).unwrap(), "soon");
+    }
+
+    #[test]
+    fn pluralize_helper() {
+        assert_eq!(render(r#"{{pluralize 1 "update"}}"#).unwrap(), "1 update");
+        assert_eq!(render(r#"{{pluralize 0 "update"}}"#).unwrap(), "0 updates");
+        assert_eq!(render(r#"{{pluralize 3 "update"}}"#).unwrap(), "3 updates");
+        assert_eq!(
+            render(r#"{{pluralize 1 "entry" "entries"}}"#).unwrap(),
+            "1 entry"
+        );
+        assert_eq!(
+            render(r#"{{pluralize 2 "entry" "entries"}}"#).unwrap(),
+            "2 entries"
+        );
+    }
+
+    #[test]
+    fn link_helpers_need_a_name() {
+        let error = render("{{{team_link}}}").unwrap_err();
+        assert!(error.contains("`team_link` expects a name"), "{error}");
+        let error = render("{{{user_link}}}").unwrap_err();
+        assert!(error.contains("`user_link` expects a name"), "{error}");
+    }
+
+    #[test]
+    #[ignore = "fetches the team data, like the markdown processor always does"]
+    fn team_link_helper() {
+        let lang = render(r#"{{{team_link "lang"}}}"#).unwrap();
+        assert!(lang.starts_with("<a href=\"http"), "{lang}");
+        assert!(lang.ends_with("\">lang</a>"), "{lang}");
+        assert_eq!(render(r#"{{{team_link "T-lang"}}}"#).unwrap(), lang);
+
+        let error = render(r#"{{{team_link "T-frobnicators"}}}"#).unwrap_err();
+        assert!(error.contains("unknown team `frobnicators`"), "{error}");
+    }
+
+    #[test]
+    #[ignore = "fetches the team data, like the markdown processor always does"]
+    fn user_link_helper() {
+        let alice = "<a href=\"https://github.com/alice\">Alice</a>";
+        assert_eq!(render(r#"{{{user_link "@alice"}}}"#).unwrap(), alice);
+        assert_eq!(render(r#"{{{user_link "alice"}}}"#).unwrap(), alice);
+        assert_eq!(
+            render(r#"{{{user_link "@no-such-user-on-any-team"}}}"#).unwrap(),
+            "@no-such-user-on-any-team"
+        );
+    }
+
+    #[test]
+    fn list_items_stay_in_their_item() {
+        assert_eq!(
+            list_item_text("the new trait solver\nlanding in nightly."),
+            "the new trait solver landing in nightly."
+        );
+        assert_eq!(
+            list_item_text("run\n```sh\ncargo build\n```"),
+            "\n\n  run\n  ```sh\n  cargo build\n  ```\n\n  "
+        );
+    }
+}
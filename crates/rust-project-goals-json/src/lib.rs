@@ -4,6 +4,8 @@
 //! to the types in `gh` and so forth but because they represent
 //! a versioned API, we copy them over here to insulate them from incidental changes.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize)]
@@ -35,6 +37,122 @@ pub struct TrackingIssue {
 
     /// Issue state
     pub state: GithubIssueState,
+
+    /// Links to the Zulip channels where the goal is discussed
+    #[serde(default)]
+    pub zulip_channels: Vec<String>,
+
+    /// Links to issues in other repositories that track this goal
+    #[serde(default)]
+    pub other_tracking_issues: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ContributorOpportunities {
+    pub milestone: String,
+    pub opportunities: Vec<ContributorOpportunity>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ContributorOpportunity {
+    /// Title of the goal looking for help
+    pub goal: String,
+
+    /// Link to the goal page
+    pub goal_url: String,
+
+    /// Roadmap themes of the goal
+    pub roadmaps: Vec<String>,
+
+    /// Description of the task
+    pub task: String,
+
+    /// Normalized experience level (`beginner`, `intermediate`, or `advanced`), if known
+    pub level: Option<String>,
+
+    /// Experience level as written by the goal author
+    pub experience_level: String,
+
+    /// Upper bound of the expected time investment in weeks (approximate), if known
+    pub max_weeks: Option<u32>,
+
+    /// Time investment as written by the goal author
+    pub time_investment: String,
+
+    /// `goal` for items from the goal document, `update` for items from status updates
+    pub source: String,
+
+    /// Link to where the task is described
+    pub url: String,
+}
+
+/// The goals of a milestone, as embedded in the goal explorer page.
+#[derive(Serialize, Deserialize)]
+pub struct GoalIndex {
+    pub milestone: String,
+
+    /// Support levels, from least to most involvement
+    pub support_levels: Vec<String>,
+
+    pub goals: Vec<GoalIndexEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct GoalIndexEntry {
+    pub title: String,
+
+    /// Link to the goal page, relative to the milestone directory
+    pub url: String,
+
+    /// `Proposed`, `Accepted`, or `Not accepted`
+    pub status: String,
+
+    /// Usernames of the points of contact
+    pub point_of_contact: Vec<String>,
+
+    /// Champion of each team, by team name
+    pub champions: BTreeMap<String, String>,
+
+    pub team_asks: Vec<GoalIndexTeamAsk>,
+
+    /// Largest support level asked of any team, for goals that use support levels
+    pub support_level: Option<String>,
+
+    /// Roadmap themes of the goal
+    pub roadmaps: Vec<String>,
+
+    pub highlights: Vec<String>,
+
+    pub needs: Vec<String>,
+
+    /// Funding status (`No`, `Partial`, or `Full`), if the goal has a funding section
+    pub funding: Option<String>,
+
+    /// Link to the tracking issue
+    pub tracking_issue: Option<String>,
+
+    /// Progress of the tracking issue, if it could be loaded
+    pub progress: Option<Progress>,
+}
+
+/// Something a goal asks of a team.
+#[derive(Serialize, Deserialize)]
+pub struct GoalIndexTeamAsk {
+    pub team: String,
+
+    /// Support level, for goals that use support levels (2026+)
+    pub level: Option<String>,
+
+    /// What the team is asked to do, for goals that list specific asks (before 2026)
+    pub ask: Option<String>,
+
+    /// Title of the subgoal the ask belongs to, if any
+    pub subgoal: Option<String>,
+
+    pub notes: String,
+
+    /// Champion of the team for this goal
+    pub champion: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
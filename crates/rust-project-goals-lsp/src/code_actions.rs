@@ -0,0 +1,62 @@
+//! Quick fixes that add missing rows to the metadata table.
+
+use std::collections::{BTreeSet, HashMap};
+
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, Range, TextEdit, Url, WorkspaceEdit,
+};
+use rust_project_goals::goal::TRACKING_ISSUE_ROW;
+
+use crate::document::Document;
+
+pub fn code_actions(uri: &Url, document: &Document) -> Vec<CodeActionOrCommand> {
+    let Ok(Some(goal)) = document.load_goal() else {
+        return vec![];
+    };
+    let metadata = &goal.metadata;
+
+    let mut rows = vec![];
+
+    if !metadata
+        .table
+        .rows
+        .iter()
+        .any(|row| row[0].trim() == TRACKING_ISSUE_ROW)
+    {
+        rows.push((
+            "Insert tracking issue row".to_string(),
+            format!("| {TRACKING_ISSUE_ROW} | |"),
+        ));
+    }
+
+    // Asks at some support levels (e.g., medium and large) need a champion from the team.
+    let mut teams = BTreeSet::new();
+    for support in goal.team_involvement.as_support().into_iter().flatten() {
+        if support.support_level.requires_champion()
+            && !metadata.champions.contains_key(&support.team)
+            && teams.insert(support.team)
+        {
+            rows.push((
+                format!("Add {} champion row", support.team),
+                format!("| {} champion | |", support.team),
+            ));
+        }
+    }
+
+    // New rows go at the end of the metadata table.
+    let end = document.position(metadata.table.span.bytes.end);
+    rows.into_iter()
+        .map(|(title, row)| {
+            let edit = TextEdit::new(Range::new(end, end), format!("\n{row}"));
+            CodeActionOrCommand::CodeAction(CodeAction {
+                title,
+                kind: Some(CodeActionKind::QUICKFIX),
+                edit: Some(WorkspaceEdit {
+                    changes: Some(HashMap::from([(uri.clone(), vec![edit])])),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
+        })
+        .collect()
+}
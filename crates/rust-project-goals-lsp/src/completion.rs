@@ -0,0 +1,192 @@
+//! Completions for the metadata table, the team asks table, and `@usernames`.
+
+use std::collections::BTreeMap;
+
+use lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionTextEdit, Position, Range, TextEdit,
+};
+use rust_project_goals::config::{Configuration, Vocabulary};
+use rust_project_goals::goal::{self, Status, SupportLevel, TRACKING_ISSUE_ROW};
+use rust_project_goals::team;
+
+use crate::document::{Context, Document};
+
+/// Rows of the metadata table, with a description of what goes in them.
+/// `[team] champion` rows are completed separately, one per team.
+const METADATA_ROWS: &[(&str, &str)] = &[
+    (
+        "Point of contact",
+        "GitHub username of the person driving the goal",
+    ),
+    ("Status", "`Proposed`, `Accepted`, or `Not accepted`"),
+    (
+        TRACKING_ISSUE_ROW,
+        "Issue tracking the goal, like `rust-lang/rust-project-goals#123`",
+    ),
+    (
+        "Short title",
+        "Title used in tables, if the title is too long",
+    ),
+    ("What and why", "One-line summary used in goal tables"),
+    (
+        "Timespan",
+        "When the goal runs, if not the whole milestone (e.g., `2026-2027`)",
+    ),
+    (
+        "Roadmap",
+        "Roadmap theme the goal belongs to; can be repeated",
+    ),
+    (
+        "Highlight",
+        "Highlight theme the goal belongs to; can be repeated",
+    ),
+    ("Needs", "What the goal is looking for; can be repeated"),
+    (
+        "Zulip channel",
+        "Channel for discussing the goal (e.g., `#t-lang`)",
+    ),
+    ("Funding contact", "Who to contact about funding the goal"),
+    (
+        "Other tracking issues",
+        "Issues in other repositories that track the work",
+    ),
+    (
+        "Depends on",
+        "Goal that must land before this one; can be repeated",
+    ),
+    (
+        "Blocks",
+        "Goal that cannot land before this one; can be repeated",
+    ),
+];
+
+pub fn completions(document: &Document, position: Position) -> Vec<CompletionItem> {
+    let context = document.context(position);
+
+    if context.word.starts_with('@') {
+        return usernames()
+            .into_iter()
+            .map(|(username, name)| word_item(&context, &username, Some(name)))
+            .collect();
+    }
+
+    let Some(table) = &context.table else {
+        return vec![];
+    };
+
+    match (table.header[0].as_str(), table.column) {
+        ("Metadata", 0) => METADATA_ROWS
+            .iter()
+            .map(|&(row, detail)| cell_item(&context, row, Some(detail.to_string())))
+            .chain(
+                teams()
+                    .into_iter()
+                    .map(|team| cell_item(&context, &format!("{team} champion"), None)),
+            )
+            .collect(),
+
+        ("Metadata", 1) => metadata_values(document, &context, &table.row_name),
+
+        ("Team", 0) => teams()
+            .into_iter()
+            .map(|team| cell_item(&context, &team, None))
+            .collect(),
+
+        ("Team", 1) => SupportLevel::all()
+            .map(|level| {
+                let details = level.details();
+                cell_item(&context, level.as_str(), Some(details.description.clone()))
+            })
+            .collect(),
+
+        // Pre-2026 goals list their asks in the `Task` column of this section.
+        ("Task", 0) if context.section == "Ownership and team asks" => Configuration::get()
+            .team_asks
+            .iter()
+            .map(|(ask, details)| cell_item(&context, ask, Some(details.about.clone())))
+            .collect(),
+
+        _ => vec![],
+    }
+}
+
+fn metadata_values(document: &Document, context: &Context, row_name: &str) -> Vec<CompletionItem> {
+    let config = Configuration::get();
+    match row_name {
+        "Status" => Status::VALID_VALUES
+            .iter()
+            .map(|(status, _)| cell_item(context, status, None))
+            .collect(),
+
+        "Roadmap" => {
+            let Some(dir) = document.milestone_dir() else {
+                return vec![];
+            };
+            goal::roadmaps_in_dir(dir)
+                .unwrap_or_default()
+                .iter()
+                .map(|r| cell_item(context, &r.short_title, Some(r.what_and_why.clone())))
+                .collect()
+        }
+
+        "Highlight" => vocabulary_items(context, &config.highlights),
+
+        "Needs" => vocabulary_items(context, &config.needs),
+
+        _ if row_name == "Point of contact" || row_name.ends_with(" champion") => usernames()
+            .into_iter()
+            .map(|(username, name)| cell_item(context, &username, Some(name)))
+            .collect(),
+
+        _ => vec![],
+    }
+}
+
+fn vocabulary_items(context: &Context, vocabulary: &Vocabulary) -> Vec<CompletionItem> {
+    vocabulary
+        .iter()
+        .map(|(name, details)| cell_item(context, name, Some(details.description.clone())))
+        .collect()
+}
+
+/// Team names as written in goal documents, like `[lang]`.
+fn teams() -> Vec<String> {
+    match team::get_team_names() {
+        Ok(names) => names.map(|team| team.to_string()).collect(),
+        Err(_) => vec![],
+    }
+}
+
+/// The `@username`s of all team members, mapped to their names.
+fn usernames() -> BTreeMap<String, String> {
+    let Ok(names) = team::get_team_names() else {
+        return BTreeMap::new();
+    };
+    names
+        .flat_map(|team| &team.data().members)
+        .map(|member| (format!("@{}", member.github), member.name.clone()))
+        .collect()
+}
+
+/// An item replacing the table cell up to the cursor.
+fn cell_item(context: &Context, label: &str, detail: Option<String>) -> CompletionItem {
+    item(context.cell_start, context.cursor, label, detail)
+}
+
+/// An item replacing the word up to the cursor.
+fn word_item(context: &Context, label: &str, detail: Option<String>) -> CompletionItem {
+    item(context.word_start, context.cursor, label, detail)
+}
+
+fn item(start: Position, end: Position, label: &str, detail: Option<String>) -> CompletionItem {
+    CompletionItem {
+        label: label.to_string(),
+        kind: Some(CompletionItemKind::VALUE),
+        detail,
+        text_edit: Some(CompletionTextEdit::Edit(TextEdit::new(
+            Range::new(start, end),
+            label.to_string(),
+        ))),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,192 @@
+//! Diagnostics: the errors `cargo rpg check` would report for the document.
+
+use std::path::{Path, PathBuf};
+
+use lsp_types::{Diagnostic, DiagnosticSeverity, Position, Range};
+use regex::Regex;
+use rust_project_goals::goal::{self, ValidationError};
+use rust_project_goals::spanned;
+
+use crate::document::Document;
+
+pub fn diagnostics(document: &Document) -> Vec<Diagnostic> {
+    let Some(milestone_dir) = document.milestone_dir() else {
+        return vec![];
+    };
+
+    let goal = match document.load_goal() {
+        Ok(Some(goal)) => goal,
+        Ok(None) => return vec![],
+        Err(e) => return vec![error_diagnostic(document, &e)],
+    };
+    let goals = vec![goal];
+
+    // The checks that only need this goal (and the roadmaps of its milestone).
+    // Each one stops at its first error.
+    let mut errors = vec![];
+    errors.extend(goal::validate_username_consistency(&goals).err());
+    errors.extend(goal::validate_timespans(&goals).err());
+    errors.extend(goal::validate_sponsors(&goals).err());
+    errors.extend(goal::validate_highlights_and_needs(&goals).err());
+    errors.extend(goal::validate_champions(&goals).err());
+    match goal::roadmaps_in_dir(milestone_dir) {
+        Ok(roadmaps) => {
+            errors.extend(goal::validate_roadmap_references(&goals, &roadmaps).err());
+        }
+        Err(e) => {
+            return vec![error_diagnostic(document, &e)];
+        }
+    }
+
+    let mut diagnostics: Vec<Diagnostic> = errors
+        .into_iter()
+        .map(|e| diagnostic(range(document, &e), e.message))
+        .collect();
+    diagnostics.extend(dependency_diagnostic(document, milestone_dir, goals));
+    diagnostics
+}
+
+/// The first error in the `Depends on` and `Blocks` rows of the milestone's goals, with this
+/// goal as it is in the editor. Errors in the rows of other goals are left to those goals;
+/// if another goal fails to load, the check is skipped.
+fn dependency_diagnostic(
+    document: &Document,
+    milestone_dir: &Path,
+    goals: Vec<goal::GoalDocument>,
+) -> Option<Diagnostic> {
+    let mut milestone_goals = goal::goals_in_dir(milestone_dir).ok()?;
+    milestone_goals.retain(|g| goals.iter().all(|goal| goal.link_path != g.link_path));
+    milestone_goals.extend(goals);
+
+    let e = goal::validate_dependencies(&milestone_goals).err()?;
+    match location(&message_text(&e)) {
+        Some((file, ..)) if file != document.path && file.parent() == Some(milestone_dir) => None,
+        _ => Some(error_diagnostic(document, &e)),
+    }
+}
+
+/// The text that `error` is about, or the first line if it is not about a part of this document.
+fn range(document: &Document, error: &ValidationError) -> Range {
+    match &error.span {
+        Some(span) if span.file == document.path => Range::new(
+            document.position(span.bytes.start),
+            document.position(span.bytes.end),
+        ),
+        _ => document.line_range(0),
+    }
+}
+
+/// A diagnostic for an error that is not a `ValidationError`. `spanned::Error` does not
+/// expose its span, so the location is taken from its message, which names the file, line
+/// and column like a compiler error does. The location is computed against the file on disk,
+/// so it can be off while the document has unsaved changes. Errors that are not about a part
+/// of this document go on the first line.
+fn error_diagnostic(document: &Document, error: &spanned::Error) -> Diagnostic {
+    let text = message_text(error);
+    let title = text.lines().next().unwrap_or_default();
+    let message = title.strip_prefix("error: ").unwrap_or(title).to_string();
+
+    let range = match location(&text) {
+        Some((file, line, column)) if file == document.path => {
+            let line_range = document.line_range(line.saturating_sub(1));
+            let start = column.saturating_sub(1).min(line_range.end.character);
+            Range::new(Position::new(line_range.start.line, start), line_range.end)
+        }
+        _ => document.line_range(0),
+    };
+    diagnostic(range, message)
+}
+
+/// The message of `error`, without the colors it has when written to a terminal.
+fn message_text(error: &spanned::Error) -> String {
+    lazy_static::lazy_static! {
+        static ref COLOR: Regex = Regex::new("\x1b\\[[0-9;]*m").unwrap();
+    }
+
+    COLOR.replace_all(&error.to_string(), "").into_owned()
+}
+
+/// The file, (one-based) line and column from the `--> file:line:column` line of an error message.
+fn location(text: &str) -> Option<(PathBuf, u32, u32)> {
+    lazy_static::lazy_static! {
+        static ref LOCATION: Regex = Regex::new(r"(?m)^\s*--> (.+):(\d+):(\d+)\s*$").unwrap();
+    }
+
+    let captures = LOCATION.captures(text)?;
+    Some((
+        PathBuf::from(&captures[1]),
+        captures[2].parse().ok()?,
+        captures[3].parse().ok()?,
+    ))
+}
+
+fn diagnostic(range: Range, message: String) -> Diagnostic {
+    Diagnostic {
+        range,
+        severity: Some(DiagnosticSeverity::ERROR),
+        source: Some("rust-project-goals".to_string()),
+        message,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GOAL: &str = "\
+# Title
+
+| Metadata         |          |
+| :--              | :--      |
+| Point of contact | @ghost   |
+| Status           | Prop     |
+";
+
+    #[test]
+    fn error_location() {
+        let text =
+            "error: no goal file `foo.md` in this milestone\n  --> src/2026/bar.md:12:21\n   |\n";
+        assert_eq!(
+            location(text),
+            Some((PathBuf::from("src/2026/bar.md"), 12, 21))
+        );
+        assert_eq!(location("error: no location\n"), None);
+    }
+
+    /// Diagnostics for `text`, saved as a goal of the 2026 milestone.
+    fn check(name: &str, text: &str) -> Vec<Diagnostic> {
+        let dir = std::env::temp_dir().join(format!("rpg-lsp-{}-{name}/2026", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("{name}.md"));
+        std::fs::write(&path, text).unwrap();
+
+        let diagnostics = diagnostics(&Document {
+            path,
+            text: text.to_string(),
+        });
+        std::fs::remove_dir_all(dir.parent().unwrap()).unwrap();
+        diagnostics
+    }
+
+    #[test]
+    fn load_error_on_its_line() {
+        let diagnostics = check("status", GOAL);
+        assert_eq!(diagnostics.len(), 1, "{diagnostics:?}");
+        assert_eq!(diagnostics[0].range.start, Position::new(5, 21));
+        assert!(diagnostics[0].message.starts_with("unrecognized status"));
+    }
+
+    #[test]
+    fn dependency_error() {
+        let text = GOAL.replace("Prop    ", "Not accepted")
+            + "| Depends on       | missing.md |\n\n## Team asks\n\n| Team | Support level | Notes |\n| --- | --- | --- |\n";
+        let diagnostics = check("depends", &text);
+        assert_eq!(diagnostics.len(), 1, "{diagnostics:?}");
+        assert_eq!(diagnostics[0].range.start.line, 6);
+        assert_eq!(
+            diagnostics[0].message,
+            "no goal file `missing.md` in this milestone"
+        );
+    }
+}
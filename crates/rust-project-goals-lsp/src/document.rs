@@ -0,0 +1,261 @@
+//! Open documents and the bits of their text that the requests care about.
+
+use std::path::{Path, PathBuf};
+
+use lsp_types::{Position, Range};
+use regex::Regex;
+use rust_project_goals::goal::GoalDocument;
+use rust_project_goals::spanned::Result;
+use rust_project_goals::util::MILESTONE_REGEX;
+
+/// The current contents of a document open in the editor.
+pub struct Document {
+    pub path: PathBuf,
+    pub text: String,
+}
+
+/// Where the cursor is, as far as completions are concerned.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Context {
+    /// Title of the enclosing section, without the `#`s (empty before the first title).
+    pub section: String,
+
+    /// Set if the cursor is in a table row.
+    pub table: Option<TableContext>,
+
+    /// Contents of the table cell up to the cursor, without leading whitespace.
+    pub cell: String,
+
+    /// Where `cell` starts.
+    pub cell_start: Position,
+
+    /// The word (e.g., `@user`) up to the cursor.
+    pub word: String,
+
+    /// Where `word` starts.
+    pub word_start: Position,
+
+    pub cursor: Position,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct TableContext {
+    /// Cells of the first row of the table.
+    pub header: Vec<String>,
+
+    /// Index of the column the cursor is in.
+    pub column: usize,
+
+    /// First cell of the row the cursor is in, e.g. `Status` in the metadata table.
+    pub row_name: String,
+}
+
+impl Document {
+    /// The milestone directory (e.g., `src/2026`) containing this document, if it is a goal
+    /// document; roadmap documents are excluded.
+    pub fn milestone_dir(&self) -> Option<&Path> {
+        lazy_static::lazy_static! {
+            static ref MILESTONE: Regex = Regex::new(MILESTONE_REGEX).unwrap();
+        }
+
+        let file_name = self.path.file_name()?.to_str()?;
+        if file_name.starts_with("roadmap-") || file_name == "TEMPLATE.md" {
+            return None;
+        }
+
+        let dir = self.path.parent()?;
+        MILESTONE
+            .is_match(dir.file_name()?.to_str()?)
+            .then_some(dir)
+    }
+
+    /// Parse the text as a goal document. Returns `None` if the document is not a goal.
+    pub fn load_goal(&self) -> Result<Option<GoalDocument>> {
+        if self.milestone_dir().is_none() {
+            return Ok(None);
+        }
+        let link_path = Path::new(self.path.file_name().unwrap());
+        GoalDocument::load_text(&self.path, link_path, &self.text)
+    }
+
+    /// The given (zero-based) line, without its line ending.
+    pub fn line(&self, line: u32) -> Option<&str> {
+        self.text
+            .split('\n')
+            .nth(line as usize)
+            .map(|l| l.trim_end_matches('\r'))
+    }
+
+    /// The range covering the given (zero-based) line.
+    pub fn line_range(&self, line: u32) -> Range {
+        let length = self.line(line).unwrap_or_default().encode_utf16().count();
+        Range::new(Position::new(line, 0), Position::new(line, length as u32))
+    }
+
+    pub fn context(&self, position: Position) -> Context {
+        context(&self.text, position)
+    }
+
+    pub fn position(&self, offset: usize) -> Position {
+        position(&self.text, offset)
+    }
+}
+
+/// The byte offset in `text` of an LSP position, whose `character` counts UTF-16 code units.
+pub fn offset(text: &str, position: Position) -> usize {
+    let mut line_start = 0;
+    for _ in 0..position.line {
+        match text[line_start..].find('\n') {
+            Some(i) => line_start += i + 1,
+            None => return text.len(),
+        }
+    }
+
+    let line_end = text[line_start..]
+        .find('\n')
+        .map_or(text.len(), |i| line_start + i);
+    let mut units = 0;
+    for (i, c) in text[line_start..line_end].char_indices() {
+        if units >= position.character {
+            return line_start + i;
+        }
+        units += c.len_utf16() as u32;
+    }
+    line_end
+}
+
+/// The LSP position of a byte offset in `text`.
+pub fn position(text: &str, offset: usize) -> Position {
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    Position::new(
+        before.matches('\n').count() as u32,
+        before[line_start..].encode_utf16().count() as u32,
+    )
+}
+
+fn context(text: &str, cursor: Position) -> Context {
+    let cursor_offset = offset(text, cursor);
+    let line_start = text[..cursor_offset].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = text[cursor_offset..]
+        .find('\n')
+        .map_or(text.len(), |i| cursor_offset + i);
+    let line = &text[line_start..line_end];
+    let before_cursor = &text[line_start..cursor_offset];
+    let lines_above: Vec<&str> = text[..line_start].lines().collect();
+
+    let section = lines_above
+        .iter()
+        .rev()
+        .find(|l| l.starts_with('#'))
+        .map(|l| l.trim_start_matches('#').trim().to_string())
+        .unwrap_or_default();
+
+    let is_table_row = |l: &str| l.trim_start().starts_with('|');
+    let table = is_table_row(line).then(|| {
+        let header = lines_above
+            .iter()
+            .rev()
+            .take_while(|l| is_table_row(l))
+            .last()
+            .copied()
+            .unwrap_or(line);
+        TableContext {
+            header: cells(header),
+            column: before_cursor.matches('|').count().saturating_sub(1),
+            row_name: cells(line).into_iter().next().unwrap_or_default(),
+        }
+    });
+
+    let cell = match before_cursor.rfind('|') {
+        Some(i) => &before_cursor[i + 1..],
+        None => before_cursor,
+    }
+    .trim_start();
+
+    let word_start = before_cursor
+        .rfind(|c: char| !(c.is_alphanumeric() || matches!(c, '@' | '-' | '_')))
+        .map_or(0, |i| i + 1);
+    let word = &before_cursor[word_start..];
+
+    Context {
+        section,
+        table,
+        cell: cell.to_string(),
+        cell_start: position(text, cursor_offset - cell.len()),
+        word: word.to_string(),
+        word_start: position(text, line_start + word_start),
+        cursor,
+    }
+}
+
+/// The trimmed cells of a markdown table row.
+fn cells(row: &str) -> Vec<String> {
+    let row = row.trim();
+    let row = row.strip_prefix('|').unwrap_or(row);
+    let row = row.strip_suffix('|').unwrap_or(row);
+    row.split('|').map(|c| c.trim().to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GOAL: &str = "\
+# Title
+
+| Metadata         |          |
+| :--              | :--      |
+| Point of contact | @ghost   |
+| Status           | Prop     |
+
+## Team asks
+
+| Team     | Support level | Notes |
+| -------- | ------------- | ----- |
+| [lang]   | Medium        | 🦀    |
+";
+
+    #[test]
+    fn offsets_and_positions_round_trip() {
+        let text = "ab\n🦀c\n";
+        // The crab is two UTF-16 code units but four bytes.
+        assert_eq!(offset(text, Position::new(1, 2)), 7);
+        assert_eq!(position(text, 7), Position::new(1, 2));
+        assert_eq!(offset(text, Position::new(1, 99)), 8);
+        assert_eq!(offset(text, Position::new(9, 0)), text.len());
+    }
+
+    #[test]
+    fn metadata_value_context() {
+        let context = context(GOAL, Position::new(5, 25));
+        assert_eq!(context.section, "Title");
+        assert_eq!(
+            context.table,
+            Some(TableContext {
+                header: vec!["Metadata".to_string(), "".to_string()],
+                column: 1,
+                row_name: "Status".to_string(),
+            })
+        );
+        assert_eq!(context.cell, "Prop");
+        assert_eq!(context.cell_start, Position::new(5, 21));
+    }
+
+    #[test]
+    fn username_context() {
+        let context = context(GOAL, Position::new(4, 25));
+        assert_eq!(context.word, "@gho");
+        assert_eq!(context.word_start, Position::new(4, 21));
+    }
+
+    #[test]
+    fn team_asks_context() {
+        let context = context(GOAL, Position::new(11, 6));
+        assert_eq!(context.section, "Team asks");
+        let table = context.table.unwrap();
+        assert_eq!(table.header[0], "Team");
+        assert_eq!(table.column, 0);
+        assert_eq!(context.cell, "[lan");
+    }
+}
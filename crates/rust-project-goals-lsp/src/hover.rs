@@ -0,0 +1,34 @@
+//! Hover docs for the team asks defined in `rust-project-goals.toml`.
+
+use lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind, Position, Range};
+use rust_project_goals::config::Configuration;
+
+use crate::document::Document;
+
+pub fn hover(document: &Document, position: Position) -> Option<Hover> {
+    let line = document.line(position.line)?;
+    let cursor = position.character as usize;
+
+    for (ask, details) in &Configuration::get().team_asks {
+        for (start, _) in line.match_indices(ask.as_str()) {
+            let start = line[..start].encode_utf16().count();
+            let end = start + ask.encode_utf16().count();
+            if !(start..=end).contains(&cursor) {
+                continue;
+            }
+
+            return Some(Hover {
+                contents: HoverContents::Markup(MarkupContent {
+                    kind: MarkupKind::Markdown,
+                    value: format!("**{}** ({}): {}", ask, details.short, details.about),
+                }),
+                range: Some(Range::new(
+                    Position::new(position.line, start as u32),
+                    Position::new(position.line, end as u32),
+                )),
+            });
+        }
+    }
+
+    None
+}
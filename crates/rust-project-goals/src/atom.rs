@@ -0,0 +1,67 @@
+//! A minimal writer for [Atom](https://www.rfc-editor.org/rfc/rfc4287) feeds.
+
+/// An Atom feed. Dates are RFC 3339 timestamps, e.g. `2026-03-01T12:00:00Z`.
+pub struct Feed {
+    /// Unique and permanent identifier of the feed, usually its URL.
+    pub id: String,
+    pub title: String,
+    /// Link to the page the feed is about.
+    pub link: String,
+    pub updated: String,
+    pub entries: Vec<Entry>,
+}
+
+pub struct Entry {
+    /// Unique and permanent identifier of the entry, usually its URL.
+    pub id: String,
+    pub title: String,
+    pub link: String,
+    pub updated: String,
+    pub author: Option<String>,
+    /// Entry content, as HTML.
+    pub content: String,
+}
+
+impl Feed {
+    /// Render the feed as XML.
+    pub fn render(&self) -> String {
+        let mut output = String::new();
+        output.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+        output.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+        output.push_str(&format!("  <id>{}</id>\n", escape(&self.id)));
+        output.push_str(&format!("  <title>{}</title>\n", escape(&self.title)));
+        output.push_str(&format!("  <link href=\"{}\"/>\n", escape(&self.link)));
+        output.push_str(&format!("  <updated>{}</updated>\n", escape(&self.updated)));
+        for entry in &self.entries {
+            output.push_str("  <entry>\n");
+            output.push_str(&format!("    <id>{}</id>\n", escape(&entry.id)));
+            output.push_str(&format!("    <title>{}</title>\n", escape(&entry.title)));
+            output.push_str(&format!("    <link href=\"{}\"/>\n", escape(&entry.link)));
+            output.push_str(&format!(
+                "    <updated>{}</updated>\n",
+                escape(&entry.updated)
+            ));
+            if let Some(author) = &entry.author {
+                output.push_str(&format!(
+                    "    <author><name>{}</name></author>\n",
+                    escape(author)
+                ));
+            }
+            output.push_str(&format!(
+                "    <content type=\"html\">{}</content>\n",
+                escape(&entry.content)
+            ));
+            output.push_str("  </entry>\n");
+        }
+        output.push_str("</feed>\n");
+        output
+    }
+}
+
+/// Escape text for use in XML (or HTML) content and attribute values.
+pub fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
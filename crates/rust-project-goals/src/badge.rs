@@ -0,0 +1,162 @@
+//! Static SVG badges and progress bars, so the book renders without an external badge
+//! service. They are embedded as `data:` URIs, which work in every renderer and offline.
+//!
+//! The badges and their style are configured in `rust-project-goals.toml`; see
+//! [`Configuration::badges`] and [`Configuration::badge_style`].
+
+use crate::config::{BadgeDetails, BadgeStyle, Configuration};
+
+/// A `data:` URI for the configured badge `name`, if there is one.
+pub fn named(name: &str) -> Option<String> {
+    let config = Configuration::get();
+    let details = config.badges.get(name)?;
+    Some(uri(name, details, &config.badge_style))
+}
+
+/// A `data:` URI for the badge `name`.
+pub fn uri(name: &str, details: &BadgeDetails, style: &BadgeStyle) -> String {
+    let text = details.text.as_deref().unwrap_or(name);
+    data_uri(&svg(text, &details.color, style))
+}
+
+/// The configured badge `name` as an HTML image (falling back to its name as text).
+pub fn html(name: &str) -> String {
+    match named(name) {
+        Some(uri) => format!(r#"<img src="{}" alt="{}">"#, uri, escape(name)),
+        None => escape(name),
+    }
+}
+
+/// A progress bar for `completed` out of `total` as an HTML image.
+pub fn progress_html(completed: u32, total: u32) -> String {
+    let svg = progress_svg(completed, total, &Configuration::get().badge_style);
+    format!(
+        r#"<img src="{}" alt="{}/{}">"#,
+        data_uri(&svg),
+        completed,
+        total
+    )
+}
+
+/// A flat badge showing `text` on a `color` background.
+pub fn svg(text: &str, color: &str, style: &BadgeStyle) -> String {
+    let width = text_width(text, style) + 2 * style.padding;
+    format!(
+        concat!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" role="img" aria-label="{text}">"#,
+            r#"<title>{text}</title>"#,
+            r#"<rect width="{width}" height="{height}" rx="{radius}" fill="{color}"/>"#,
+            r#"<text x="{x}" y="{y}" fill="{text_color}" font-family="{font_family}" font-size="{font_size}" text-anchor="middle">{text}</text>"#,
+            r#"</svg>"#,
+        ),
+        width = width,
+        height = style.height,
+        radius = style.radius,
+        color = escape(color),
+        x = width / 2,
+        y = text_baseline(style),
+        text_color = escape(&style.text_color),
+        font_family = escape(&style.font_family),
+        font_size = style.font_size,
+        text = escape(text),
+    )
+}
+
+/// A bar filled in proportion to `completed` out of `total`, labelled `completed/total`.
+pub fn progress_svg(completed: u32, total: u32, style: &BadgeStyle) -> String {
+    let width = style.progress_width;
+    let filled = (width * completed.min(total))
+        .checked_div(total)
+        .unwrap_or(0);
+    let text = format!("{completed}/{total}");
+    format!(
+        concat!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" role="img" aria-label="{text}">"#,
+            r#"<title>{text}</title>"#,
+            r#"<rect width="{width}" height="{height}" rx="{radius}" fill="{background}"/>"#,
+            r#"<rect width="{filled}" height="{height}" rx="{radius}" fill="{color}"/>"#,
+            r#"<text x="{x}" y="{y}" fill="{text_color}" font-family="{font_family}" font-size="{font_size}" text-anchor="middle">{text}</text>"#,
+            r#"</svg>"#,
+        ),
+        width = width,
+        height = style.height,
+        radius = style.radius,
+        background = escape(&style.progress_background),
+        filled = filled,
+        color = escape(&style.progress_color),
+        x = width / 2,
+        y = text_baseline(style),
+        text_color = escape(&style.progress_text_color),
+        font_family = escape(&style.font_family),
+        font_size = style.font_size,
+        text = text,
+    )
+}
+
+/// Embed `svg` in a `data:` URI, percent-encoding everything that could end a URL
+/// in markdown or HTML, or make it look like a link to another site.
+pub fn data_uri(svg: &str) -> String {
+    let mut uri = String::from("data:image/svg+xml,");
+    for byte in svg.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-_.~=;,'!*".contains(&byte) {
+            uri.push(byte as char);
+        } else {
+            uri.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    uri
+}
+
+/// Estimated width of `text`: there is no font to measure, so assume the average
+/// width of a Verdana character, which is a bit wider than most fonts.
+fn text_width(text: &str, style: &BadgeStyle) -> u32 {
+    (text.chars().count() as u32 * style.font_size * 62).div_ceil(100)
+}
+
+/// The y coordinate that vertically centers text in the badge.
+fn text_baseline(style: &BadgeStyle) -> u32 {
+    (style.height + style.font_size * 7 / 10) / 2
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn badges_grow_with_their_text() {
+        let style = BadgeStyle::default();
+        let short = svg("TBD", "#e05d44", &style);
+        let long = svg("Not yet funded", "#e05d44", &style);
+        assert!(short.contains(r#"width="31""#), "{short}");
+        assert!(long.contains(r#"width="106""#), "{long}");
+        assert!(long.contains(">Not yet funded</text>"));
+    }
+
+    #[test]
+    fn progress_bars_are_filled_in_proportion() {
+        let style = BadgeStyle::default();
+        let svg = progress_svg(1, 4, &style);
+        assert!(svg.contains(r#"<rect width="25" "#), "{svg}");
+        assert!(svg.contains(">1/4</text>"));
+        assert!(progress_svg(0, 0, &style).contains(r#"<rect width="0" "#));
+    }
+
+    #[test]
+    fn data_uris_have_no_markdown_or_html_delimiters() {
+        let uri = data_uri(&svg(
+            "Help <wanted> (now)",
+            "#dfb317",
+            &BadgeStyle::default(),
+        ));
+        assert!(uri.starts_with("data:image/svg+xml,%3Csvg%20"));
+        let (_, data) = uri.split_once(',').unwrap();
+        assert!(!data.contains([' ', '<', '>', '"', '(', ')', '#', '[', ']', '/', ':']));
+    }
+}
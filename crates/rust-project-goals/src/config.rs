@@ -1,20 +1,85 @@
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
 use anyhow::Context;
 use indexmap::IndexMap;
 use regex::Regex;
-use serde::Deserialize;
-use spanned::{Context as _, Result};
-
-#[derive(Deserialize)]
+use serde::{Deserialize, Serialize};
+use spanned::{Context as _, Error, Result};
+
+use crate::badge;
+
+/// Environment variable holding TOML that is layered over the configuration files,
+/// e.g. `RUST_PROJECT_GOALS_CONFIG='exchange_rates.EUR = 1.1'`.
+pub const CONFIG_ENV_VAR: &str = "RUST_PROJECT_GOALS_CONFIG";
+
+/// Keys of `[preprocessor.goals]` in `book.toml` that configure mdbook itself rather than us.
+const MDBOOK_PREPROCESSOR_KEYS: &[&str] = &["command", "renderers", "before", "after", "optional"];
+
+/// The configuration of the goals program. It is layered from, in order:
+///
+/// * `rust-project-goals.toml`;
+/// * the `[preprocessor.goals]` table of `book.toml`, which becomes [`Configuration::markdown`];
+/// * TOML in the [`CONFIG_ENV_VAR`] environment variable.
+///
+/// Tables are merged key by key, other values from later layers replace earlier ones.
+/// Unknown keys are errors.
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct Configuration {
     /// Defines the valid "asks" of teams. The key is the ask, the value is an extended description.
     /// IndexMap is used to preserve the ordering as defined in the TOML file.
     pub team_asks: IndexMap<String, TeamAskDetails>,
+
+    /// Exchange rates used to normalize funding costs to USD. The key is an ISO 4217
+    /// currency code (e.g., `EUR`), the value is the number of USD per unit of that currency.
+    #[serde(default)]
+    pub exchange_rates: IndexMap<String, f64>,
+
+    /// Registry of goal sponsors. The key is the canonical sponsor name.
+    /// IndexMap is used to preserve the ordering as defined in the TOML file.
+    #[serde(default)]
+    pub sponsors: IndexMap<String, SponsorDetails>,
+
+    /// Valid highlight themes for the `Highlight` metadata row. The key is the theme name.
+    /// IndexMap is used to preserve the display order as defined in the TOML file.
+    #[serde(default)]
+    pub highlights: Vocabulary,
+
+    /// Valid kinds of needs for the `Needs` metadata row. The key is the kind of need.
+    /// IndexMap is used to preserve the display order as defined in the TOML file.
+    #[serde(default)]
+    pub needs: Vocabulary,
+
+    /// Levels of support a goal can ask of a team, from least to most involvement.
+    /// The key is the name used in the `Support level` column of the team asks table.
+    /// IndexMap is used to preserve the ordering as defined in the TOML file, which is
+    /// also the order in which levels compare.
+    #[serde(default)]
+    pub support_levels: IndexMap<String, SupportLevelDetails>,
+
+    /// Badges that goal documents can show as images, like `![TBD][]`. The key is the
+    /// name used in the markdown.
+    #[serde(default)]
+    pub badges: IndexMap<String, BadgeDetails>,
+
+    /// How badges and progress bars are drawn.
+    #[serde(default)]
+    pub badge_style: BadgeStyle,
+
+    /// Email digests written by `cargo rpg updates --format email`.
+    #[serde(default)]
+    pub email: EmailConfig,
+
+    /// Settings for processing markdown (links, user names, ...), usually given as
+    /// `[preprocessor.goals]` in `book.toml`.
+    #[serde(default)]
+    pub markdown: MarkdownConfig,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct TeamAskDetails {
     /// A short descriptor of the team ask suitable for inclusion in a table
     pub short: String,
@@ -27,59 +92,408 @@ pub struct TeamAskDetails {
     pub elide: bool,
 }
 
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SponsorDetails {
+    /// Link to the sponsor's website
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// Other spellings of the sponsor's name that may appear in goal documents
+    #[serde(default)]
+    pub aliases: Vec<String>,
+
+    /// Path to the sponsor's logo, relative to the book's `src` directory
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo: Option<String>,
+}
+
+/// A set of valid names, each with a description, in display order.
+pub type Vocabulary = IndexMap<String, VocabularyDetails>;
+
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct VocabularyDetails {
+    /// What the theme or need means
+    pub description: String,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SupportLevelDetails {
+    /// What the team is asked to do at this level
+    pub description: String,
+
+    /// If true, asks at this level need a champion from the team
+    /// (a `[team] champion` row); otherwise someone on the team only has to "second" the goal.
+    #[serde(default)]
+    pub champion_required: bool,
+
+    /// If true, teams asked for this level are shown in bold in goal tables.
+    #[serde(default)]
+    pub bold: bool,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct BadgeDetails {
+    /// Text of the badge, if not its name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    /// Background color, as an SVG color like `#e05d44` or `red`
+    pub color: String,
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields, default)]
+pub struct BadgeStyle {
+    pub font_family: String,
+    pub font_size: u32,
+    pub text_color: String,
+
+    /// Height of badges and progress bars, in pixels
+    pub height: u32,
+
+    /// Radius of their rounded corners
+    pub radius: u32,
+
+    /// Space on either side of the text of a badge
+    pub padding: u32,
+
+    pub progress_width: u32,
+    pub progress_color: String,
+    pub progress_background: String,
+    pub progress_text_color: String,
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields, default)]
+pub struct EmailConfig {
+    /// Sender of the digests, like `Rust project goals team <goals-team@rust-lang.org>`.
+    /// Left for the mail client to fill in if not given.
+    pub from: Option<String>,
+
+    /// Recipient of the digest with the updates of all goals.
+    pub to: String,
+}
+
+impl Default for EmailConfig {
+    fn default() -> Self {
+        EmailConfig {
+            from: None,
+            to: "all@rust-lang.org".to_string(),
+        }
+    }
+}
+
+impl Default for BadgeStyle {
+    fn default() -> Self {
+        BadgeStyle {
+            font_family: "Verdana,Geneva,DejaVu Sans,sans-serif".to_string(),
+            font_size: 11,
+            text_color: "#fff".to_string(),
+            height: 20,
+            radius: 3,
+            padding: 5,
+            progress_width: 100,
+            progress_color: "#4c1".to_string(),
+            progress_background: "#ddd".to_string(),
+            progress_text_color: "#333".to_string(),
+        }
+    }
+}
+
+static CONFIG: OnceLock<Configuration> = OnceLock::new();
+
 impl Configuration {
+    /// The configuration given to [`Configuration::set`] or, if there was none,
+    /// the one [loaded](Configuration::load) on first use.
     pub fn get() -> &'static Configuration {
-        lazy_static::lazy_static! {
-            static ref CONFIG: Configuration = Configuration::load().unwrap();
-        }
-        &*CONFIG
+        CONFIG.get_or_init(|| Configuration::load().unwrap())
     }
 
-    fn load() -> Result<Configuration> {
-        let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-        let toml_file = manifest_dir.join("../../rust-project-goals.toml");
+    /// Use `config` from now on. Fails if the configuration is already in use.
+    pub fn set(config: Configuration) -> Result<()> {
+        CONFIG
+            .set(config)
+            .map_err(|_| Error::str("the configuration was already loaded"))
+    }
+
+    /// Load the configuration from the files in the closest of the current directory and
+    /// its ancestors that has a `rust-project-goals.toml`, and from the [`CONFIG_ENV_VAR`]
+    /// environment variable.
+    pub fn load() -> Result<Configuration> {
+        let current_dir = std::env::current_dir()?;
+        let Some(root) = current_dir
+            .ancestors()
+            .find(|dir| dir.join("rust-project-goals.toml").is_file())
+        else {
+            spanned::bail_here!(
+                "no `rust-project-goals.toml` in {} or its parent directories",
+                current_dir.display()
+            );
+        };
+        let book_goals = book_goals_table(&root.join("book.toml"))?;
+        Self::load_layers(
+            root,
+            book_goals,
+            std::env::var(CONFIG_ENV_VAR).ok().as_deref(),
+        )
+    }
+
+    /// Load the configuration of the book at `root`. `book_goals` is the `[preprocessor.goals]`
+    /// table of the book's configuration as mdbook sees it, which is used instead of the
+    /// one in `book.toml` (e.g., it includes mdbook's environment variable overrides).
+    pub fn load_for_book(root: &Path, book_goals: Option<toml::Table>) -> Result<Configuration> {
+        Self::load_layers(
+            root,
+            book_goals.map(without_mdbook_keys),
+            std::env::var(CONFIG_ENV_VAR).ok().as_deref(),
+        )
+    }
+
+    fn load_layers(
+        root: &Path,
+        book_goals: Option<toml::Table>,
+        env_override: Option<&str>,
+    ) -> Result<Configuration> {
+        // The configuration is checked after each layer, so that errors point at the
+        // layer that introduced them (and, thanks to the TOML parser, the right line).
+        let check = |table: &toml::Table, layer: &dyn std::fmt::Display| {
+            toml::Value::Table(table.clone())
+                .try_into::<Configuration>()
+                .map_err(|e| Error::str(format!("{layer}: {e}")))
+        };
+
+        let toml_file = root.join("rust-project-goals.toml");
         let toml_string = std::fs::read_to_string(&toml_file)
             .with_path_context(&toml_file, "loading configuration")?;
-        Ok(toml::from_str(&toml_string)?)
+        let mut table: toml::Table = toml::from_str(&toml_string)
+            .map_err(|e| Error::str(format!("{}: {e}", toml_file.display())))?;
+        resolve_template_paths(table.get_mut("markdown"), root);
+        let mut config = check(&table, &toml_file.display())?;
+
+        if let Some(goals) = book_goals {
+            let mut goals = toml::Value::Table(goals);
+            resolve_template_paths(Some(&mut goals), root);
+            merge(
+                &mut table,
+                toml::Table::from_iter([("markdown".to_string(), goals)]),
+            );
+            config = check(
+                &table,
+                &format!(
+                    "{}: in `[preprocessor.goals]`",
+                    root.join("book.toml").display()
+                ),
+            )?;
+        }
+
+        if let Some(env_override) = env_override {
+            let mut layer: toml::Table = toml::from_str(env_override)
+                .map_err(|e| Error::str(format!("`{CONFIG_ENV_VAR}`: {e}")))?;
+            resolve_template_paths(layer.get_mut("markdown"), &std::env::current_dir()?);
+            merge(&mut table, layer);
+            config = check(&table, &format!("`{CONFIG_ENV_VAR}`"))?;
+        }
+
+        Ok(config)
+    }
+
+    /// The configuration as TOML, as it could be written in `rust-project-goals.toml`.
+    pub fn to_toml(&self) -> Result<String> {
+        toml::to_string(self).map_err(|e| Error::str(e.to_string()))
+    }
+
+    /// Check the things the schema can't express, returning a description of each problem.
+    pub fn problems(&self) -> Vec<String> {
+        let mut problems = vec![];
+
+        for (currency, rate) in &self.exchange_rates {
+            if currency.len() != 3 || !currency.chars().all(|c| c.is_ascii_uppercase()) {
+                problems.push(format!(
+                    "exchange rate for `{currency}`: expected an ISO 4217 currency code like `EUR`"
+                ));
+            }
+            if !rate.is_finite() || *rate <= 0.0 {
+                problems.push(format!(
+                    "exchange rate for `{currency}`: expected a positive number, found {rate}"
+                ));
+            }
+        }
+
+        let mut sponsor_names: BTreeMap<String, &str> = BTreeMap::new();
+        for (name, details) in &self.sponsors {
+            for spelling in std::iter::once(name).chain(&details.aliases) {
+                let other = sponsor_names.insert(spelling.to_lowercase(), name);
+                if let Some(other) = other.filter(|other| *other != name) {
+                    problems.push(format!(
+                        "sponsor name or alias `{spelling}` is used by both `{other}` and `{name}`"
+                    ));
+                }
+            }
+        }
+
+        if self.support_levels.is_empty() {
+            problems.push("no support levels are defined in `[support_levels]`".to_string());
+        }
+        let mut level_names = BTreeMap::new();
+        for name in self.support_levels.keys() {
+            if name.trim().is_empty() || name.contains('|') {
+                problems.push(format!(
+                    "support level `{name}`: expected a name that fits in a table cell"
+                ));
+            }
+            if let Some(other) = level_names.insert(name.to_lowercase(), name) {
+                problems.push(format!(
+                    "support levels `{other}` and `{name}` differ only in case"
+                ));
+            }
+        }
+
+        for (pattern, _) in &self.markdown.linkifiers {
+            if let Err(e) = Regex::new(&format!(r"\[{}\]", pattern)) {
+                problems.push(format!("invalid linkifier pattern `{pattern}`: {e}"));
+            }
+        }
+
+        for username in self
+            .markdown
+            .users
+            .keys()
+            .chain(&self.markdown.ignore_users)
+        {
+            if !username.starts_with('@') {
+                problems.push(format!(
+                    "`{username}` in the markdown settings: expected a username like `@ghost`"
+                ));
+            }
+        }
+
+        problems
+    }
+
+    /// The number of USD per unit of `currency`, if known.
+    pub fn exchange_rate(&self, currency: &str) -> Option<f64> {
+        if currency == "USD" {
+            return Some(1.0);
+        }
+        self.exchange_rates.get(currency).copied()
+    }
+
+    /// Look up a sponsor by its canonical name or one of its aliases (case-insensitive),
+    /// returning the canonical name and details.
+    pub fn sponsor(&self, name: &str) -> Option<(&str, &SponsorDetails)> {
+        let name = name.trim();
+        self.sponsors
+            .iter()
+            .find(|(canonical, details)| {
+                canonical.eq_ignore_ascii_case(name)
+                    || details.aliases.iter().any(|a| a.eq_ignore_ascii_case(name))
+            })
+            .map(|(canonical, details)| (canonical.as_str(), details))
     }
 }
 
-// Goals-specific configuration for markdown processing
-// This is separate from the main Configuration above
+/// Describes the entries of a vocabulary (e.g., [`Configuration::highlights`]) in display
+/// order, one per line, for use in error messages.
+pub fn describe_vocabulary(vocabulary: &Vocabulary) -> String {
+    vocabulary
+        .iter()
+        .map(|(name, details)| format!("* `{}`: {}", name, details.description))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
 
-#[derive(Deserialize, Debug, Default)]
-struct TomlBookConfig {
-    #[serde(default)]
-    preprocessor: TomlPreprocessorConfig,
+/// Merge `layer` into `base`: tables are merged key by key, other values are replaced.
+fn merge(base: &mut toml::Table, layer: toml::Table) {
+    for (key, value) in layer {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(layer)) => merge(base, layer),
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
 }
 
-#[derive(Deserialize, Debug, Default)]
-struct TomlPreprocessorConfig {
-    #[serde(default)]
-    goals: TomlGoalsConfig,
+/// Make the paths of the `templates` table in `markdown` relative to `dir`, the directory of
+/// the file that declares them (the current directory for the environment variable), so
+/// that they don't depend on where the program runs from.
+fn resolve_template_paths(markdown: Option<&mut toml::Value>, dir: &Path) {
+    let Some(toml::Value::Table(templates)) = markdown.and_then(|m| m.get_mut("templates")) else {
+        return;
+    };
+    for (_, path) in templates.iter_mut() {
+        if let toml::Value::String(path) = path {
+            *path = dir.join(path.as_str()).to_string_lossy().into_owned();
+        }
+    }
+}
+
+/// The `[preprocessor.goals]` table of the `book.toml` at `path`, if the file exists,
+/// without the keys meant for mdbook.
+fn book_goals_table(path: &Path) -> Result<Option<toml::Table>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = std::fs::read_to_string(path).with_path_context(path, "loading book.toml")?;
+    let book: toml::Table =
+        toml::from_str(&content).map_err(|e| Error::str(format!("{}: {e}", path.display())))?;
+    Ok(preprocessor_goals_table(book))
+}
+
+fn preprocessor_goals_table(mut book: toml::Table) -> Option<toml::Table> {
+    let Some(toml::Value::Table(mut preprocessor)) = book.remove("preprocessor") else {
+        return None;
+    };
+    let Some(toml::Value::Table(goals)) = preprocessor.remove("goals") else {
+        return None;
+    };
+    Some(without_mdbook_keys(goals))
 }
 
-#[derive(Deserialize, Debug, Default, Clone)]
-struct TomlGoalsConfig {
+/// A `[preprocessor.goals]` table without the keys meant for mdbook.
+fn without_mdbook_keys(mut goals: toml::Table) -> toml::Table {
+    for key in MDBOOK_PREPROCESSOR_KEYS {
+        goals.remove(*key);
+    }
+    goals
+}
+
+// Goals-specific configuration for markdown processing
+
+/// Markdown settings as written in the configuration ([`Configuration::markdown`]).
+/// IndexMap is used to preserve the ordering as defined in the TOML files.
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct MarkdownConfig {
     /// Static link definitions (name -> URL)
     /// Maps from link names like "Help wanted" to URLs like "https://img.shields.io/badge/Help%20wanted-yellow"
     #[serde(default)]
-    pub links: HashMap<String, String>,
+    pub links: IndexMap<String, String>,
 
     /// Linkifier patterns (regex pattern -> URL template)
     /// Maps from patterns like "RFC #([0-9]+)" to URL templates like "https://github.com/rust-lang/rfcs/pull/$1"
     #[serde(default)]
-    pub linkifiers: HashMap<String, String>,
+    pub linkifiers: IndexMap<String, String>,
 
     /// User display name overrides (username -> display name)
     /// Maps from usernames like "@nikomatsakis" to display names like "Niko Matsakis"
     #[serde(default)]
-    pub users: HashMap<String, String>,
+    pub users: IndexMap<String, String>,
 
     /// Usernames to ignore during auto-linking
     /// List of usernames like ["@bot", "@automated"] that should not be auto-linked
     #[serde(default)]
     pub ignore_users: Vec<String>,
+
+    /// Handlebars templates of `cargo rpg updates` replacing the built-in ones in `templates/`
+    /// Maps from template names like "goal_card" to paths like "my-templates/goal_card.hbs",
+    /// which are made absolute from the directory of the file declaring them when loading
+    #[serde(default)]
+    pub templates: IndexMap<String, PathBuf>,
 }
 
 /// Parsed and processed goals configuration ready for use
@@ -94,6 +508,8 @@ pub struct GoalsConfig {
     pub users: HashMap<String, String>,
     /// Usernames to ignore during auto-linking
     pub ignore_users: Vec<String>,
+    /// Badges (name -> `data:` URI of the image), defined like links
+    pub badges: Vec<(String, String)>,
 }
 
 impl GoalsConfig {
@@ -103,15 +519,38 @@ impl GoalsConfig {
             "Failed to read book.toml at {}",
             path.as_ref().display()
         ))?;
-        let book_config: TomlBookConfig = toml::from_str(&content).context(format!(
+        let book: toml::Table = toml::from_str(&content).context(format!(
             "Failed to parse book.toml at {}",
             path.as_ref().display()
         ))?;
-        Self::from_toml_goals_config(book_config.preprocessor.goals)
+        let Some(goals) = preprocessor_goals_table(book) else {
+            return Ok(Self::default());
+        };
+        let config: MarkdownConfig = toml::Value::Table(goals).try_into().context(format!(
+            "Failed to parse `[preprocessor.goals]` in book.toml at {}",
+            path.as_ref().display()
+        ))?;
+        Self::from_markdown_config(config)
+    }
+
+    /// Goals configuration from the [layered configuration](Configuration).
+    pub fn from_configuration(configuration: &Configuration) -> anyhow::Result<Self> {
+        let mut config = Self::from_markdown_config(configuration.markdown.clone())?;
+        config.badges = configuration
+            .badges
+            .iter()
+            .map(|(name, details)| {
+                (
+                    name.clone(),
+                    badge::uri(name, details, &configuration.badge_style),
+                )
+            })
+            .collect();
+        Ok(config)
     }
 
-    /// Convert from raw TomlGoalsConfig to processed GoalsConfig
-    fn from_toml_goals_config(config: TomlGoalsConfig) -> anyhow::Result<Self> {
+    /// Convert from raw MarkdownConfig to processed GoalsConfig
+    fn from_markdown_config(config: MarkdownConfig) -> anyhow::Result<Self> {
         // Compile linkifier regex patterns
         let linkifiers: Vec<(Regex, String)> = config
             .linkifiers
@@ -124,10 +563,11 @@ impl GoalsConfig {
             .collect::<anyhow::Result<Vec<_>>>()?;
 
         Ok(GoalsConfig {
-            links: config.links,
+            links: config.links.into_iter().collect(),
             linkifiers,
-            users: config.users,
+            users: config.users.into_iter().collect(),
             ignore_users: config.ignore_users,
+            badges: Vec::new(),
         })
     }
 
@@ -138,6 +578,7 @@ impl GoalsConfig {
             linkifiers: Vec::new(),
             users: HashMap::new(),
             ignore_users: Vec::new(),
+            badges: Vec::new(),
         }
     }
 }
@@ -148,6 +589,138 @@ mod tests {
     use std::io::Write;
     use tempfile::NamedTempFile;
 
+    const PROJECT_GOALS_TOML: &str = r#"
+[team_asks]
+"RFC decision" = { short="RFC", about="review an RFC and deciding whether to accept" }
+
+[exchange_rates]
+EUR = 1.08
+
+[support_levels]
+Small = { description="routine activities" }
+Large = { description="deeper review from the entire team", champion_required=true, bold=true }
+"#;
+
+    const BOOK_TOML: &str = r#"
+[preprocessor.goals]
+command = "cargo run -p mdbook-goals --"
+ignore_users = ["@triagebot"]
+
+[preprocessor.goals.users]
+"@alice" = "Alice"
+"#;
+
+    fn load_layers(
+        project_goals_toml: &str,
+        book_toml: &str,
+        env_override: Option<&str>,
+    ) -> std::result::Result<Configuration, String> {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(
+            root.path().join("rust-project-goals.toml"),
+            project_goals_toml,
+        )
+        .unwrap();
+        std::fs::write(root.path().join("book.toml"), book_toml).unwrap();
+        let book_goals = book_goals_table(&root.path().join("book.toml")).unwrap();
+        Configuration::load_layers(root.path(), book_goals, env_override).map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn test_configuration_layers() {
+        let Ok(config) = load_layers(
+            PROJECT_GOALS_TOML,
+            BOOK_TOML,
+            Some("exchange_rates.GBP = 1.27\nmarkdown.users.\"@bob\" = \"Bob\""),
+        ) else {
+            panic!("failed to load configuration");
+        };
+
+        assert!(config.team_asks.contains_key("RFC decision"));
+        assert!(config.support_levels["Large"].champion_required);
+        assert_eq!(config.exchange_rate("EUR"), Some(1.08));
+        assert_eq!(config.exchange_rate("GBP"), Some(1.27));
+        assert_eq!(config.markdown.ignore_users, vec!["@triagebot".to_string()]);
+        assert_eq!(config.markdown.users.len(), 2);
+        assert!(config.problems().is_empty());
+
+        let toml = config.to_toml().unwrap();
+        assert!(toml.contains("[markdown.users]"));
+    }
+
+    #[test]
+    fn test_template_paths() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(
+            root.path().join("rust-project-goals.toml"),
+            format!("{PROJECT_GOALS_TOML}\n[markdown.templates]\nprogress = \"a/progress.hbs\""),
+        )
+        .unwrap();
+        std::fs::write(
+            root.path().join("book.toml"),
+            format!("{BOOK_TOML}\n[preprocessor.goals.templates]\ngoal_card = \"b/goal_card.hbs\""),
+        )
+        .unwrap();
+        let book_goals = book_goals_table(&root.path().join("book.toml")).unwrap();
+        let config = Configuration::load_layers(
+            root.path(),
+            book_goals,
+            Some("markdown.templates.goal_text = \"c/goal_text.hbs\""),
+        )
+        .unwrap();
+
+        let templates = &config.markdown.templates;
+        assert_eq!(templates["progress"], root.path().join("a/progress.hbs"));
+        assert_eq!(templates["goal_card"], root.path().join("b/goal_card.hbs"));
+        assert_eq!(
+            templates["goal_text"],
+            std::env::current_dir().unwrap().join("c/goal_text.hbs")
+        );
+    }
+
+    #[test]
+    fn test_configuration_unknown_keys() {
+        let error = load_layers(
+            &PROJECT_GOALS_TOML.replace("[exchange_rates]", "[exchange_rate]"),
+            BOOK_TOML,
+            None,
+        )
+        .err()
+        .unwrap();
+        assert!(error.contains("rust-project-goals.toml"), "{error}");
+        assert!(error.contains("exchange_rate"), "{error}");
+
+        let error = load_layers(
+            PROJECT_GOALS_TOML,
+            &BOOK_TOML.replace("ignore_users", "ignored_users"),
+            None,
+        )
+        .err()
+        .unwrap();
+        assert!(error.contains("book.toml"), "{error}");
+        assert!(error.contains("ignored_users"), "{error}");
+        assert!(!error.contains(CONFIG_ENV_VAR), "{error}");
+
+        let error = load_layers(PROJECT_GOALS_TOML, BOOK_TOML, Some("colour = true"))
+            .err()
+            .unwrap();
+        assert!(error.contains(CONFIG_ENV_VAR), "{error}");
+    }
+
+    #[test]
+    fn test_configuration_problems() {
+        let Ok(config) = load_layers(
+            PROJECT_GOALS_TOML,
+            BOOK_TOML,
+            Some("exchange_rates.euro = 0.0\nmarkdown.ignore_users = [\"triagebot\"]\nsupport_levels.small = { description=\"\" }"),
+        ) else {
+            panic!("failed to load configuration");
+        };
+
+        let problems = config.problems();
+        assert_eq!(problems.len(), 4, "{problems:?}");
+    }
+
     #[test]
     fn test_goals_config_empty_toml() {
         let mut file = NamedTempFile::new().unwrap();
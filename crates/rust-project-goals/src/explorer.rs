@@ -0,0 +1,219 @@
+//! The goal explorer: an index of a milestone's goals, embedded as JSON in a page where
+//! the bundled `goal-explorer.js` script lets readers filter, sort, and search them.
+
+use rust_project_goals_json::{GoalIndex, GoalIndexEntry, GoalIndexTeamAsk};
+
+use crate::gh::issues::{checkboxes, ExistingGithubIssue};
+use crate::goal::{GoalDocument, SupportLevel, TeamInvolvement};
+use crate::team::TeamName;
+
+/// The script that renders the explorer, installed next to `book.toml` by `mdbook-goals install`.
+pub const SCRIPT_NAME: &str = "goal-explorer.js";
+
+/// Index the `goals` of `milestone`. The progress of each goal is taken from its tracking
+/// issue in `issues`; pass an empty slice to leave it out.
+pub fn goal_index(
+    milestone: &str,
+    goals: &[&GoalDocument],
+    issues: &[ExistingGithubIssue],
+) -> GoalIndex {
+    GoalIndex {
+        milestone: milestone.to_string(),
+        support_levels: SupportLevel::all()
+            .map(|level| level.as_str().to_string())
+            .collect(),
+        goals: goals
+            .iter()
+            .map(|goal| goal_index_entry(goal, issues))
+            .collect(),
+    }
+}
+
+fn goal_index_entry(goal: &GoalDocument, issues: &[ExistingGithubIssue]) -> GoalIndexEntry {
+    let champion = |team: &'static TeamName| {
+        goal.metadata
+            .champions
+            .get(&team)
+            .map(|c| c.content.trim().to_string())
+    };
+
+    let team_asks = match &goal.team_involvement {
+        TeamInvolvement::Asks(asks) => asks
+            .iter()
+            .flat_map(|ask| {
+                ask.teams.iter().map(move |&team| GoalIndexTeamAsk {
+                    team: team.as_str().to_string(),
+                    level: None,
+                    ask: Some(ask.ask_description.clone()),
+                    subgoal: ask.goal_titles.get(1).map(|t| t.content.clone()),
+                    notes: ask.notes.clone(),
+                    champion: champion(team),
+                })
+            })
+            .collect(),
+        TeamInvolvement::Support(supports) => supports
+            .iter()
+            .map(|support| GoalIndexTeamAsk {
+                team: support.team.as_str().to_string(),
+                level: Some(support.support_level.as_str().to_string()),
+                ask: None,
+                subgoal: support.subgoal.as_ref().map(|s| s.content.clone()),
+                notes: support.notes.clone(),
+                champion: champion(support.team),
+            })
+            .collect(),
+    };
+
+    let progress = goal.metadata.tracking_issue.as_ref().and_then(|issue_id| {
+        let issue = issues
+            .iter()
+            .find(|issue| issue.number == issue_id.number)?;
+        Some(checkboxes(issue, &goal.metadata.other_tracking_issues))
+    });
+
+    GoalIndexEntry {
+        title: goal.metadata.title.content.clone(),
+        url: goal.link_path.with_extension("html").display().to_string(),
+        status: goal.metadata.status.as_str().to_string(),
+        point_of_contact: goal
+            .metadata
+            .owner_usernames()
+            .into_iter()
+            .map(|u| u.to_string())
+            .collect(),
+        champions: goal
+            .metadata
+            .champions
+            .iter()
+            .map(|(team, champion)| {
+                (
+                    team.as_str().to_string(),
+                    champion.content.trim().to_string(),
+                )
+            })
+            .collect(),
+        team_asks,
+        support_level: goal.max_support_level().map(|l| l.as_str().to_string()),
+        roadmaps: goal.all_roadmaps().iter().map(|t| t.to_string()).collect(),
+        highlights: goal
+            .metadata
+            .highlight
+            .iter()
+            .map(|t| t.to_string())
+            .collect(),
+        needs: goal.metadata.needs.iter().map(|t| t.to_string()).collect(),
+        funding: (!goal.funding.is_empty()).then(|| goal.funding_status().label().to_string()),
+        tracking_issue: goal.metadata.tracking_issue.as_ref().map(|i| i.url()),
+        progress,
+    }
+}
+
+/// The HTML that the explorer script replaces with the explorer. The index is embedded as
+/// JSON, with `<`, `>`, and `&` escaped so that it cannot end the script element.
+pub fn format_goal_explorer(index: &GoalIndex) -> String {
+    let json = serde_json::to_string(index)
+        .unwrap()
+        .replace('<', "\\u003c")
+        .replace('>', "\\u003e")
+        .replace('&', "\\u0026");
+    format!(
+        concat!(
+            "<div class=\"goal-explorer\">\n",
+            "<script type=\"application/json\" class=\"goal-explorer-index\">{json}</script>\n",
+            "<noscript>The goal explorer needs JavaScript.</noscript>\n",
+            "</div>\n",
+        ),
+        json = json
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use spanned::Spanned;
+
+    use super::*;
+    use crate::goal::{FundingCost, FundingItem, FundingStatus, TeamSupport};
+
+    /// A goal asking `lang` for the largest support level (with a champion) and `compiler`
+    /// for the smallest one, looking for funding.
+    fn goal() -> GoalDocument {
+        let mut goal = GoalDocument::for_test("2026/frobnicate.md", "Frobnicate </script>");
+        let (lang, compiler) = (TeamName::for_test("lang"), TeamName::for_test("compiler"));
+        let levels: Vec<SupportLevel> = SupportLevel::all().collect();
+        let support = |team, support_level, notes: &str| TeamSupport {
+            link_path: goal.link_path.clone(),
+            team,
+            support_level,
+            notes: notes.to_string(),
+            subgoal: None,
+        };
+        goal.team_involvement = TeamInvolvement::Support(vec![
+            support(lang, levels[levels.len() - 1], "Design meetings"),
+            support(compiler, levels[0], "Reviews"),
+        ]);
+        goal.metadata.champions = BTreeMap::from([(lang, Spanned::here(" @bob ".to_string()))]);
+        goal.funding = vec![FundingItem {
+            purpose: "Maintainer time".to_string(),
+            cost: FundingCost::Tbd,
+            status: FundingStatus::Partial(None),
+            sponsors: None,
+        }];
+        goal
+    }
+
+    #[test]
+    fn explorer_index() {
+        let goal = goal();
+        let html = format_goal_explorer(&goal_index("2026", &[&goal], &[]));
+
+        // The index cannot end its script element early
+        assert_eq!(html.matches("</script>").count(), 1, "{html}");
+        assert!(html.contains("Frobnicate \\u003c/script\\u003e"), "{html}");
+
+        let json = html
+            .split_once("class=\"goal-explorer-index\">")
+            .and_then(|(_, rest)| rest.split_once("</script>"))
+            .map(|(json, _)| json)
+            .unwrap();
+        let index: serde_json::Value = serde_json::from_str(json).unwrap();
+        let levels: Vec<String> = SupportLevel::all()
+            .map(|l| l.as_str().to_string())
+            .collect();
+        assert_eq!(index["milestone"], "2026");
+        assert_eq!(index["support_levels"], serde_json::json!(levels));
+
+        let entry = &index["goals"][0];
+        assert_eq!(entry["title"], "Frobnicate </script>");
+        assert_eq!(entry["url"], "frobnicate.html");
+        assert_eq!(entry["status"], "Accepted");
+        assert_eq!(entry["point_of_contact"], serde_json::json!(["@alice"]));
+        assert_eq!(entry["champions"], serde_json::json!({"lang": "@bob"}));
+        assert_eq!(
+            entry["team_asks"],
+            serde_json::json!([
+                {
+                    "team": "lang",
+                    "level": levels[levels.len() - 1],
+                    "ask": null,
+                    "subgoal": null,
+                    "notes": "Design meetings",
+                    "champion": "@bob",
+                },
+                {
+                    "team": "compiler",
+                    "level": levels[0],
+                    "ask": null,
+                    "subgoal": null,
+                    "notes": "Reviews",
+                    "champion": null,
+                },
+            ])
+        );
+        assert_eq!(entry["support_level"], levels[levels.len() - 1]);
+        assert_eq!(entry["funding"], "Partial");
+        assert_eq!(entry["tracking_issue"], serde_json::Value::Null);
+        assert_eq!(entry["progress"], serde_json::Value::Null);
+    }
+}
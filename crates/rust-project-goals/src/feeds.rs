@@ -0,0 +1,318 @@
+//! Atom feeds of the status updates posted on the tracking issues of a milestone's goals:
+//! one feed per goal, per team (the goals it champions), per roadmap theme, and one for
+//! all goals.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+
+use pulldown_cmark::{Options, Parser};
+
+use crate::atom;
+use crate::gh::issues::{ExistingGithubComment, ExistingGithubIssue};
+use crate::goal::GoalDocument;
+use crate::team::TeamName;
+use crate::util::{slug, BOOK_URL};
+
+/// A feed, along with where it goes in the book, relative to the milestone directory
+/// (e.g., `feeds/goals/cargo-script.xml`).
+pub struct UpdateFeed {
+    pub path: PathBuf,
+    pub feed: atom::Feed,
+}
+
+/// A status update, i.e. a comment on a goal's tracking issue that is not hidden from reports.
+struct Update<'a> {
+    goal: &'a GoalDocument,
+    comment: &'a ExistingGithubComment,
+}
+
+/// Build the update feeds of `milestone` from the comments on the tracking issues of
+/// `goals`. `updated` (an RFC 3339 timestamp) is the date of feeds without any entries.
+pub fn update_feeds(
+    milestone: &str,
+    goals: &[&GoalDocument],
+    issues: &[ExistingGithubIssue],
+    updated: &str,
+) -> Vec<UpdateFeed> {
+    let mut updates: Vec<Update<'_>> = goals
+        .iter()
+        .filter_map(|&goal| {
+            let number = goal.metadata.tracking_issue.as_ref()?.number;
+            let issue = issues.iter().find(|issue| issue.number == number)?;
+            Some(
+                issue
+                    .comments
+                    .iter()
+                    .map(move |comment| Update { goal, comment }),
+            )
+        })
+        .flatten()
+        .filter(|update| !update.comment.should_hide_from_reports())
+        .collect();
+    updates.sort_by(|a, b| b.comment.created_at.cmp(&a.comment.created_at));
+
+    let goals_url = format!("{BOOK_URL}/{milestone}/goals.html");
+    let feed = |path: PathBuf, title: String, link: String, matches: &dyn Fn(&Update) -> bool| {
+        let entries: Vec<&Update> = updates.iter().filter(|u| matches(u)).collect();
+        let feed = atom::Feed {
+            id: format!("{BOOK_URL}/{milestone}/{}", path.display()),
+            title,
+            link,
+            updated: entries
+                .first()
+                .map(|u| u.comment.created_at.clone())
+                .unwrap_or_else(|| updated.to_string()),
+            entries: entries.into_iter().map(entry).collect(),
+        };
+        UpdateFeed { path, feed }
+    };
+
+    let mut feeds = vec![feed(
+        PathBuf::from("feeds/all.xml"),
+        format!("Rust project goals {milestone}: updates"),
+        goals_url.clone(),
+        &|_| true,
+    )];
+
+    for goal in goals {
+        let stem = goal.link_path.file_stem().unwrap().to_string_lossy();
+        feeds.push(feed(
+            PathBuf::from(format!("feeds/goals/{stem}.xml")),
+            format!(
+                "Rust project goals {milestone}: {}",
+                goal.metadata.title.content
+            ),
+            format!(
+                "{BOOK_URL}/{milestone}/{}",
+                goal.link_path.with_extension("html").display()
+            ),
+            &|u| std::ptr::eq(u.goal, *goal),
+        ));
+    }
+
+    let teams: BTreeSet<&'static TeamName> = goals
+        .iter()
+        .flat_map(|goal| goal.metadata.champions.keys().copied())
+        .collect();
+    for team in teams {
+        let name = team.as_str();
+        feeds.push(feed(
+            PathBuf::from(format!("feeds/teams/{}.xml", slug(name))),
+            format!("Rust project goals {milestone}: goals championed by the {name} team"),
+            goals_url.clone(),
+            &|u| u.goal.metadata.champions.contains_key(team),
+        ));
+    }
+
+    let themes: BTreeMap<String, String> = goals
+        .iter()
+        .flat_map(|goal| {
+            goal.all_roadmaps()
+                .iter()
+                .map(|t| t.trim().to_string())
+                .collect::<Vec<_>>()
+        })
+        .map(|theme| (slug(&theme), theme))
+        .collect();
+    for (theme_slug, theme) in themes {
+        feeds.push(feed(
+            PathBuf::from(format!("feeds/roadmaps/{theme_slug}.xml")),
+            format!("Rust project goals {milestone}: {theme}"),
+            goals_url.clone(),
+            &|u| u.goal.matches_roadmap_theme(&theme),
+        ));
+    }
+
+    feeds
+}
+
+fn entry(update: &Update<'_>) -> atom::Entry {
+    let comment = update.comment;
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let mut content = String::new();
+    pulldown_cmark::html::push_html(&mut content, Parser::new_ext(&comment.body, options));
+    atom::Entry {
+        id: comment.url.clone(),
+        title: format!(
+            "{}: update from @{} on {}",
+            update.goal.metadata.title.content,
+            comment.author,
+            comment.created_at_date()
+        ),
+        link: comment.url.clone(),
+        updated: comment.created_at.clone(),
+        author: Some(comment.author.clone()),
+        content,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use rust_project_goals_json::GithubIssueState;
+    use spanned::Spanned;
+
+    use super::*;
+    use crate::gh::issue_id::{IssueId, Repository};
+    use crate::gh::issues::LOCK_TEXT;
+
+    fn goal(path: &str, title: &str, issue: u64, champion: Option<&str>) -> GoalDocument {
+        let mut goal = GoalDocument::for_test(path, title);
+        goal.metadata.tracking_issue = Some(IssueId::new(
+            Repository::new("rust-lang", "rust-project-goals"),
+            issue,
+        ));
+        if let Some(team) = champion {
+            goal.metadata.champions =
+                BTreeMap::from([(TeamName::for_test(team), Spanned::here("@bob".to_string()))]);
+        }
+        goal
+    }
+
+    fn comment(
+        issue: u64,
+        id: u64,
+        created_at: &str,
+        body: &str,
+        hidden: bool,
+    ) -> ExistingGithubComment {
+        serde_json::from_value(serde_json::json!({
+            "author": "alice",
+            "body": body,
+            "created_at": created_at,
+            "url": format!("https://github.com/rust-lang/rust-project-goals/issues/{issue}#issuecomment-{id}"),
+            "hidden": hidden,
+        }))
+        .unwrap()
+    }
+
+    fn issue(number: u64, comments: Vec<ExistingGithubComment>) -> ExistingGithubIssue {
+        ExistingGithubIssue {
+            number,
+            title: String::new(),
+            assignees: Default::default(),
+            comments,
+            body: String::new(),
+            state: GithubIssueState::Open,
+            labels: vec![],
+            milestone: None,
+        }
+    }
+
+    fn feeds() -> Vec<UpdateFeed> {
+        let frob = goal("2026/frobnicate.md", "Frobnicate <things>", 1, Some("lang"));
+        let quux = goal("2026/quux.md", "Quux", 2, None);
+        let issues = [
+            issue(
+                1,
+                vec![
+                    comment(1, 10, "2026-02-01T12:00:00Z", LOCK_TEXT, false),
+                    comment(
+                        1,
+                        11,
+                        "2026-02-02T12:00:00Z",
+                        "Done: `a < b` & *more*",
+                        false,
+                    ),
+                    comment(1, 12, "2026-02-03T12:00:00Z", "Off topic", true),
+                    comment(1, 13, "2026-03-01T12:00:00Z", "Later", false),
+                ],
+            ),
+            issue(
+                2,
+                vec![comment(2, 20, "2026-02-15T12:00:00Z", "Quux news", false)],
+            ),
+        ];
+        update_feeds("2026", &[&frob, &quux], &issues, "2026-01-01T00:00:00Z")
+    }
+
+    fn entry_ids(feed: &UpdateFeed) -> Vec<&str> {
+        feed.feed
+            .entries
+            .iter()
+            .map(|entry| entry.id.rsplit('-').next().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn feed_entries() {
+        let feeds = feeds();
+        let paths: Vec<_> = feeds.iter().map(|f| f.path.display().to_string()).collect();
+        assert_eq!(
+            paths,
+            [
+                "feeds/all.xml",
+                "feeds/goals/frobnicate.xml",
+                "feeds/goals/quux.xml",
+                "feeds/teams/lang.xml",
+            ]
+        );
+
+        // Newest first, without automated or hidden comments
+        assert_eq!(entry_ids(&feeds[0]), ["13", "20", "11"]);
+        assert_eq!(feeds[0].feed.updated, "2026-03-01T12:00:00Z");
+        assert_eq!(entry_ids(&feeds[1]), ["13", "11"]);
+        assert_eq!(entry_ids(&feeds[2]), ["20"]);
+        assert_eq!(entry_ids(&feeds[3]), ["13", "11"]);
+        assert_eq!(
+            feeds[3].feed.title,
+            "Rust project goals 2026: goals championed by the lang team"
+        );
+
+        let entry = &feeds[1].feed.entries[1];
+        assert_eq!(
+            entry.title,
+            "Frobnicate <things>: update from @alice on 2026-02-02"
+        );
+        assert_eq!(entry.author.as_deref(), Some("alice"));
+        assert_eq!(
+            entry.content,
+            "<p>Done: <code>a &lt; b</code> &amp; <em>more</em></p>\n"
+        );
+        assert_eq!(
+            feeds[1].feed.link,
+            "https://rust-lang.github.io/goals/2026/frobnicate.html"
+        );
+    }
+
+    #[test]
+    fn empty_feed() {
+        let goal = goal("2026/frobnicate.md", "Frobnicate", 1, None);
+        let feeds = update_feeds(
+            "2026",
+            &[&goal],
+            &[issue(1, vec![])],
+            "2026-01-01T00:00:00Z",
+        );
+        assert_eq!(feeds.len(), 2);
+        assert!(feeds[1].feed.entries.is_empty());
+        assert_eq!(feeds[1].feed.updated, "2026-01-01T00:00:00Z");
+    }
+
+    #[test]
+    fn render_escapes() {
+        let feeds = feeds();
+        let xml = feeds[1].feed.render();
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<feed xmlns=\"http://www.w3.org/2005/Atom\">\n"), "{xml}");
+        assert!(
+            xml.contains("  <title>Rust project goals 2026: Frobnicate &lt;things&gt;</title>\n"),
+            "{xml}"
+        );
+        assert!(
+            xml.contains("  <updated>2026-03-01T12:00:00Z</updated>\n"),
+            "{xml}"
+        );
+        assert!(
+            xml.contains("    <author><name>alice</name></author>\n"),
+            "{xml}"
+        );
+        assert!(
+            xml.contains("    <content type=\"html\">&lt;p&gt;Done: &lt;code&gt;a &amp;lt; b&lt;/code&gt; &amp;amp; &lt;em&gt;more&lt;/em&gt;&lt;/p&gt;\n</content>\n"),
+            "{xml}"
+        );
+        assert_eq!(xml.matches("<entry>").count(), 2);
+        assert!(xml.ends_with("</feed>\n"));
+    }
+}
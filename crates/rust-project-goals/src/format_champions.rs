@@ -1,74 +1,52 @@
 use std::collections::{BTreeMap, BTreeSet};
 
-use spanned::{Result, Spanned};
+use spanned::Result;
 
-use crate::{goal::GoalDocument, util};
+use crate::{
+    goal::GoalDocument,
+    table::{Cell, Table},
+};
 
 /// Format a champions table showing each champion and their goals.
 pub fn format_champions(goals: &[&GoalDocument]) -> Result<String> {
-    use std::fmt::Write;
-
-    let mut output = String::new();
+    let table = champions_table(goals);
+    if table.is_empty() {
+        return Ok("No champions found.".to_string());
+    }
+    Ok(table.to_markdown())
+}
 
+/// A table with one row per goal of each champion,
+/// showing champion name and count only on the first row.
+pub fn champions_table(goals: &[&GoalDocument]) -> Table {
     // Collect champions and their goals
-    let mut champion_goals: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    let mut champion_goals: BTreeMap<&str, BTreeSet<(&str, String)>> = BTreeMap::new();
 
     for goal in goals {
         for champion in goal.metadata.champions.values() {
-            let champion_name = champion.content.clone();
-            let goal_link = format!(
-                "[{}]({})",
-                goal.metadata.title.content,
-                goal.link_path.display()
-            );
-
             champion_goals
-                .entry(champion_name)
+                .entry(&champion.content)
                 .or_default()
-                .insert(goal_link);
+                .insert((
+                    &goal.metadata.title.content,
+                    goal.link_path.display().to_string(),
+                ));
         }
     }
 
-    if champion_goals.is_empty() {
-        return Ok("No champions found.".to_string());
+    let mut table = Table::new(["Champion", "#", "Goal"]);
+    for (champion, goals) in champion_goals {
+        for (i, (title, link)) in goals.iter().enumerate() {
+            let (champion_cell, count_cell) = if i == 0 {
+                (
+                    Cell::markdown(champion),
+                    Cell::text(goals.len().to_string()),
+                )
+            } else {
+                (Cell::Empty, Cell::Empty)
+            };
+            table.push_row(vec![champion_cell, count_cell, Cell::link(*title, link)]);
+        }
     }
-
-    // Create the table with one row per goal,
-    // showing champion name and count only on the first row.
-    let table = {
-        let headings = vec![
-            Spanned::here("Champion".to_string()),
-            Spanned::here("#".to_string()),
-            Spanned::here("Goal".to_string()),
-        ];
-
-        let rows = champion_goals.into_iter().flat_map(|(champion, goals)| {
-            let goals_vec: Vec<String> = goals.into_iter().collect();
-            let count = goals_vec.len();
-            goals_vec
-                .into_iter()
-                .enumerate()
-                .map(move |(i, goal_link)| {
-                    if i == 0 {
-                        vec![
-                            Spanned::here(champion.clone()),
-                            Spanned::here(count.to_string()),
-                            Spanned::here(goal_link),
-                        ]
-                    } else {
-                        vec![
-                            Spanned::here(String::new()),
-                            Spanned::here(String::new()),
-                            Spanned::here(goal_link),
-                        ]
-                    }
-                })
-        });
-
-        std::iter::once(headings).chain(rows).collect::<Vec<_>>()
-    };
-
-    write!(output, "{}", util::format_table(&table))?;
-
-    Ok(output)
+    table
 }
@@ -3,9 +3,15 @@ use std::{
     path::PathBuf,
 };
 
-use spanned::{Result, Spanned};
-
-use crate::{config::Configuration, goal::TeamAsk, team::TeamName, util};
+use spanned::Result;
+
+use crate::{
+    config::Configuration,
+    goal::TeamAsk,
+    table::{Cell, Table},
+    team::TeamName,
+    util,
+};
 
 /// Format a set of team asks into a table, with asks separated by team and grouped by kind.
 ///
@@ -22,11 +28,20 @@ use crate::{config::Configuration, goal::TeamAsk, team::TeamName, util};
 pub fn format_team_asks(asks_of_any_team: &[&TeamAsk]) -> Result<String> {
     use std::fmt::Write;
 
-    const CHECK: &str = "✅";
-
     let mut output = String::new();
+    for (team_name, table) in team_asks_tables(asks_of_any_team)? {
+        let team_data = team_name.data();
+        write!(output, "\n#### {} team\n", team_data.name)?;
+        write!(output, "{}", table.to_markdown())?;
+    }
+    Ok(output)
+}
 
-    let all_teams: BTreeSet<&TeamName> = asks_of_any_team
+/// One table of asks per team, as shown by [`format_team_asks`].
+pub fn team_asks_tables(asks_of_any_team: &[&TeamAsk]) -> Result<Vec<(&'static TeamName, Table)>> {
+    const CHECK: &str = "✅";
+
+    let all_teams: BTreeSet<&'static TeamName> = asks_of_any_team
         .iter()
         .flat_map(|a| &a.teams)
         .copied()
@@ -35,18 +50,13 @@ pub fn format_team_asks(asks_of_any_team: &[&TeamAsk]) -> Result<String> {
     // The set of configured team asks
     let config = Configuration::get();
 
+    let mut tables = vec![];
     for team_name in all_teams {
         let asks_of_this_team: Vec<_> = asks_of_any_team
             .iter()
             .filter(|a| a.teams.contains(&team_name))
             .collect();
 
-        let team_data = team_name.data();
-        write!(output, "\n#### {} team\n", team_data.name)?;
-
-        // We will accumulate footnotes when we encounter comments that are too long.
-        let mut footnotes = util::Footnotes::new();
-
         // These are things like "discussion and moral support". They are extracted from
         // the configuration. We prune out the ones that do not appear in the asks for a particular team.
         let ask_headings = config
@@ -60,14 +70,21 @@ pub fn format_team_asks(asks_of_any_team: &[&TeamAsk]) -> Result<String> {
             })
             .map(|(ask_kind, _)| ask_kind)
             .collect::<Vec<_>>();
-        let empty_row = || {
-            (0..ask_headings.len())
-                .map(|_| "".to_string())
-                .collect::<Vec<_>>()
-        };
+
+        // The table accumulates footnotes when we encounter comments that are too long.
+        let mut table = Table::new(
+            std::iter::once(Cell::text("Goal")).chain(ask_headings.iter().map(|&ask_kind| {
+                Cell::markdown(format!(
+                    "[{team_ask_short}][valid_team_asks]", // HACK: This should not be hardcoded in the code.
+                    team_ask_short = config.team_asks[ask_kind].short,
+                ))
+            })), // e.g. "discussion and moral support"
+        );
+
+        let empty_row = || vec![Cell::Empty; ask_headings.len()];
 
         // Collect the asks by goal. The `rows` map goes from goal title to a row with entries
-        let mut goal_rows: BTreeMap<GoalData<'_>, Vec<String>> = BTreeMap::default();
+        let mut goal_rows: BTreeMap<GoalData<'_>, Vec<Cell>> = BTreeMap::default();
         for ask in &asks_of_this_team {
             let goal_data = GoalData::new(ask)?;
 
@@ -89,13 +106,8 @@ pub fn format_team_asks(asks_of_any_team: &[&TeamAsk]) -> Result<String> {
                 CHECK
             };
 
-            let cell = footnotes.maybe_footnote(text, &ask.link_path);
-
-            if !row[index].is_empty() {
-                row[index] = format!("{} {}", row[index], cell);
-            } else {
-                row[index] = cell;
-            }
+            let cell = table.footnote(text, &ask.link_path);
+            row[index] = std::mem::take(&mut row[index]).and(cell);
         }
 
         // Ensure that we have an entry for the "meta-goal", even if there are no asks.
@@ -105,33 +117,18 @@ pub fn format_team_asks(asks_of_any_team: &[&TeamAsk]) -> Result<String> {
             goal_rows.entry(goal_data).or_insert_with(empty_row);
         }
 
-        // Create the table itself.
-        let table = {
-            let headings = std::iter::once(Spanned::here("Goal".to_string()))
-                .chain(ask_headings.iter().map(|&ask_kind| {
-                    Spanned::here(format!(
-                        "[{team_ask_short}][valid_team_asks]", // HACK: This should not be hardcoded in the code.
-                        team_ask_short = config.team_asks[ask_kind].short,
-                    ))
-                })) // e.g. "discussion and moral support"
-                .collect::<Vec<Spanned<String>>>();
-
-            let rows = goal_rows.into_iter().map(|(goal_data, goal_columns)| {
+        for (goal_data, goal_columns) in goal_rows {
+            table.push_row(
                 std::iter::once(goal_data.goal_title())
                     .chain(goal_columns)
-                    .map(Spanned::here)
-                    .collect::<Vec<Spanned<String>>>()
-            });
-
-            std::iter::once(headings).chain(rows).collect::<Vec<_>>()
-        };
-
-        write!(output, "{}", util::format_table(&table))?;
+                    .collect(),
+            );
+        }
 
-        footnotes.write_to(&mut output)?;
+        tables.push((team_name, table));
     }
 
-    Ok(output)
+    Ok(tables)
 }
 
 #[derive(Eq, PartialEq, PartialOrd, Ord, Hash, Copy, Clone, Debug)]
@@ -162,7 +159,7 @@ impl<'g> GoalData<'g> {
         }
     }
 
-    fn goal_title(&self) -> String {
+    fn goal_title(&self) -> Cell {
         util::goal_title_cell(
             self.goal_title,
             self.link,
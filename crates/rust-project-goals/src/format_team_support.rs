@@ -6,10 +6,11 @@
 use std::collections::BTreeSet;
 use std::path::PathBuf;
 
-use spanned::{Result, Spanned};
+use spanned::Result;
 
 use crate::{
     goal::{GoalDocument, SupportLevel},
+    table::{Cell, Table},
     team::TeamName,
     util,
 };
@@ -25,7 +26,7 @@ struct GoalSupportData<'g> {
 }
 
 impl<'g> GoalSupportData<'g> {
-    fn goal_title_cell(&self) -> String {
+    fn goal_title_cell(&self) -> Cell {
         util::goal_title_cell(self.goal_title, self.link, self.subgoal_title)
     }
 }
@@ -72,11 +73,11 @@ pub fn format_team_support_for_team(
     goals: &[&GoalDocument],
     team_name: &'static TeamName,
 ) -> Result<String> {
-    use std::fmt::Write;
-
-    let mut output = String::new();
-    let mut footnotes = util::Footnotes::new();
+    Ok(team_support_table(goals, team_name).to_markdown())
+}
 
+/// The table of support entries for a single team, largest support level first.
+pub fn team_support_table(goals: &[&GoalDocument], team_name: &'static TeamName) -> Table {
     // Collect support entries for this team from all goals
     let mut entries: Vec<GoalSupportData> = vec![];
 
@@ -107,12 +108,7 @@ pub fn format_team_support_for_team(
     entries.sort_by_key(|e| std::cmp::Reverse(e.support_level));
 
     // Build the table
-    let mut table: Vec<Vec<Spanned<String>>> = vec![vec![
-        Spanned::here("Goal".to_string()),
-        Spanned::here("Level".to_string()),
-        Spanned::here("Champion".to_string()),
-        Spanned::here("Notes".to_string()),
-    ]];
+    let mut table = Table::new(["Goal", "Level", "Champion", "Notes"]);
 
     // Track which goals we've seen to avoid repeating goal title for subgoals
     let mut seen_goals: BTreeSet<&PathBuf> = BTreeSet::new();
@@ -123,27 +119,23 @@ pub fn format_team_support_for_team(
             entry.goal_title_cell()
         } else if seen_goals.contains(entry.link) {
             // We've already shown this goal, skip the title
-            String::new()
+            Cell::Empty
         } else {
             seen_goals.insert(entry.link);
             entry.goal_title_cell()
         };
 
-        let champion_cell = entry.champion.unwrap_or("").to_string();
+        let champion_cell = Cell::markdown(entry.champion.unwrap_or(""));
 
-        let notes_cell = footnotes.maybe_footnote(entry.notes, entry.link);
+        let notes_cell = table.footnote(entry.notes, entry.link);
 
-        table.push(vec![
-            Spanned::here(goal_cell),
-            Spanned::here(entry.support_level.to_string()),
-            Spanned::here(champion_cell),
-            Spanned::here(notes_cell),
+        table.push_row(vec![
+            goal_cell,
+            Cell::badge(entry.support_level.as_str()),
+            champion_cell,
+            notes_cell,
         ]);
     }
 
-    write!(output, "{}", util::format_table(&table))?;
-
-    footnotes.write_to(&mut output)?;
-
-    Ok(output)
+    table
 }
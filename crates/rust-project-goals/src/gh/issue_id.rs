@@ -1,4 +1,4 @@
-use crate::re::{GITHUB_ISSUE_URL, REPOSITORY, TRACKING_ISSUE};
+use crate::re::{self, GITHUB_ISSUE_URL, REPOSITORY, TRACKING_ISSUE};
 use std::fmt::Display;
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Debug)]
@@ -71,6 +71,33 @@ impl IssueId {
     }
 }
 
+impl IssueId {
+    /// Parse an issue reference written in any of the forms authors use in metadata tables:
+    /// `[org/repo#N]`, `org/repo#N`, or `https://github.com/org/repo/issues/N`.
+    pub fn parse_reference(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let s = s
+            .strip_prefix('[')
+            .and_then(|s| s.strip_suffix(']'))
+            .unwrap_or(s);
+
+        if re::is_just(&re::SEE_ALSO_ISSUE2, s) {
+            return Self::from_url(s);
+        }
+
+        if re::is_just(&re::SEE_ALSO_ISSUE1, s) {
+            let c = re::SEE_ALSO_ISSUE1.captures(s)?;
+            if c["org"].is_empty() || c["repo"].is_empty() {
+                return None;
+            }
+            let number = c["issue"].parse().ok()?;
+            return Some(IssueId::new(Repository::new(&c["org"], &c["repo"]), number));
+        }
+
+        None
+    }
+}
+
 impl std::fmt::Debug for IssueId {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{self}")
@@ -233,9 +233,10 @@ pub fn create_issue(
     title: &str,
     labels: &[String],
     assignees: &BTreeSet<String>,
-    milestone: &str,
+    milestone: Option<&str>,
 ) -> Result<IssueId> {
-    let output = Command::new("gh")
+    let mut command = Command::new("gh");
+    command
         .arg("-R")
         .arg(&repository.to_string())
         .arg("issue")
@@ -245,12 +246,17 @@ pub fn create_issue(
         .arg("-t")
         .arg(&title)
         .arg("-l")
-        .arg(labels.join(","))
-        .arg("-a")
-        .arg(comma(&assignees))
-        .arg("-m")
-        .arg(&milestone)
-        .output()?;
+        .arg(labels.join(","));
+
+    if !assignees.is_empty() {
+        command.arg("-a").arg(comma(assignees));
+    }
+
+    if let Some(milestone) = milestone {
+        command.arg("-m").arg(milestone);
+    }
+
+    let output = command.output()?;
 
     if !output.status.success() {
         return Err(Error::str(format!(
@@ -273,6 +279,69 @@ pub fn create_issue(
     Err(Error::str(format!("creating issue did not return a URL")))
 }
 
+pub fn close_issue(repository: &Repository, number: u64, comment: &str) -> Result<()> {
+    let output = Command::new("gh")
+        .arg("-R")
+        .arg(repository.to_string())
+        .arg("issue")
+        .arg("close")
+        .arg(number.to_string())
+        .arg("-c")
+        .arg(comment)
+        .output()?;
+
+    if !output.status.success() {
+        Err(Error::str(format!(
+            "failed to close issue `{}`: {}",
+            number,
+            String::from_utf8_lossy(&output.stderr)
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Make `child` a sub-issue of `parent`, so that it is listed on the parent issue.
+pub fn add_sub_issue(repository: &Repository, parent: u64, child: u64) -> Result<()> {
+    // The sub-issues API takes the internal id of the child, not its number.
+    let output = Command::new("gh")
+        .arg("api")
+        .arg(format!("repos/{repository}/issues/{child}"))
+        .arg("--jq")
+        .arg(".id")
+        .output()?;
+
+    if !output.status.success() {
+        return Err(Error::str(format!(
+            "failed to fetch id of issue `{}`: {}",
+            child,
+            String::from_utf8_lossy(&output.stderr)
+        )));
+    }
+
+    let child_id = str::from_utf8(&output.stdout)?.trim().to_string();
+
+    let output = Command::new("gh")
+        .arg("api")
+        .arg("--method")
+        .arg("POST")
+        .arg(format!("repos/{repository}/issues/{parent}/sub_issues"))
+        .arg("-F")
+        .arg(format!("sub_issue_id={child_id}"))
+        .output()?;
+
+    if !output.status.success() {
+        Err(Error::str(format!(
+            "failed to add issue `{}` as a sub-issue of `{}`: {}",
+            child,
+            parent,
+            String::from_utf8_lossy(&output.stderr)
+        )))
+    } else {
+        Ok(())
+    }
+}
+
 pub fn change_title(repository: &Repository, number: u64, title: &str) -> Result<()> {
     let mut command = Command::new("gh");
     command
@@ -433,6 +502,10 @@ pub fn sync_labels(
 
 pub const ROADMAP_LABEL: &str = "Roadmap Goal";
 
+pub const HELP_WANTED_LABEL: &str = "help wanted";
+
+pub const GOOD_FIRST_ISSUE_LABEL: &str = "good first issue";
+
 pub const LOCK_TEXT: &str = "This issue is intended for status updates only.\n\nFor general questions or comments, please contact the owner(s) directly.";
 
 pub const CONTINUING_GOAL_PREFIX: &str = "This is a continuing project goal, and the updates below this comment will be for the new period";
@@ -530,9 +603,12 @@ impl From<ExistingGithubIssueJson> for ExistingGithubIssue {
 /// * Option A, the most common, is to have checkboxes in the issue. We just count the number that are checked.
 /// * Option B is to include a metadata line called "Tracked issues" that lists a search query. We count the number of open vs closed issues in that query.
 ///
+/// In addition, each of `other_tracking_issues` (from the goal's `Other tracking issues` row)
+/// contributes its own progress, just like issues listed in a "See also" line.
+///
 /// Returns a tuple (completed, total) with the number of completed items and the total number of items.
-pub fn checkboxes(issue: &ExistingGithubIssue) -> Progress {
-    match try_checkboxes(&issue) {
+pub fn checkboxes(issue: &ExistingGithubIssue, other_tracking_issues: &[IssueId]) -> Progress {
+    match try_checkboxes(issue, other_tracking_issues) {
         Ok(pair) => pair,
         Err(e) => Progress::Error {
             message: e.to_string(),
@@ -540,10 +616,19 @@ pub fn checkboxes(issue: &ExistingGithubIssue) -> Progress {
     }
 }
 
-fn try_checkboxes(issue: &ExistingGithubIssue) -> Result<Progress> {
+fn try_checkboxes(
+    issue: &ExistingGithubIssue,
+    other_tracking_issues: &[IssueId],
+) -> Result<Progress> {
     let mut completed = 0;
     let mut total = 0;
 
+    for IssueId { repository, number } in other_tracking_issues {
+        let (c, t) = linked_issue_progress(repository, *number)?;
+        completed += c;
+        total += t;
+    }
+
     for line in issue.body.lines() {
         // Does this match TRACKED_ISSUES?
         if let Some(c) = re::TRACKED_ISSUES_QUERY.captures(line) {
@@ -572,27 +657,9 @@ fn try_checkboxes(issue: &ExistingGithubIssue) -> Result<Progress> {
                 };
                 let repository = Repository::new(&c["org"], &c["repo"]);
                 let issue_number = c["issue"].parse::<u64>()?;
-                let issue = fetch_issue(&repository, issue_number)?;
-                match try_checkboxes(&issue)? {
-                    Progress::Binary { is_closed } => {
-                        if is_closed {
-                            completed += 1;
-                        }
-                        total += 1;
-                    }
-
-                    Progress::Tracked {
-                        completed: c,
-                        total: t,
-                    } => {
-                        completed += c;
-                        total += t;
-                    }
-
-                    Progress::Error { message } => {
-                        spanned::bail_here!("error parsing {repository}#{issue_number}: {message}")
-                    }
-                }
+                let (c, t) = linked_issue_progress(&repository, issue_number)?;
+                completed += c;
+                total += t;
             }
         }
 
@@ -612,3 +679,18 @@ fn try_checkboxes(issue: &ExistingGithubIssue) -> Result<Progress> {
         Ok(Progress::Tracked { completed, total })
     }
 }
+
+/// Progress of an issue linked from a tracking issue, as `(completed, total)`.
+/// An issue without checkboxes counts as a single item.
+fn linked_issue_progress(repository: &Repository, issue_number: u64) -> Result<(u32, u32)> {
+    let issue = fetch_issue(repository, issue_number)?;
+    match try_checkboxes(&issue, &[])? {
+        Progress::Binary { is_closed } => Ok((if is_closed { 1 } else { 0 }, 1)),
+
+        Progress::Tracked { completed, total } => Ok((completed, total)),
+
+        Progress::Error { message } => {
+            spanned::bail_here!("error parsing {repository}#{issue_number}: {message}")
+        }
+    }
+}
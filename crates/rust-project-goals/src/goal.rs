@@ -3,30 +3,45 @@ use std::path::Path;
 use std::sync::Arc;
 use std::{collections::BTreeSet, path::PathBuf};
 
-use spanned::{Error, Result, Spanned};
+use spanned::{Error, Result, Span, Spanned};
 
-use crate::config::{Configuration, TeamAskDetails};
+use crate::badge;
+use crate::config::{
+    describe_vocabulary, Configuration, SupportLevelDetails, TeamAskDetails, Vocabulary,
+};
 use crate::gh::issue_id::{IssueId, Repository};
 use crate::gh::issues::{checkboxes, ExistingGithubIssue};
 use crate::markwaydown::{self, Section, Table};
 use crate::re::{self, CHAMPION_METADATA};
+use crate::table::{self, Cell};
 use crate::team::{self, TeamName};
+use crate::timespan::Timespan;
 use crate::util::{self, commas, markdown_files};
 use rust_project_goals_json::{GithubIssueState, Progress};
 
 /// A parsed funding cost value.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum FundingCost {
-    /// A known dollar amount in USD (stored as whole dollars).
-    Usd(u64),
+    /// A known amount, or a range of amounts, in a given currency (stored as whole units).
+    /// For a single amount, `low == high`.
+    Amount {
+        /// ISO 4217 currency code, e.g. `USD` or `EUR`.
+        currency: String,
+        low: u64,
+        high: u64,
+    },
     /// The cost is not yet determined.
     Tbd,
     /// Ask the Funding contact about the cost.
     Ask,
 }
 
+/// Currency symbols we recognize, along with their ISO 4217 codes.
+const CURRENCY_SYMBOLS: &[(&str, &str)] = &[("$", "USD"), ("€", "EUR"), ("£", "GBP")];
+
 impl FundingCost {
-    /// Parse a cost string like "$25,000", "$75K", "$1.5M", "TBD", or "Ask".
+    /// Parse a cost string like "$25,000", "$75K", "€1.5M", "GBP 40,000", "50K CHF",
+    /// a range like "$50K–$80K" or "€40,000 to €60,000", "TBD", or "Ask".
     pub fn parse(s: &str) -> Option<Self> {
         let s = s.trim();
         if s.eq_ignore_ascii_case("TBD") {
@@ -35,29 +50,138 @@ impl FundingCost {
         if s.eq_ignore_ascii_case("Ask") {
             return Some(FundingCost::Ask);
         }
-        let s = s.strip_prefix('$')?;
-        let s = s.replace(',', "");
-        let s = s.trim();
-        if let Some(base) = s.strip_suffix('K').or_else(|| s.strip_suffix('k')) {
-            let n: f64 = base.trim().parse().ok()?;
-            Some(FundingCost::Usd((n * 1_000.0) as u64))
-        } else if let Some(base) = s.strip_suffix('M').or_else(|| s.strip_suffix('m')) {
-            let n: f64 = base.trim().parse().ok()?;
-            Some(FundingCost::Usd((n * 1_000_000.0) as u64))
-        } else {
-            let n: u64 = s.parse().ok()?;
-            Some(FundingCost::Usd(n))
+
+        let (low, high) = match ["–", "—", " to ", "-"]
+            .iter()
+            .find_map(|sep| s.split_once(sep))
+        {
+            Some((low, high)) => (parse_money(low)?, parse_money(high)?),
+            None => {
+                let amount = parse_money(s)?;
+                (amount.clone(), amount)
+            }
+        };
+
+        // In a range, the currency only needs to be written once (e.g. "$50K-80K").
+        let currency = match (low.0, high.0) {
+            (Some(a), Some(b)) if a != b => return None,
+            (Some(c), _) | (None, Some(c)) => c,
+            (None, None) => return None,
+        };
+        if low.1 > high.1 {
+            return None;
         }
+
+        Some(FundingCost::Amount {
+            currency,
+            low: low.1,
+            high: high.1,
+        })
     }
 
-    /// Format as a display string (e.g., "$75,000", "TBD", or "Ask").
+    /// Format as a display string in the original currency
+    /// (e.g., "$75,000", "€50,000–€80,000", "TBD", or "Ask").
     pub fn display(&self) -> String {
         match self {
             FundingCost::Tbd => "TBD".to_string(),
             FundingCost::Ask => "Ask".to_string(),
-            FundingCost::Usd(amount) => format!("${}", format_with_commas(*amount)),
+            FundingCost::Amount {
+                currency,
+                low,
+                high,
+            } => {
+                if low == high {
+                    format_money(currency, *low)
+                } else {
+                    format!(
+                        "{}–{}",
+                        format_money(currency, *low),
+                        format_money(currency, *high)
+                    )
+                }
+            }
         }
     }
+
+    /// Like [`display`](Self::display), but amounts in currencies other than USD
+    /// are followed by their approximate USD value, e.g. "€50,000 (≈$54,000)".
+    pub fn display_normalized(&self) -> String {
+        let display = self.display();
+        match self {
+            FundingCost::Amount { currency, .. } if currency != "USD" => match self.to_usd() {
+                Some(usd) => format!("{display} (≈{})", usd.display()),
+                None => display,
+            },
+            _ => display,
+        }
+    }
+
+    /// Convert an amount to USD using the exchange rates from `rust-project-goals.toml`.
+    /// Returns `None` for `Tbd`/`Ask` or if there is no exchange rate for the currency.
+    pub fn to_usd(&self) -> Option<FundingCost> {
+        let FundingCost::Amount {
+            currency,
+            low,
+            high,
+        } = self
+        else {
+            return None;
+        };
+        let rate = Configuration::get().exchange_rate(currency)?;
+        Some(FundingCost::Amount {
+            currency: "USD".to_string(),
+            low: (*low as f64 * rate).round() as u64,
+            high: (*high as f64 * rate).round() as u64,
+        })
+    }
+}
+
+/// Parse a single amount like "$25,000", "€75K", "GBP 1.5M", or "50,000 CHF",
+/// returning the currency code (if any was written) and the amount in whole units.
+fn parse_money(s: &str) -> Option<(Option<String>, u64)> {
+    let s = s.trim();
+
+    let (currency, s) = if let Some((rest, code)) = CURRENCY_SYMBOLS
+        .iter()
+        .find_map(|(symbol, code)| Some((s.strip_prefix(symbol)?, code)))
+    {
+        (Some(code.to_string()), rest)
+    } else if let Some(code) = s.get(..3).filter(|code| is_currency_code(code)) {
+        (Some(code.to_string()), &s[3..])
+    } else if let Some(code) = s
+        .len()
+        .checked_sub(3)
+        .and_then(|i| s.get(i..))
+        .filter(|code| is_currency_code(code))
+    {
+        (Some(code.to_string()), &s[..s.len() - 3])
+    } else {
+        (None, s)
+    };
+
+    let s = s.replace(',', "");
+    let s = s.trim();
+    let amount = if let Some(base) = s.strip_suffix('K').or_else(|| s.strip_suffix('k')) {
+        let n: f64 = base.trim().parse().ok()?;
+        (n * 1_000.0) as u64
+    } else if let Some(base) = s.strip_suffix('M').or_else(|| s.strip_suffix('m')) {
+        let n: f64 = base.trim().parse().ok()?;
+        (n * 1_000_000.0) as u64
+    } else {
+        s.parse().ok()?
+    };
+    Some((currency, amount))
+}
+
+fn is_currency_code(s: &str) -> bool {
+    s.len() == 3 && s.chars().all(|c| c.is_ascii_uppercase())
+}
+
+fn format_money(currency: &str, amount: u64) -> String {
+    match CURRENCY_SYMBOLS.iter().find(|(_, code)| *code == currency) {
+        Some((symbol, _)) => format!("{symbol}{}", format_with_commas(amount)),
+        None => format!("{currency} {}", format_with_commas(amount)),
+    }
 }
 
 fn format_with_commas(n: u64) -> String {
@@ -72,18 +196,16 @@ fn format_with_commas(n: u64) -> String {
     result.chars().rev().collect()
 }
 
-/// Sum a slice of FundingCost values. Returns None if the slice is empty, `Tbd` if all costs are TBD, otherwise sums the known amounts.
+/// Sum a slice of FundingCost values. Returns None if the slice is empty, `Tbd` if all costs are TBD,
+/// otherwise sums the low and high bounds of the known amounts. If all known amounts share a currency,
+/// the total is in that currency; otherwise, it is normalized to USD using the configured exchange rates.
 pub fn sum_funding_costs(costs: &[FundingCost]) -> Option<FundingCost> {
-    let mut total: u64 = 0;
-    let mut any_known = false;
+    let mut amounts = vec![];
     let mut any_tbd = false;
     let mut any_undisclosed = false;
     for cost in costs {
         match cost {
-            FundingCost::Usd(n) => {
-                total += n;
-                any_known = true;
-            }
+            FundingCost::Amount { .. } => amounts.push(cost.clone()),
             FundingCost::Tbd => {
                 any_tbd = true;
             }
@@ -92,8 +214,49 @@ pub fn sum_funding_costs(costs: &[FundingCost]) -> Option<FundingCost> {
             }
         }
     }
-    if any_known {
-        Some(FundingCost::Usd(total))
+
+    let currencies: BTreeSet<&str> = amounts
+        .iter()
+        .filter_map(|cost| match cost {
+            FundingCost::Amount { currency, .. } => Some(currency.as_str()),
+            _ => None,
+        })
+        .collect();
+    if currencies.len() > 1 {
+        // Amounts without an exchange rate are rejected when the goal is loaded.
+        amounts = amounts.iter().filter_map(|cost| cost.to_usd()).collect();
+    }
+
+    let mut total: Option<FundingCost> = None;
+    for amount in amounts {
+        let FundingCost::Amount {
+            currency,
+            low,
+            high,
+        } = amount
+        else {
+            continue;
+        };
+        total = Some(match total {
+            Some(FundingCost::Amount {
+                low: total_low,
+                high: total_high,
+                ..
+            }) => FundingCost::Amount {
+                currency,
+                low: total_low + low,
+                high: total_high + high,
+            },
+            _ => FundingCost::Amount {
+                currency,
+                low,
+                high,
+            },
+        });
+    }
+
+    if total.is_some() {
+        total
     } else if any_tbd {
         Some(FundingCost::Tbd)
     } else if any_undisclosed {
@@ -136,21 +299,21 @@ impl FundingStatus {
         }
     }
 
-    /// The status emoji only.
-    pub fn emoji(&self) -> &'static str {
+    /// The name of the badge for this status, see [`Cell::badge`].
+    pub fn badge(&self) -> &'static str {
         match self {
-            FundingStatus::No => "\u{1f7e5}",
-            FundingStatus::Partial(_) => "\u{1f7e1}",
-            FundingStatus::Funded(_) => "\u{2705}",
+            FundingStatus::No => "Not funded",
+            FundingStatus::Partial(_) => "Partially funded",
+            FundingStatus::Funded(_) => "Funded",
         }
     }
 
-    /// Display as emoji + label (for per-goal section rendering).
-    pub fn display(&self) -> &'static str {
+    /// The status as written in the `Funded` column, without the sponsor.
+    pub fn label(&self) -> &'static str {
         match self {
-            FundingStatus::No => "\u{25cb} No",
-            FundingStatus::Partial(_) => "\u{25d1} Partial",
-            FundingStatus::Funded(_) => "\u{2713} Yes",
+            FundingStatus::No => "No",
+            FundingStatus::Partial(_) => "Partial",
+            FundingStatus::Funded(_) => "Full",
         }
     }
 
@@ -207,12 +370,256 @@ pub struct FundingItem {
     pub sponsors: Option<String>,
 }
 
+impl FundingItem {
+    /// The sponsor names as written in the `Sponsor(s)` column and the `Funded` column
+    /// (e.g. "Partial by X"), with markdown links reduced to their text.
+    pub fn sponsor_names(&self) -> Vec<&str> {
+        self.sponsors
+            .iter()
+            .map(|s| s.as_str())
+            .chain(self.status.sponsor())
+            .flat_map(|s| s.split(','))
+            .map(sponsor_link_text)
+            .filter(|s| !s.is_empty())
+            .collect()
+    }
+
+    /// The sponsors of this item, using canonical names from the sponsor registry
+    /// where known and the name as written otherwise.
+    pub fn canonical_sponsors(&self) -> Vec<&str> {
+        let config = Configuration::get();
+        let mut sponsors = vec![];
+        for name in self.sponsor_names() {
+            let name = config
+                .sponsor(name)
+                .map(|(canonical, _)| canonical)
+                .unwrap_or(name);
+            if !sponsors.contains(&name) {
+                sponsors.push(name);
+            }
+        }
+        sponsors
+    }
+}
+
+/// Reduce a sponsor entry like `[AWS](https://aws.amazon.com/)` to `AWS`.
+fn sponsor_link_text(entry: &str) -> &str {
+    let entry = entry.trim();
+    entry
+        .strip_prefix('[')
+        .and_then(|rest| rest.split_once("]("))
+        .map(|(text, _)| text.trim())
+        .unwrap_or(entry)
+}
+
+/// Format a sponsor name as a link to its website, if the registry has one.
+fn format_sponsor(name: &str) -> String {
+    match Configuration::get().sponsor(name) {
+        Some((canonical, details)) => match &details.url {
+            Some(url) => format!("[{canonical}]({url})"),
+            None => canonical.to_string(),
+        },
+        None => name.to_string(),
+    }
+}
+
 /// A single row from the `## Help wanted` section's `| Task | Experience level | Time investment |` table.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct HelpWantedItem {
     pub task: String,
-    pub experience_level: String,
-    pub time_investment: String,
+    pub experience_level: ExperienceLevel,
+    pub time_investment: TimeInvestment,
+}
+
+/// The experience level needed for a help wanted item.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ExperienceLevel {
+    Beginner,
+    Intermediate,
+    Advanced,
+    /// Not specified (empty or `TBD`).
+    Unspecified,
+    /// Free-form text that does not start with a level, e.g. "Familiarity with rustdoc".
+    Other(String),
+}
+
+impl ExperienceLevel {
+    /// Parse an experience level cell. Text starting with a level keyword
+    /// (e.g. "Intermediate Rust", "Expert") is normalized to that level.
+    pub fn parse(s: &str) -> Self {
+        let s = s.trim();
+        if s.is_empty() || s.eq_ignore_ascii_case("TBD") {
+            return ExperienceLevel::Unspecified;
+        }
+        let first_word = s
+            .split(|c: char| !c.is_alphanumeric())
+            .next()
+            .unwrap_or("")
+            .to_lowercase();
+        match first_word.as_str() {
+            "beginner" | "newcomer" | "novice" => ExperienceLevel::Beginner,
+            "intermediate" => ExperienceLevel::Intermediate,
+            "advanced" | "expert" => ExperienceLevel::Advanced,
+            _ => ExperienceLevel::Other(s.to_string()),
+        }
+    }
+
+    /// The lowercase name of a normalized level, e.g. `beginner`.
+    pub fn key(&self) -> Option<&'static str> {
+        match self {
+            ExperienceLevel::Beginner => Some("beginner"),
+            ExperienceLevel::Intermediate => Some("intermediate"),
+            ExperienceLevel::Advanced => Some("advanced"),
+            ExperienceLevel::Unspecified | ExperienceLevel::Other(_) => None,
+        }
+    }
+
+    /// Parse a level name as used in filters, e.g. `beginner`.
+    pub fn parse_level(s: &str) -> Option<Self> {
+        match Self::parse(s) {
+            level @ (ExperienceLevel::Beginner
+            | ExperienceLevel::Intermediate
+            | ExperienceLevel::Advanced) => Some(level),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ExperienceLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExperienceLevel::Beginner => write!(f, "Beginner"),
+            ExperienceLevel::Intermediate => write!(f, "Intermediate"),
+            ExperienceLevel::Advanced => write!(f, "Advanced"),
+            ExperienceLevel::Unspecified => write!(f, "TBD"),
+            ExperienceLevel::Other(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+/// Unit of a [`TimeInvestment`] duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TimeUnit {
+    Hours,
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+impl TimeUnit {
+    fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().trim_end_matches('s') {
+            "hour" => Some(TimeUnit::Hours),
+            "day" => Some(TimeUnit::Days),
+            "week" => Some(TimeUnit::Weeks),
+            "month" => Some(TimeUnit::Months),
+            "year" => Some(TimeUnit::Years),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            TimeUnit::Hours => "hour",
+            TimeUnit::Days => "day",
+            TimeUnit::Weeks => "week",
+            TimeUnit::Months => "month",
+            TimeUnit::Years => "year",
+        }
+    }
+
+    /// Approximate number of (working) weeks in `n` of this unit, rounded up.
+    fn weeks(self, n: u32) -> u32 {
+        match self {
+            TimeUnit::Hours => n.div_ceil(40),
+            TimeUnit::Days => n.div_ceil(5),
+            TimeUnit::Weeks => n,
+            TimeUnit::Months => n * 4,
+            TimeUnit::Years => n * 52,
+        }
+    }
+}
+
+/// The time investment expected for a help wanted item.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TimeInvestment {
+    /// A duration or range of durations, e.g. "2 weeks" or "1-3 months part-time".
+    Duration {
+        min: u32,
+        max: u32,
+        unit: TimeUnit,
+        part_time: bool,
+    },
+    /// Not specified (empty or `TBD`).
+    Unspecified,
+    /// Free-form text that is not a duration.
+    Other(String),
+}
+
+impl TimeInvestment {
+    /// Parse a time investment cell like "2 weeks", "1-3 months part-time", or "TBD".
+    pub fn parse(s: &str) -> Self {
+        let s = s.trim();
+        if s.is_empty() || s.eq_ignore_ascii_case("TBD") {
+            return TimeInvestment::Unspecified;
+        }
+        let Some(c) = re::TIME_INVESTMENT.captures(s) else {
+            return TimeInvestment::Other(s.to_string());
+        };
+        let (Ok(min), Some(unit)) = (c["min"].parse::<u32>(), TimeUnit::parse(&c["unit"])) else {
+            return TimeInvestment::Other(s.to_string());
+        };
+        let max = match c.name("max").map(|m| m.as_str().parse::<u32>()) {
+            Some(Ok(max)) if max >= min => max,
+            Some(_) => return TimeInvestment::Other(s.to_string()),
+            None => min,
+        };
+        let rest = c["rest"].to_lowercase();
+        TimeInvestment::Duration {
+            min,
+            max,
+            unit,
+            part_time: rest.contains("part-time") || rest.contains("part time"),
+        }
+    }
+
+    /// Upper bound of the expected time in weeks (approximate), if known.
+    pub fn max_weeks(&self) -> Option<u32> {
+        match self {
+            TimeInvestment::Duration { max, unit, .. } => Some(unit.weeks(*max)),
+            TimeInvestment::Unspecified | TimeInvestment::Other(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for TimeInvestment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeInvestment::Duration {
+                min,
+                max,
+                unit,
+                part_time,
+            } => {
+                if min == max {
+                    write!(f, "{min}")?;
+                } else {
+                    write!(f, "{min}–{max}")?;
+                }
+                write!(f, " {}", unit.name())?;
+                if *max != 1 {
+                    write!(f, "s")?;
+                }
+                if *part_time {
+                    write!(f, " part-time")?;
+                }
+                Ok(())
+            }
+            TimeInvestment::Unspecified => write!(f, "TBD"),
+            TimeInvestment::Other(s) => write!(f, "{s}"),
+        }
+    }
 }
 
 /// Data parsed from a goal file in the expected format
@@ -340,12 +747,24 @@ pub struct Metadata {
     /// Optional "What and why" description from metadata table
     pub what_and_why: Option<String>,
 
-    /// Optional "Timespan" override (e.g. "2026–2027"). Defaults to the milestone directory name.
-    pub timespan: Option<String>,
+    /// Optional "Timespan" override (e.g. "2026–2027"). Defaults to the milestone.
+    pub timespan: Option<Spanned<Timespan>>,
 
     /// Optional funding point of contact (freeform markdown).
     /// Defaults to the Rust Funding team link when absent.
     pub funding_poc: Option<String>,
+
+    /// Zulip channels for discussing this goal (`| Zulip channel | ... |` row)
+    pub zulip_channels: Vec<ZulipChannel>,
+
+    /// Issues in other repositories that track this work (`| Other tracking issues | ... |` row)
+    pub other_tracking_issues: Vec<IssueId>,
+
+    /// Goals that must land before this one (`| Depends on | ... |` rows)
+    pub depends_on: Vec<GoalReference>,
+
+    /// Goals that cannot land before this one (`| Blocks | ... |` rows)
+    pub blocks: Vec<GoalReference>,
 }
 
 impl Metadata {
@@ -355,6 +774,114 @@ impl Metadata {
     }
 }
 
+/// A Zulip channel from the `Zulip channel` metadata row.
+/// Authors write these as `#t-lang`, `[#t-lang][channel]`, `[#t-lang](https://...)`,
+/// or a bare link to rust-lang.zulipchat.com; at least one of the fields is present.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ZulipChannel {
+    /// Channel name without the leading `#`, e.g. `t-lang/move-trait`
+    pub name: Option<String>,
+
+    /// Explicit link to the channel or a topic within it
+    pub url: Option<String>,
+
+    /// The original reference-style link (e.g. `[#t-lang][channel]`),
+    /// whose target is defined elsewhere in the goal document
+    pub reference_link: Option<String>,
+}
+
+pub const ZULIP_URL: &str = "https://rust-lang.zulipchat.com/";
+
+impl ZulipChannel {
+    /// Parse a single entry of the `Zulip channel` row; returns `None` if it is not recognized.
+    fn parse(entry: &str) -> Option<Self> {
+        let entry = entry.trim();
+
+        if let Some(c) = re::MARKDOWN_LINK_TARGET.captures(entry) {
+            let name = re::strip_markdown_link(entry).trim_start_matches('#');
+            return Some(ZulipChannel {
+                name: Some(name.to_string()),
+                url: Some(c["target"].to_string()),
+                reference_link: None,
+            });
+        }
+
+        if let Some(c) = re::MARKDOWN_REFERENCE_LINK.captures(entry) {
+            return Some(ZulipChannel {
+                name: Some(c["text"].trim_start_matches('#').to_string()),
+                url: None,
+                reference_link: Some(entry.to_string()),
+            });
+        }
+
+        if entry.starts_with("https://") {
+            return Some(ZulipChannel {
+                name: None,
+                url: Some(entry.to_string()),
+                reference_link: None,
+            });
+        }
+
+        if entry.starts_with('#') && !entry.contains(char::is_whitespace) {
+            return Some(ZulipChannel {
+                name: Some(entry[1..].to_string()),
+                url: None,
+                reference_link: None,
+            });
+        }
+
+        None
+    }
+
+    /// Link to the channel: the explicit URL if one was given, otherwise a link
+    /// that narrows to the channel by name.
+    pub fn url(&self) -> String {
+        if let Some(url) = &self.url {
+            return url.clone();
+        }
+
+        // Zulip encodes narrow operands like `encodeURIComponent`, but with `.` in place of `%`.
+        let mut encoded = String::new();
+        for byte in self.name.as_deref().unwrap_or_default().bytes() {
+            if byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_' {
+                encoded.push(byte as char);
+            } else {
+                encoded.push_str(&format!(".{byte:02X}"));
+            }
+        }
+        format!("{ZULIP_URL}#narrow/channel/{encoded}")
+    }
+
+    /// Markdown link to the channel, e.g. `[#t-lang](https://...)`.
+    /// Reference-style links are kept as written so they use the document's definition.
+    pub fn markdown_link(&self) -> String {
+        if let Some(reference_link) = &self.reference_link {
+            return reference_link.clone();
+        }
+        match &self.name {
+            Some(name) => format!("[#{}]({})", name, self.url()),
+            None => format!("<{}>", self.url()),
+        }
+    }
+}
+
+/// True if a metadata cell says there is nothing to fill in (`N/A`, `TBD`, `none`, ...).
+fn is_placeholder(s: &str) -> bool {
+    let s = s.trim().to_lowercase();
+    s.is_empty() || s.starts_with("n/a") || s.starts_with("tbd") || s == "none"
+}
+
+/// A reference to another goal from a `Depends on` or `Blocks` metadata row.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum GoalReference {
+    /// A goal file in the same milestone, e.g. `[Next solver](./next-solver.md)`.
+    /// Stores the path relative to the milestone directory (without a leading `./`).
+    File(Spanned<String>),
+
+    /// A tracking issue, e.g. `[rust-lang/rust-project-goals#123]`.
+    Issue(Spanned<IssueId>),
+}
+
 /// A set of theme names parsed from metadata rows.
 /// Used for both roadmap and highlight themes.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
@@ -398,6 +925,19 @@ impl Themes {
     }
 }
 
+#[cfg(any(test, feature = "testing"))]
+impl Themes {
+    /// Themes for tests, as if parsed from a metadata row.
+    pub fn for_test(themes: &[&str]) -> Themes {
+        Themes {
+            themes: themes
+                .iter()
+                .map(|t| Spanned::here(t.to_string()))
+                .collect(),
+        }
+    }
+}
+
 pub const TRACKING_ISSUE_ROW: &str = "Tracking issue";
 
 /// A single row from a `| Task | Owner(s) | Notes |` table
@@ -406,9 +946,87 @@ pub const TRACKING_ISSUE_ROW: &str = "Tracking issue";
 pub struct TaskItem {
     pub task: Spanned<String>,
     pub owners: String,
+
+    /// From the optional `Status` column (`None` if the column is absent or the cell is empty)
+    pub status: Option<TaskStatus>,
+
     pub notes: String,
 }
 
+/// Status of a work item, from the optional `Status` column of a task table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TaskStatus {
+    Planned,
+    InProgress,
+    Done,
+    Dropped,
+}
+
+impl TaskStatus {
+    /// Parse a `Status` cell; returns `None` for an empty cell.
+    fn parse(s: &Spanned<String>) -> Result<Option<Self>> {
+        let valid_values = [
+            ("planned", TaskStatus::Planned),
+            ("in progress", TaskStatus::InProgress),
+            ("done", TaskStatus::Done),
+            ("dropped", TaskStatus::Dropped),
+        ];
+
+        let value = s.content.trim();
+        if value.is_empty() {
+            return Ok(None);
+        }
+
+        for (valid_value, status) in valid_values {
+            if value.eq_ignore_ascii_case(valid_value) {
+                return Ok(Some(status));
+            }
+        }
+
+        spanned::bail!(
+            s,
+            "unrecognized task status `{}`, expected one of {:?}",
+            value,
+            valid_values.iter().map(|(s, _)| s).collect::<Vec<_>>(),
+        )
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskStatus::Planned => "Planned",
+            TaskStatus::InProgress => "In progress",
+            TaskStatus::Done => "Done",
+            TaskStatus::Dropped => "Dropped",
+        }
+    }
+}
+
+/// Progress derived from the `Status` column of the work item tables.
+/// Dropped tasks count towards neither `done` nor `total`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TaskProgress {
+    pub done: u32,
+    pub total: u32,
+}
+
+impl TaskProgress {
+    fn add(&mut self, other: TaskProgress) {
+        self.done += other.done;
+        self.total += other.total;
+    }
+
+    /// Render as a progress bar, in the same style as tracking issue progress.
+    pub fn html(&self) -> String {
+        generate_progress_html(
+            &Progress::Tracked {
+                completed: self.done,
+                total: self.total,
+            },
+            &GithubIssueState::Open,
+        )
+    }
+}
+
 /// Hierarchical task structure parsed from "Work items over the next year" (2026+ format).
 /// The root node represents the goal itself; children represent `####` subsections.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -423,7 +1041,7 @@ pub struct TaskTree {
     pub roadmap: Themes,
 
     /// Effective timespan (own if present, else parent's)
-    pub timespan: Option<String>,
+    pub timespan: Option<Spanned<Timespan>>,
 
     /// Effective "what and why" (own if present, else parent's)
     pub what_and_why: Option<String>,
@@ -472,6 +1090,34 @@ impl TaskTree {
         themes
     }
 
+    /// Progress of this node and its children, rolled up from the `Status` column.
+    /// Returns `None` if no task in the tree has a status.
+    pub fn progress(&self) -> Option<TaskProgress> {
+        let mut progress = None;
+        for task in &self.tasks {
+            let Some(status) = task.status else {
+                continue;
+            };
+            let p = progress.get_or_insert_with(TaskProgress::default);
+            match status {
+                TaskStatus::Done => {
+                    p.done += 1;
+                    p.total += 1;
+                }
+                TaskStatus::Planned | TaskStatus::InProgress => p.total += 1,
+                TaskStatus::Dropped => {}
+            }
+        }
+        for child in &self.children {
+            if let Some(child_progress) = child.progress() {
+                progress
+                    .get_or_insert_with(TaskProgress::default)
+                    .add(child_progress);
+            }
+        }
+        progress
+    }
+
     /// Returns true if this node (or any child) matches the given roadmap theme.
     /// When children exist, only children are checked (they inherit parent themes).
     pub fn matches_roadmap_theme(&self, theme: &str) -> bool {
@@ -544,19 +1190,13 @@ pub struct TeamAsk {
 /// This is part of the **new format** introduced in 2026. Instead of listing specific
 /// asks (like "RFC decision"), goals now specify a support level that indicates how
 /// much involvement is needed from each team.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub enum SupportLevel {
-    /// Team only needs to do routine activities.
-    /// Example: A compiler change that will require a few small PRs to be reviewed.
-    Small,
-
-    /// Dedicated support from one person, but the rest of the team doesn't have to do much.
-    /// Example: Implementing a small, noncontroversial language feature.
-    Medium,
-
-    /// Deeper review from the entire team.
-    /// Example: Rearchitecting part of the compiler or implementing a complex language feature.
-    Large,
+///
+/// The levels are defined by [`Configuration::support_levels`]; they compare in the
+/// order in which they are listed there, from least to most involvement.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SupportLevel {
+    /// Index into [`Configuration::support_levels`].
+    index: usize,
 }
 
 /// Represents the support needed from a single team for a goal.
@@ -571,7 +1211,7 @@ pub struct TeamSupport {
     /// The team being asked for support
     pub team: &'static TeamName,
 
-    /// Level of support needed (Small, Medium, Large)
+    /// Level of support needed (e.g., Small, Medium, Large)
     pub support_level: SupportLevel,
 
     /// Any notes about what's needed
@@ -631,25 +1271,24 @@ impl TeamInvolvement {
 /// Load all the goals from a given directory.
 /// Roadmap files (`roadmap-*.md`) are skipped; use `roadmaps_in_dir` for those.
 pub fn goals_in_dir(directory_path: &Path) -> Result<Vec<GoalDocument>> {
-    let mut goal_documents = vec![];
-    for (path, link_path) in markdown_files(&directory_path)? {
-        // Skip template files
-        if path.file_name().unwrap() == "TEMPLATE.md" {
-            continue;
-        }
+    let files: Vec<_> = markdown_files(directory_path)?
+        .into_iter()
+        .filter(|(path, _)| {
+            let filename = path.file_name().unwrap().to_str().unwrap_or_default();
 
-        // Skip roadmap files (they're loaded separately via roadmaps_in_dir)
-        if let Some(filename) = path.file_name().and_then(|n| n.to_str()) {
-            if filename.starts_with("roadmap-") {
-                continue;
-            }
-        }
+            // Skip template files, and roadmap files (they're loaded separately via
+            // roadmaps_in_dir)
+            filename != "TEMPLATE.md" && !filename.starts_with("roadmap-")
+        })
+        .collect();
 
-        if let Some(goal_document) = GoalDocument::load(&path, &link_path)? {
-            goal_documents.push(goal_document);
-        }
-    }
-    Ok(goal_documents)
+    // Parse the goals in parallel; if several fail, report the first in file order.
+    util::par_map(&files, |(path, link_path)| {
+        GoalDocument::load(path, link_path)
+    })
+    .into_iter()
+    .filter_map(|goal_document| goal_document.transpose())
+    .collect()
 }
 
 /// Load all the roadmaps from a given directory.
@@ -672,14 +1311,55 @@ pub fn roadmaps_in_dir(directory_path: &Path) -> Result<Vec<RoadmapDocument>> {
     Ok(roadmap_documents)
 }
 
-/// Validate that usernames are consistently capitalized across all goals.
-/// GitHub usernames are case-insensitive, so `@BennoLossin` and `@bennolossin`
-/// refer to the same person but would appear as duplicates in aggregated lists.
-pub fn validate_username_consistency(goals: &[GoalDocument]) -> Result<()> {
-    // Map from lowercase username to (set of observed casings, list of files where each appears)
-    let mut seen: std::collections::BTreeMap<
-        String,
-        std::collections::BTreeMap<String, Vec<String>>,
+/// An error found by one of the checks that only look at goal documents (e.g.
+/// [`validate_timespans`]). Unlike [`Error`], whose span only shows up in the rendered
+/// message, it gives access to the span of the offending text, so that editors can
+/// point at it. It converts into an [`Error`] with `?`.
+#[derive(Debug)]
+pub struct ValidationError {
+    /// The offending text, if the error is about a specific part of a goal.
+    pub span: Option<Span>,
+    pub message: String,
+}
+
+impl ValidationError {
+    fn at<T>(value: &Spanned<T>, message: String) -> Self {
+        ValidationError {
+            span: Some(value.span.clone()),
+            message,
+        }
+    }
+
+    fn general(message: String) -> Self {
+        ValidationError {
+            span: None,
+            message,
+        }
+    }
+}
+
+impl From<ValidationError> for Error {
+    #[track_caller]
+    fn from(error: ValidationError) -> Self {
+        match error.span {
+            Some(span) => {
+                Error::str("error reported here").wrap_str(Spanned::new(error.message, span))
+            }
+            None => Error::str(error.message),
+        }
+    }
+}
+
+/// Validate that usernames are consistently capitalized across all goals.
+/// GitHub usernames are case-insensitive, so `@BennoLossin` and `@bennolossin`
+/// refer to the same person but would appear as duplicates in aggregated lists.
+pub fn validate_username_consistency(
+    goals: &[GoalDocument],
+) -> std::result::Result<(), ValidationError> {
+    // Map from lowercase username to (set of observed casings, list of files where each appears)
+    let mut seen: std::collections::BTreeMap<
+        String,
+        std::collections::BTreeMap<String, Vec<String>>,
     > = std::collections::BTreeMap::new();
 
     for goal in goals {
@@ -723,19 +1403,120 @@ pub fn validate_username_consistency(goals: &[GoalDocument]) -> Result<()> {
     }
 
     if !errors.is_empty() {
-        spanned::bail_here!("{}", errors.join("\n"));
+        return Err(ValidationError::general(errors.join("\n")));
+    }
+
+    Ok(())
+}
+
+/// Validate that every sponsor named in a goal's funding table appears in the
+/// sponsor registry in `rust-project-goals.toml`, either by name or by alias.
+pub fn validate_sponsors(goals: &[GoalDocument]) -> std::result::Result<(), ValidationError> {
+    let config = Configuration::get();
+
+    let mut errors = Vec::new();
+    for goal in goals {
+        for item in &goal.funding {
+            for name in item.sponsor_names() {
+                if config.sponsor(name).is_none() {
+                    errors.push(format!(
+                        "unknown sponsor `{}` in {}; add it (or an alias) to `[sponsors]` in `rust-project-goals.toml`",
+                        name,
+                        goal.path.display()
+                    ));
+                }
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(ValidationError::general(errors.join("\n")));
+    }
+
+    Ok(())
+}
+
+/// Validate that every `| Highlight | theme |` and `| Needs | kind |` declared by a goal
+/// is listed in the `[highlights]` or `[needs]` registry of `rust-project-goals.toml`.
+pub fn validate_highlights_and_needs(
+    goals: &[GoalDocument],
+) -> std::result::Result<(), ValidationError> {
+    let config = Configuration::get();
+
+    for goal in goals {
+        validate_vocabulary(
+            &goal.metadata.highlight,
+            &config.highlights,
+            "highlight theme",
+        )?;
+        validate_vocabulary(&goal.metadata.needs, &config.needs, "kind of need")?;
     }
 
     Ok(())
 }
 
+/// Validate that, in every accepted goal, each team asked for support at a level with
+/// `champion_required` in `[support_levels]` of `rust-project-goals.toml` has a
+/// `[team] champion` row. Proposed goals may still be looking for their champions.
+pub fn validate_champions(goals: &[GoalDocument]) -> std::result::Result<(), ValidationError> {
+    for goal in goals {
+        if goal.metadata.status.content != Status::Accepted {
+            continue;
+        }
+        let Some(supports) = goal.team_involvement.as_support() else {
+            continue;
+        };
+        for support in supports {
+            if support.support_level.requires_champion()
+                && !goal.metadata.champions.contains_key(support.team)
+            {
+                return Err(ValidationError::at(
+                    &goal.metadata.table,
+                    format!(
+                        "{}: {} is asked for {} support, which requires a champion; add a `{} champion` row",
+                        goal.path.display(),
+                        support.team,
+                        support.support_level,
+                        support.team,
+                    ),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate that every theme in `themes` is an entry of `vocabulary`.
+fn validate_vocabulary(
+    themes: &Themes,
+    vocabulary: &Vocabulary,
+    what: &str,
+) -> std::result::Result<(), ValidationError> {
+    for theme in themes.iter_spanned() {
+        let theme_name = theme.content.trim();
+        if !vocabulary.contains_key(theme_name) {
+            return Err(ValidationError::at(
+                theme,
+                format!(
+                    "unknown {} `{}`; expected one of:\n{}",
+                    what,
+                    theme_name,
+                    describe_vocabulary(vocabulary),
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
 /// Validate that every `| Roadmap | theme |` declared by a goal has a corresponding
 /// `roadmap-*.md` file whose short title matches. Skipped when no roadmap documents exist
 /// in the directory (e.g. older milestones that used `| Flagship |`).
 pub fn validate_roadmap_references(
     goals: &[GoalDocument],
     roadmaps: &[RoadmapDocument],
-) -> Result<()> {
+) -> std::result::Result<(), ValidationError> {
     if roadmaps.is_empty() {
         return Ok(());
     }
@@ -756,41 +1537,300 @@ pub fn validate_roadmap_references(
 fn validate_themes(
     themes: &Themes,
     roadmap_titles: &std::collections::BTreeSet<String>,
-) -> Result<()> {
+) -> std::result::Result<(), ValidationError> {
     for theme in themes.iter_spanned() {
         let theme_name = theme.content.trim();
         if !roadmap_titles.contains(theme_name) {
-            spanned::bail!(
+            return Err(ValidationError::at(
                 theme,
-                "roadmap `{}` does not match any `roadmap-*.md` short title; \
-                 available roadmaps: {}",
-                theme_name,
-                roadmap_titles
+                format!(
+                    "roadmap `{}` does not match any `roadmap-*.md` short title; \
+                     available roadmaps: {}",
+                    theme_name,
+                    roadmap_titles
+                        .iter()
+                        .cloned()
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Validate the `Depends on` / `Blocks` rows of every goal: each reference must resolve
+/// (to a goal file in the milestone or to a tracking issue) and the resulting
+/// graph must not contain any cycles.
+pub fn validate_dependencies(goals: &[GoalDocument]) -> Result<()> {
+    let goals: Vec<DependencyRows<'_>> = goals.iter().map(DependencyRows::of).collect();
+    check_dependencies(&goals)
+}
+
+fn check_dependencies(goals: &[DependencyRows<'_>]) -> Result<()> {
+    let edges = dependency_edges(goals)?;
+
+    if let Some(cycle) = find_dependency_cycle(&edges) {
+        spanned::bail_here!(
+            "dependency cycle between goals: {}",
+            cycle
+                .iter()
+                .map(|node| node.display(goals))
+                .collect::<Vec<_>>()
+                .join(" → "),
+        );
+    }
+
+    Ok(())
+}
+
+/// A node in the goal dependency graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum DependencyNode<'g> {
+    /// A goal in the milestone (index into the goal slice).
+    Goal(usize),
+
+    /// A tracking issue that does not belong to any goal in the milestone.
+    Issue(&'g IssueId),
+}
+
+impl DependencyNode<'_> {
+    fn display(&self, goals: &[DependencyRows<'_>]) -> String {
+        match self {
+            DependencyNode::Goal(index) => goals[*index].link_path.display().to_string(),
+            DependencyNode::Issue(issue_id) => issue_id.to_string(),
+        }
+    }
+}
+
+/// The parts of a goal that make up its dependencies.
+struct DependencyRows<'g> {
+    link_path: &'g Path,
+    title: &'g Spanned<String>,
+    tracking_issue: Option<&'g IssueId>,
+    depends_on: &'g [GoalReference],
+    blocks: &'g [GoalReference],
+}
+
+impl<'g> DependencyRows<'g> {
+    fn of(goal: &'g GoalDocument) -> Self {
+        DependencyRows {
+            link_path: &goal.link_path,
+            title: &goal.metadata.title,
+            tracking_issue: goal.metadata.tracking_issue.as_ref(),
+            depends_on: &goal.metadata.depends_on,
+            blocks: &goal.metadata.blocks,
+        }
+    }
+}
+
+/// Resolve the `Depends on` / `Blocks` rows of `goals` into a set of edges.
+/// An edge `(a, b)` means that `a` must land before `b`.
+fn dependency_edges<'g>(
+    goals: &[DependencyRows<'g>],
+) -> Result<BTreeSet<(DependencyNode<'g>, DependencyNode<'g>)>> {
+    let resolve = |reference: &'g GoalReference| -> Result<DependencyNode<'g>> {
+        match reference {
+            GoalReference::File(file) => {
+                let Some(index) = goals
                     .iter()
-                    .cloned()
-                    .collect::<Vec<_>>()
-                    .join(", "),
-            );
+                    .position(|g| g.link_path == Path::new(&file.content))
+                else {
+                    spanned::bail!(file, "no goal file `{}` in this milestone", file.content);
+                };
+                Ok(DependencyNode::Goal(index))
+            }
+            GoalReference::Issue(issue_id) => Ok(goals
+                .iter()
+                .position(|g| g.tracking_issue == Some(&issue_id.content))
+                .map(DependencyNode::Goal)
+                .unwrap_or(DependencyNode::Issue(&issue_id.content))),
+        }
+    };
+
+    let mut edges = BTreeSet::new();
+    for (index, goal) in goals.iter().enumerate() {
+        let this = DependencyNode::Goal(index);
+
+        for reference in goal.depends_on {
+            let other = resolve(reference)?;
+            if other == this {
+                spanned::bail!(goal.title, "goal depends on itself");
+            }
+            edges.insert((other, this));
+        }
+
+        for reference in goal.blocks {
+            let other = resolve(reference)?;
+            if other == this {
+                spanned::bail!(goal.title, "goal blocks itself");
+            }
+            edges.insert((this, other));
+        }
+    }
+
+    Ok(edges)
+}
+
+/// Returns the nodes of a cycle in `edges` (first node repeated at the end), if any.
+fn find_dependency_cycle<'g>(
+    edges: &BTreeSet<(DependencyNode<'g>, DependencyNode<'g>)>,
+) -> Option<Vec<DependencyNode<'g>>> {
+    let mut successors: BTreeMap<DependencyNode<'g>, Vec<DependencyNode<'g>>> = BTreeMap::new();
+    for &(from, to) in edges {
+        successors.entry(from).or_default().push(to);
+    }
+
+    fn visit<'g>(
+        node: DependencyNode<'g>,
+        successors: &BTreeMap<DependencyNode<'g>, Vec<DependencyNode<'g>>>,
+        finished: &mut BTreeSet<DependencyNode<'g>>,
+        path: &mut Vec<DependencyNode<'g>>,
+    ) -> Option<Vec<DependencyNode<'g>>> {
+        if let Some(start) = path.iter().position(|n| *n == node) {
+            let mut cycle = path[start..].to_vec();
+            cycle.push(node);
+            return Some(cycle);
+        }
+
+        if finished.contains(&node) {
+            return None;
+        }
+
+        path.push(node);
+        for &next in successors.get(&node).into_iter().flatten() {
+            if let Some(cycle) = visit(next, successors, finished, path) {
+                return Some(cycle);
+            }
+        }
+        path.pop();
+        finished.insert(node);
+        None
+    }
+
+    let mut finished = BTreeSet::new();
+    for &node in successors.keys() {
+        if let Some(cycle) = visit(node, &successors, &mut finished, &mut vec![]) {
+            return Some(cycle);
         }
     }
+    None
+}
+
+/// Validate the `Timespan` rows of every goal: a goal's timespan must overlap its milestone,
+/// and each subgoal's timespan must fall within the timespan of the goal.
+pub fn validate_timespans(goals: &[GoalDocument]) -> std::result::Result<(), ValidationError> {
+    for goal in goals {
+        let milestone = Timespan::from_milestone(goal.milestone());
+
+        if let (Some(timespan), Some(milestone)) = (&goal.metadata.timespan, milestone) {
+            if !timespan.overlaps(&milestone) {
+                return Err(ValidationError::at(
+                    timespan,
+                    format!(
+                        "timespan `{}` does not overlap the `{}` milestone",
+                        timespan.content, milestone,
+                    ),
+                ));
+            }
+        }
+
+        let Some(goal_timespan) = goal.timespan() else {
+            continue;
+        };
+
+        for child in &goal.task_tree.children {
+            let Some(child_timespan) = &child.timespan else {
+                continue;
+            };
+
+            if !goal_timespan.contains(child_timespan) {
+                return Err(ValidationError::at(
+                    child_timespan,
+                    format!(
+                        "timespan `{}` of `{}` is not within the goal's timespan `{}`",
+                        child_timespan.content, child.title.content, goal_timespan,
+                    ),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Compare the progress recorded in the `Status` column of each goal's work items
+/// against the checkboxes of its tracking issue, reporting every goal where they disagree.
+/// Goals without task statuses, and tracking issues without checkboxes, are skipped.
+pub fn validate_progress_against_issues(
+    goals: &[GoalDocument],
+    issues: &[ExistingGithubIssue],
+) -> Result<()> {
+    let mut errors = Vec::new();
+
+    for goal in goals {
+        let (Some(progress), Some(issue_id)) = (goal.progress(), &goal.metadata.tracking_issue)
+        else {
+            continue;
+        };
+
+        let Some(issue) = issues.iter().find(|i| i.number == issue_id.number) else {
+            continue;
+        };
+
+        if let Progress::Tracked { completed, total } = checkboxes(issue, &[]) {
+            if (completed, total) != (progress.done, progress.total) {
+                errors.push(format!(
+                    "{}: document has {}/{} tasks done but tracking issue {} has {}/{} checked",
+                    goal.path.display(),
+                    progress.done,
+                    progress.total,
+                    issue_id,
+                    completed,
+                    total,
+                ));
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        spanned::bail_here!("{}", errors.join("\n"));
+    }
+
     Ok(())
 }
 
 impl GoalDocument {
-    fn load(path: &Path, link_path: &Path) -> Result<Option<Self>> {
+    /// Load the goal document at `path`; `link_path` is its path relative to the milestone
+    /// directory. Returns `None` if the file has no metadata table (i.e., is not a goal).
+    pub fn load(path: &Path, link_path: &Path) -> Result<Option<Self>> {
         let sections = markwaydown::parse(path)?;
+        Self::from_sections(path, link_path, &sections)
+    }
 
-        let Some(metadata) = extract_metadata(&sections)? else {
+    /// Like [`GoalDocument::load`], but parses `text` (e.g., the unsaved contents of an
+    /// editor buffer) instead of reading the file at `path`.
+    pub fn load_text(path: &Path, link_path: &Path, text: &str) -> Result<Option<Self>> {
+        let span = Span {
+            file: path.display().to_string().into(),
+            bytes: 0..text.len(),
+        };
+        let sections = markwaydown::parse_text(Spanned::new(text, span))?;
+        Self::from_sections(path, link_path, &sections)
+    }
+
+    fn from_sections(path: &Path, link_path: &Path, sections: &[Section]) -> Result<Option<Self>> {
+        let Some(metadata) = extract_metadata(sections)? else {
             return Ok(None);
         };
 
-        let summary = extract_summary(&sections)?;
+        let summary = extract_summary(sections)?;
 
         let link_path = Arc::new(link_path.to_path_buf());
 
         // Try to extract team involvement - could be old format or new format
         let (team_involvement, goal_plans, mut task_owners) =
-            extract_team_involvement(&sections, &link_path, &metadata)?;
+            extract_team_involvement(sections, &link_path, &metadata)?;
 
         // Enforce that every goal has some team involvement (unless it is not accepted)
         if metadata.status.is_not_not_accepted() && team_involvement.is_empty() {
@@ -801,16 +1841,16 @@ impl GoalDocument {
         }
 
         // Extract task tree from "Work items over the next year"
-        let task_tree = extract_task_tree(&sections, &metadata)?;
+        let task_tree = extract_task_tree(sections, &metadata)?;
 
         // Compute task_owners from the task tree
         task_owners.extend(task_tree.all_task_owners());
 
         // Extract funding items from the `## Funding` section (if present)
-        let funding = extract_funding(&sections)?;
+        let funding = extract_funding(sections)?;
 
         // Extract help wanted items from the `## Help wanted` section (if present)
-        let help_wanted = extract_help_wanted(&sections)?;
+        let help_wanted = extract_help_wanted(sections)?;
 
         Ok(Some(GoalDocument {
             path: path.to_path_buf(),
@@ -853,14 +1893,16 @@ impl GoalDocument {
     }
 
     pub fn sponsors_display(&self) -> String {
-        let sponsors: Vec<&str> = self
-            .funding
-            .iter()
-            .filter_map(|f| f.sponsors.as_deref())
-            .collect();
+        let mut sponsors: Vec<&str> = vec![];
+        for name in self.funding.iter().flat_map(|f| f.canonical_sponsors()) {
+            if !sponsors.contains(&name) {
+                sponsors.push(name);
+            }
+        }
         if sponsors.is_empty() {
             String::new()
         } else {
+            let sponsors: Vec<String> = sponsors.iter().map(|s| format_sponsor(s)).collect();
             format!("\u{1f64f} {}", sponsors.join(", "))
         }
     }
@@ -914,6 +1956,32 @@ impl GoalDocument {
     pub fn all_roadmaps(&self) -> Themes {
         self.task_tree.all_roadmap_themes()
     }
+
+    /// Progress rolled up from the `Status` column of the work item tables, if any.
+    pub fn progress(&self) -> Option<TaskProgress> {
+        self.task_tree.progress()
+    }
+
+    /// The name of the directory in which the goal document is located.
+    /// That is our "milestone" directory (e.g., 2024h2).
+    pub fn milestone(&self) -> &str {
+        self.path
+            .parent()
+            .unwrap()
+            .file_stem()
+            .unwrap()
+            .to_str()
+            .unwrap()
+    }
+
+    /// The timespan of this goal: the `Timespan` metadata row if present, else the milestone.
+    /// Returns `None` only if neither is present nor parseable.
+    pub fn timespan(&self) -> Option<Timespan> {
+        match &self.metadata.timespan {
+            Some(timespan) => Some(timespan.content),
+            None => Timespan::from_milestone(self.milestone()),
+        }
+    }
 }
 
 /// Convert a heading title to an mdbook-compatible anchor slug.
@@ -933,31 +2001,50 @@ fn slugify(title: &str) -> String {
 /// Generate progress HTML based on issue progress and state
 fn generate_progress_html(progress: &Progress, state: &GithubIssueState) -> String {
     match (progress, state) {
-        (Progress::Tracked { completed, total }, GithubIssueState::Closed) if completed == total => {
-            r#"<img src="https://img.shields.io/badge/Completed!%20%3D%29-green" alt="Completed">"#.to_string()
-        },
-        (Progress::Tracked { completed: _, total: _ }, GithubIssueState::Closed) => {
-            r#"<img src="https://img.shields.io/badge/Will%20not%20complete%20%3A%28-yellow" alt="Incomplete">"#.to_string()
-        },
-        (Progress::Tracked { completed, total }, _) => {
-            format!(r#"<progress value="{}" max="{}">{}/{}</progress>"#, completed, total, completed, total)
-        },
-        (Progress::Binary { is_closed: true }, _) => {
-            r#"<img src="https://img.shields.io/badge/Completed!%20%3D%29-green" alt="Completed">"#.to_string()
-        },
-        (Progress::Binary { is_closed: false }, _) => {
-            r#"<progress value="0" max="1">0/1</progress>"#.to_string()
-        },
+        (Progress::Tracked { completed, total }, GithubIssueState::Closed)
+            if completed == total =>
+        {
+            badge::html("Completed")
+        }
+        (Progress::Tracked { .. }, GithubIssueState::Closed) => badge::html("Will not complete"),
+        (Progress::Tracked { completed, total }, _) => badge::progress_html(*completed, *total),
+        (Progress::Binary { is_closed: true }, _) => badge::html("Completed"),
+        (Progress::Binary { is_closed: false }, _) => badge::progress_html(0, 1),
         (Progress::Error { message }, _) => {
             format!(r#"<span title="{}">⚠️</span>"#, message)
         }
     }
 }
 
+/// Plain text version of [`generate_progress_html`].
+fn progress_text(progress: &Progress, state: &GithubIssueState) -> String {
+    match (progress, state) {
+        (Progress::Tracked { completed, total }, GithubIssueState::Closed)
+            if completed == total =>
+        {
+            "Completed".to_string()
+        }
+        (Progress::Tracked { .. }, GithubIssueState::Closed) => "Will not complete".to_string(),
+        (Progress::Tracked { completed, total }, _) => format!("{completed}/{total}"),
+        (Progress::Binary { is_closed: true }, _) => "Completed".to_string(),
+        (Progress::Binary { is_closed: false }, _) => "0/1".to_string(),
+        (Progress::Error { message }, _) => message.clone(),
+    }
+}
+
 pub fn format_goal_table(
     goals: &[&GoalDocument],
     milestone_issues: Option<&[ExistingGithubIssue]>,
 ) -> Result<String> {
+    Ok(goal_table(goals, milestone_issues).to_markdown())
+}
+
+/// The table of goals with their point of contact and either their progress or,
+/// if some are not yet accepted, their task owners and champions.
+pub fn goal_table(
+    goals: &[&GoalDocument],
+    milestone_issues: Option<&[ExistingGithubIssue]>,
+) -> table::Table {
     // If any of the goals have tracking issues, include those in the table.
     let show_champions = goals.iter().any(|g| {
         *g.metadata.status == Status::Proposed || *g.metadata.status == Status::NotAccepted
@@ -966,23 +2053,10 @@ pub fn format_goal_table(
     let mut table;
 
     if !show_champions {
-        table = vec![vec![
-            Spanned::here("Goal".to_string()),
-            Spanned::here("Point of contact".to_string()),
-            Spanned::here("Progress".to_string()),
-        ]];
+        table = table::Table::new(["Goal", "Point of contact", "Progress"]);
 
         for goal in goals {
-            // Find the directory in which the goal document is located.
-            // That is our "milestone" directory (e.g., 2024h2).
-            let milestone: &str = goal
-                .path
-                .parent()
-                .unwrap()
-                .file_stem()
-                .unwrap()
-                .to_str()
-                .unwrap();
+            let milestone = goal.milestone();
 
             let progress_bar = match &goal.metadata.tracking_issue {
                 Some(
@@ -992,44 +2066,49 @@ pub fn format_goal_table(
                     },
                 ) => {
                     // Find the matching issue in milestone_issues and generate progress HTML
-                    let progress_html = if let Some(issues) = milestone_issues {
+                    let (progress_html, text) = if let Some(issues) = milestone_issues {
                         if let Some(issue) = issues.iter().find(|issue| issue.number == *number) {
-                            let progress = checkboxes(&issue);
-                            generate_progress_html(&progress, &issue.state)
+                            let progress = checkboxes(issue, &goal.metadata.other_tracking_issues);
+                            (
+                                generate_progress_html(&progress, &issue.state),
+                                progress_text(&progress, &issue.state),
+                            )
                         } else {
                             // Issue not found - might be in different milestone or not exist
-                            r#"<span title="Issue not found in milestone">⚠️</span>"#.to_string()
+                            (
+                                r#"<span title="Issue not found in milestone">⚠️</span>"#
+                                    .to_string(),
+                                "Issue not found in milestone".to_string(),
+                            )
                         }
                     } else {
                         // No milestone issues provided - fall back to empty div for now
-                        format!("<div class='tracking-issue-progress' id='{milestone}:{org}:{repo}:{number}'></div>")
+                        (
+                            format!("<div class='tracking-issue-progress' id='{milestone}:{org}:{repo}:{number}'></div>"),
+                            issue_id.url(),
+                        )
                     };
 
-                    format!(
-                        "<a href='{url}' alt='Tracking issue'>{progress_html}</a>",
-                        url = issue_id.url(),
-                        progress_html = progress_html
-                    )
+                    Cell::Html {
+                        html: format!(
+                            "<a href='{url}' alt='Tracking issue'>{progress_html}</a>",
+                            url = issue_id.url(),
+                            progress_html = progress_html
+                        ),
+                        text,
+                    }
                 }
-                None => "(no tracking issue)".to_string(),
+                None => Cell::text("(no tracking issue)"),
             };
 
-            table.push(vec![
-                Spanned::here(format!(
-                    "[{}]({})",
-                    *goal.metadata.title,
-                    goal.link_path.display()
-                )),
-                Spanned::here(goal.point_of_contact_for_goal_list()),
-                Spanned::here(progress_bar),
+            table.push_row(vec![
+                Cell::link(&*goal.metadata.title, goal.link_path.display().to_string()),
+                Cell::markdown(goal.point_of_contact_for_goal_list()),
+                progress_bar,
             ]);
         }
     } else {
-        table = vec![vec![
-            Spanned::here("Goal".to_string()),
-            Spanned::here("Point of contact".to_string()),
-            Spanned::here("Task Owners and Champions".to_string()),
-        ]];
+        table = table::Table::new(["Goal", "Point of contact", "Task Owners and Champions"]);
 
         for goal in goals {
             // Collect task owners, excluding those who are already the POC
@@ -1051,18 +2130,14 @@ pub fn format_goal_table(
             // Combine task owners and champions
             contributors.append(&mut champions);
 
-            table.push(vec![
-                Spanned::here(format!(
-                    "[{}]({})",
-                    *goal.metadata.title,
-                    goal.link_path.display()
-                )),
-                Spanned::here(goal.point_of_contact_for_goal_list()),
-                Spanned::here(contributors.join(", ")),
+            table.push_row(vec![
+                Cell::link(&*goal.metadata.title, goal.link_path.display().to_string()),
+                Cell::markdown(goal.point_of_contact_for_goal_list()),
+                Cell::markdown(contributors.join(", ")),
             ]);
         }
     }
-    Ok(util::format_table(&table))
+    table
 }
 
 /// Format highlight goals as sections with people and summary at the given heading level.
@@ -1116,13 +2191,11 @@ pub fn format_highlight_goal_sections(
 /// Format a summary table of goals needing funding.
 /// Columns: status emoji, Goal, Cost, Sponsor.
 pub fn format_funding_table(goals: &[&GoalDocument]) -> String {
-    let mut output = String::new();
-    output.push_str("| | Goal | Cost | Funding contact | Sponsor(s) |\n");
-    output.push_str("| --- | --- | --- | --- | --- |\n");
+    let mut table = funding_table();
     for goal in goals {
-        format_funding_table_row(&mut output, goal);
+        table.push_row(funding_table_row(goal));
     }
-    output
+    table.to_markdown()
 }
 
 /// Format a summary table of goals needing funding, grouped by roadmap.
@@ -1132,9 +2205,7 @@ pub fn format_funding_table_grouped(
     goals: &[&GoalDocument],
     roadmaps: &[&RoadmapDocument],
 ) -> String {
-    let mut output = String::new();
-    output.push_str("| | Goal | Cost | Funding contact | Sponsor(s) |\n");
-    output.push_str("| --- | --- | --- | --- | --- |\n");
+    let mut table = funding_table();
 
     let mut used: Vec<bool> = vec![false; goals.len()];
 
@@ -1155,37 +2226,30 @@ pub fn format_funding_table_grouped(
             continue;
         }
 
-        // Emit roadmap header row
-        output.push_str(&format!(
-            "| - | **[{}]({})** | -- | -- | -- |\n",
-            theme,
-            roadmap.link_path.display(),
-        ));
+        table.push_group(Cell::link(theme, roadmap.link_path.display().to_string()));
 
         for i in &matching {
             used[*i] = true;
-            format_funding_table_row(&mut output, goals[*i]);
+            table.push_row(funding_table_row(goals[*i]));
         }
     }
 
-    // Emit "Other goals" for anything not in a roadmap
+    // Group "Other goals" for anything not in a roadmap
     let other: Vec<usize> = (0..goals.len()).filter(|i| !used[*i]).collect();
     if !other.is_empty() {
-        output.push_str("| - | **Other goals** | -- | -- | -- |\n");
+        table.push_group("Other goals");
         for i in &other {
-            format_funding_table_row(&mut output, goals[*i]);
+            table.push_row(funding_table_row(goals[*i]));
         }
     }
 
-    output
+    table.to_markdown()
 }
 
 /// Format a summary table of goals needing funding, grouped by funding point of contact.
 /// Each distinct POC gets a bold header row. Goals are listed underneath their POC.
 pub fn format_funding_table_grouped_by_poc(goals: &[&GoalDocument]) -> String {
-    let mut output = String::new();
-    output.push_str("| | Goal | Cost | Funding contact | Sponsor(s) |\n");
-    output.push_str("| --- | --- | --- | --- | --- |\n");
+    let mut table = funding_table();
 
     // Group goals by their funding POC
     let mut by_poc: BTreeMap<String, Vec<&GoalDocument>> = BTreeMap::new();
@@ -1194,27 +2258,113 @@ pub fn format_funding_table_grouped_by_poc(goals: &[&GoalDocument]) -> String {
         by_poc.entry(poc).or_default().push(goal);
     }
 
-    for (poc, poc_goals) in &by_poc {
-        output.push_str(&format!("| - | **{}** | -- | -- | -- |\n", poc));
+    for (poc, poc_goals) in by_poc {
+        table.push_group(Cell::markdown(poc));
 
         for goal in poc_goals {
-            format_funding_table_row(&mut output, goal);
+            table.push_row(funding_table_row(goal));
         }
     }
 
+    table.to_markdown()
+}
+
+/// Format the sponsor acknowledgement list: for each sponsor in the registry, the funding
+/// items they sponsor across the given `(milestone, goal)` pairs, followed by the total cost
+/// of those items. Links are relative to `link_root`, the path from the current chapter
+/// to the book's `src` directory.
+pub fn format_sponsors(goals: &[(&str, &GoalDocument)], link_root: &Path) -> String {
+    let mut output = String::new();
+
+    for (name, details) in &Configuration::get().sponsors {
+        let mut table = table::Table::new(["Milestone", "Goal", "Purpose", "Cost", "Funded"]);
+        let mut costs = vec![];
+        let mut funded_goals = BTreeSet::new();
+        for (milestone, goal) in goals {
+            for item in &goal.funding {
+                if !item.canonical_sponsors().contains(&name.as_str()) {
+                    continue;
+                }
+                funded_goals.insert((*milestone, goal.path.as_path()));
+                costs.push(item.cost.clone());
+                table.push_row(vec![
+                    Cell::text(*milestone),
+                    Cell::link(
+                        &*goal.metadata.title,
+                        link_root
+                            .join(milestone)
+                            .join(&*goal.link_path)
+                            .display()
+                            .to_string(),
+                    ),
+                    Cell::markdown(&item.purpose),
+                    Cell::text(item.cost.display_normalized()),
+                    Cell::badge(item.status.badge()),
+                ]);
+            }
+        }
+
+        if funded_goals.is_empty() {
+            continue;
+        }
+
+        output.push_str(&format!("### {}\n\n", format_sponsor(name)));
+        if let Some(logo) = &details.logo {
+            output.push_str(&format!(
+                "<img src=\"{}\" alt=\"{} logo\" height=\"48\">\n\n",
+                link_root.join(logo).display(),
+                name
+            ));
+        }
+        output.push_str(&table.to_markdown());
+        output.push('\n');
+
+        let total = sum_funding_costs(&costs)
+            .map(|c| c.display_normalized())
+            .unwrap_or_default();
+        output.push_str(&format!(
+            "**Total:** {} across {} goal{}\n\n",
+            total,
+            funded_goals.len(),
+            if funded_goals.len() == 1 { "" } else { "s" },
+        ));
+    }
+
+    if output.is_empty() {
+        output.push_str("*No sponsored goals yet.*\n");
+    }
+
     output
 }
 
 pub fn format_funding_legend() -> String {
-    format!(
-        "| Legend | |\n| --- | --- |\n| {} | Seeking funding |\n| {} | Partially funded, could use more support |\n| {} | Good to go |\n",
-        FundingStatus::No.emoji(),
-        FundingStatus::Partial(None).emoji(),
-        FundingStatus::Funded(None).emoji(),
-    )
+    let mut table = table::Table::new(["Legend", ""]);
+    for (status, meaning) in [
+        (FundingStatus::No, "Seeking funding"),
+        (
+            FundingStatus::Partial(None),
+            "Partially funded, could use more support",
+        ),
+        (FundingStatus::Funded(None), "Good to go"),
+    ] {
+        table.push_row(vec![Cell::badge(status.badge()), Cell::text(meaning)]);
+    }
+    table.to_markdown()
 }
 
-fn format_funding_table_row(output: &mut String, goal: &GoalDocument) {
+/// An empty table of goals needing funding, see [`funding_table_row`].
+fn funding_table() -> table::Table {
+    let mut table = table::Table::new(["", "Goal", "Cost", "Funding contact", "Sponsor(s)"]);
+    // Group labels go in the "Goal" column, with placeholders in the others
+    table.group_column = 1;
+    table.group_cells = ["-", "", "--", "--", "--"]
+        .into_iter()
+        .map(Cell::from)
+        .collect();
+    table
+}
+
+fn funding_table_row(goal: &GoalDocument) -> Vec<Cell> {
     let total = sum_funding_costs(
         &goal
             .funding
@@ -1222,17 +2372,18 @@ fn format_funding_table_row(output: &mut String, goal: &GoalDocument) {
             .map(|f| f.cost.clone())
             .collect::<Vec<_>>(),
     );
-    let cost_str = total.map(|c| c.display()).unwrap_or_default();
+    let cost_str = total.map(|c| c.display_normalized()).unwrap_or_default();
     let status = goal.funding_status();
-    output.push_str(&format!(
-        "| {} | [{}]({}#funding) | {} | {} | {} |\n",
-        status.emoji(),
-        *goal.metadata.title,
-        goal.link_path.display(),
-        cost_str,
-        goal.funding_point_of_contact(),
-        goal.sponsors_display(),
-    ));
+    vec![
+        Cell::badge(status.badge()),
+        Cell::link(
+            &*goal.metadata.title,
+            format!("{}#funding", goal.link_path.display()),
+        ),
+        Cell::text(cost_str),
+        Cell::markdown(goal.funding_point_of_contact()),
+        Cell::markdown(goal.sponsors_display()),
+    ]
 }
 
 /// Format goals that need funding as sections with people, summary, and funding table.
@@ -1274,16 +2425,15 @@ pub fn format_funding_goal_sections(
                 "**Contact:** {}\n\n",
                 goal.funding_point_of_contact()
             ));
-            output.push_str("| Purpose | Cost | Funded |\n");
-            output.push_str("|---------|------|--------|\n");
+            let mut table = table::Table::new(["Purpose", "Cost", "Funded"]);
             for item in &goal.funding {
-                output.push_str(&format!(
-                    "| {} | {} | {} |\n",
-                    item.purpose,
-                    item.cost.display(),
-                    item.status.display()
-                ));
+                table.push_row(vec![
+                    Cell::markdown(&item.purpose),
+                    Cell::text(item.cost.display_normalized()),
+                    Cell::badge(item.status.badge()),
+                ]);
             }
+            output.push_str(&table.to_markdown());
             output.push('\n');
         }
     }
@@ -1326,14 +2476,15 @@ pub fn format_help_wanted_goal_sections(
 
         // Render help wanted table
         if !goal.help_wanted.is_empty() {
-            output.push_str("| Task | Experience level | Time investment |\n");
-            output.push_str("|------|-----------------|------------------|\n");
+            let mut table = table::Table::new(["Task", "Experience level", "Time investment"]);
             for item in &goal.help_wanted {
-                output.push_str(&format!(
-                    "| {} | {} | {} |\n",
-                    item.task, item.experience_level, item.time_investment
-                ));
+                table.push_row(vec![
+                    Cell::markdown(&item.task),
+                    Cell::text(item.experience_level.to_string()),
+                    Cell::text(item.time_investment.to_string()),
+                ]);
             }
+            output.push_str(&table.to_markdown());
             output.push('\n');
         }
     }
@@ -1345,19 +2496,15 @@ pub fn format_help_wanted_goal_sections(
 /// When a goal has subgoals (task tree children), emits one row per child
 /// instead of one row for the goal itself.
 pub fn format_highlight_table(goals: &[&GoalDocument]) -> String {
-    let mut output = String::new();
-    output.push_str("| Goal | What and why |\n");
-    output.push_str("| --- | --- |\n");
+    let mut table = table::Table::new(["Goal", "What and why"]);
     for goal in goals {
         let children = &goal.task_tree.children;
 
         if children.is_empty() {
-            output.push_str(&format!(
-                "| [{}]({}) | {} |\n",
-                *goal.metadata.title,
-                goal.link_path.display(),
-                goal.what_and_why(),
-            ));
+            table.push_row(vec![
+                Cell::link(&*goal.metadata.title, goal.link_path.display().to_string()),
+                Cell::markdown(goal.what_and_why()),
+            ]);
         } else {
             for child in children {
                 let what_and_why = child
@@ -1365,18 +2512,18 @@ pub fn format_highlight_table(goals: &[&GoalDocument]) -> String {
                     .clone()
                     .unwrap_or_else(|| goal.what_and_why());
                 let anchor = slugify(&child.title);
-                output.push_str(&format!(
-                    "| [{}]({}#{}) | {} |\n",
-                    *child.title,
-                    goal.link_path.display(),
-                    anchor,
-                    what_and_why,
-                ));
+                table.push_row(vec![
+                    Cell::link(
+                        &*child.title,
+                        format!("{}#{}", goal.link_path.display(), anchor),
+                    ),
+                    Cell::markdown(what_and_why),
+                ]);
             }
         }
     }
 
-    output
+    table.to_markdown()
 }
 
 /// Format a credits line listing all people involved in the given goals.
@@ -1409,28 +2556,23 @@ pub fn format_highlight_credits(goals: &[&GoalDocument]) -> String {
 
 /// Format roadmaps as a table with "Roadmap", "Point of contact", and "What and why" columns.
 pub fn format_roadmap_table(roadmaps: &[&RoadmapDocument]) -> Result<String> {
-    let mut table = vec![vec![
-        Spanned::here("Roadmap".to_string()),
-        Spanned::here("Point of contact".to_string()),
-        Spanned::here("What and why".to_string()),
-    ]];
+    let mut table = table::Table::new(["Roadmap", "Point of contact", "What and why"]);
 
     let mut sorted_roadmaps: Vec<&&RoadmapDocument> = roadmaps.iter().collect();
     sorted_roadmaps.sort_by_key(|r| r.short_title.to_lowercase());
 
     for roadmap in sorted_roadmaps {
-        table.push(vec![
-            Spanned::here(format!(
-                "[{}]({})",
-                *roadmap.short_title,
-                roadmap.link_path.display()
-            )),
-            Spanned::here(roadmap.point_of_contact.clone()),
-            Spanned::here(roadmap.what_and_why.clone()),
+        table.push_row(vec![
+            Cell::link(
+                &*roadmap.short_title,
+                roadmap.link_path.display().to_string(),
+            ),
+            Cell::markdown(&roadmap.point_of_contact),
+            Cell::markdown(&roadmap.what_and_why),
         ]);
     }
 
-    Ok(util::format_table(&table))
+    Ok(table.to_markdown())
 }
 
 /// Format matching goals as markdown table rows (no headers, no separator).
@@ -1442,52 +2584,190 @@ pub fn format_roadmap_table(roadmaps: &[&RoadmapDocument]) -> Result<String> {
 /// When a goal's task tree has children, emits one row per child that matches
 /// `filter_theme` instead of one row for the goal.
 pub fn format_roadmap_goal_rows(goals: &[&GoalDocument], filter_theme: &str) -> String {
-    let mut output = String::new();
+    let mut table = table::Table::new(["Goal", "Timespan", "What and why"]);
     for goal in goals {
-        let milestone_dir = goal
-            .path
-            .parent()
-            .unwrap()
-            .file_stem()
-            .unwrap()
-            .to_str()
-            .unwrap();
+        let milestone_dir = goal.milestone();
+        let format_timespan = |timespan: &Option<Spanned<Timespan>>| match timespan {
+            Some(timespan) => timespan.content.to_string(),
+            None => milestone_dir.to_string(),
+        };
 
         let children = &goal.task_tree.children;
 
         if children.is_empty() {
             // No children: emit one row for the goal
-            let timespan = goal.metadata.timespan.as_deref().unwrap_or(milestone_dir);
+            table.push_row(vec![
+                Cell::link(&*goal.metadata.title, goal.link_path.display().to_string())
+                    .and(progress_cell(goal.progress())),
+                Cell::text(format_timespan(&goal.metadata.timespan)),
+                Cell::markdown(goal.what_and_why()),
+            ]);
+        } else {
+            // Has children: emit one row per child that matches the theme
+            for child in children {
+                if !child.roadmap.contains(filter_theme) {
+                    continue;
+                }
+                let what_and_why = child
+                    .what_and_why
+                    .clone()
+                    .unwrap_or_else(|| goal.what_and_why());
+                let anchor = slugify(&child.title);
+                table.push_row(vec![
+                    Cell::link(
+                        &*child.title,
+                        format!("{}#{}", goal.link_path.display(), anchor),
+                    )
+                    .and(progress_cell(child.progress())),
+                    Cell::text(format_timespan(&child.timespan)),
+                    Cell::markdown(what_and_why),
+                ]);
+            }
+        }
+    }
+
+    // The rows go into the directive's table, so drop our header and separator.
+    let mut output = String::new();
+    for line in table.to_markdown().lines().skip(2) {
+        output.push_str(line);
+        output.push('\n');
+    }
+    output
+}
+
+/// Format the dependencies between goals as a Mermaid flowchart.
+/// An arrow `A --> B` means that `A` must land before `B`.
+///
+/// Edges touching goals that were not accepted are left out. When `filter_theme`
+/// is given, only edges touching a goal that matches that roadmap theme are shown.
+pub fn format_dependency_graph(
+    goals: &[&GoalDocument],
+    filter_theme: Option<&str>,
+) -> Result<String> {
+    let rows: Vec<DependencyRows<'_>> = goals.iter().map(|goal| DependencyRows::of(goal)).collect();
+    let edges = dependency_edges(&rows)?;
+
+    let matches_filter = |node: &DependencyNode<'_>| match (node, filter_theme) {
+        (_, None) => true,
+        (DependencyNode::Goal(index), Some(theme)) => goals[*index].matches_roadmap_theme(theme),
+        (DependencyNode::Issue(_), Some(_)) => false,
+    };
+
+    let is_not_not_accepted = |node: &DependencyNode<'_>| match node {
+        DependencyNode::Goal(index) => goals[*index].is_not_not_accepted(),
+        DependencyNode::Issue(_) => true,
+    };
+
+    let edges: Vec<_> = edges
+        .into_iter()
+        .filter(|(from, to)| is_not_not_accepted(from) && is_not_not_accepted(to))
+        .filter(|(from, to)| matches_filter(from) || matches_filter(to))
+        .collect();
+
+    if edges.is_empty() {
+        return Ok("*No dependencies between goals.*\n".to_string());
+    }
+
+    let nodes: BTreeSet<DependencyNode<'_>> =
+        edges.iter().flat_map(|&(from, to)| [from, to]).collect();
+    let node_id =
+        |node: &DependencyNode<'_>| format!("n{}", nodes.iter().position(|n| n == node).unwrap());
+
+    let mut output = String::new();
+    output.push_str("```mermaid\nflowchart LR\n");
+    for node in &nodes {
+        let id = node_id(node);
+        match node {
+            DependencyNode::Goal(index) => {
+                let goal = goals[*index];
+                output.push_str(&format!(
+                    "  {id}[\"{}\"]\n",
+                    goal.metadata.short_title.replace('"', "#quot;")
+                ));
+                output.push_str(&format!(
+                    "  click {id} \"{}\"\n",
+                    goal.link_path.with_extension("html").display()
+                ));
+            }
+            DependencyNode::Issue(issue_id) => {
+                output.push_str(&format!(
+                    "  {id}([\"{}/{}#{}\"])\n",
+                    issue_id.repository.org, issue_id.repository.repo, issue_id.number
+                ));
+                output.push_str(&format!("  click {id} \"{}\"\n", issue_id.url()));
+            }
+        }
+    }
+    for (from, to) in &edges {
+        output.push_str(&format!("  {} --> {}\n", node_id(from), node_id(to)));
+    }
+    output.push_str("```\n");
+
+    Ok(output)
+}
+
+/// Progress bar to show after a goal link in a table cell (empty if there is no progress).
+fn progress_cell(progress: Option<TaskProgress>) -> Cell {
+    match progress {
+        Some(progress) => Cell::Html {
+            html: progress.html(),
+            text: format!("{}/{}", progress.done, progress.total),
+        },
+        None => Cell::Empty,
+    }
+}
+
+/// Format matching goals as a Mermaid Gantt chart, one section per goal.
+/// Used by the `(((ROADMAP GANTT: ...)))` directive.
+///
+/// When a goal's task tree has children, emits one bar per child that matches
+/// `filter_theme` instead of one bar for the goal. Goals and subgoals without a
+/// timespan use the timespan of their milestone.
+pub fn format_roadmap_gantt(goals: &[&GoalDocument], filter_theme: &str) -> String {
+    // Mermaid uses `:` to separate a task name from its data and `#`/`;` for
+    // entities and statements, so keep them out of labels.
+    let label = |s: &str| s.replace([':', '#', ';'], " ").trim().to_string();
+
+    let mut output = String::new();
+    output.push_str("```mermaid\ngantt\n");
+    output.push_str("  dateFormat YYYY-MM-DD\n");
+    output.push_str("  axisFormat %Y-%m\n");
+
+    for goal in goals {
+        let Some(goal_timespan) = goal.timespan() else {
+            continue;
+        };
+
+        output.push_str(&format!(
+            "  section {}\n",
+            label(&goal.metadata.short_title)
+        ));
+
+        let children = &goal.task_tree.children;
+        let bars: Vec<(&str, Timespan)> = if children.is_empty() {
+            vec![(goal.metadata.short_title.content.as_str(), goal_timespan)]
+        } else {
+            children
+                .iter()
+                .filter(|child| child.roadmap.contains(filter_theme))
+                .map(|child| {
+                    let timespan = child.timespan.as_ref().map_or(goal_timespan, |t| t.content);
+                    (child.title.content.as_str(), timespan)
+                })
+                .collect()
+        };
+
+        for (title, timespan) in bars {
             output.push_str(&format!(
-                "| [{}]({}) | {} | {} |\n",
-                *goal.metadata.title,
-                goal.link_path.display(),
-                timespan,
-                goal.what_and_why(),
+                "  {} : {}, {}\n",
+                label(title),
+                timespan.first_day().format("%Y-%m-%d"),
+                timespan.end_exclusive().format("%Y-%m-%d"),
             ));
-        } else {
-            // Has children: emit one row per child that matches the theme
-            for child in children {
-                if !child.roadmap.contains(filter_theme) {
-                    continue;
-                }
-                let timespan = child.timespan.as_deref().unwrap_or(milestone_dir);
-                let what_and_why = child
-                    .what_and_why
-                    .clone()
-                    .unwrap_or_else(|| goal.what_and_why());
-                let anchor = slugify(&child.title);
-                output.push_str(&format!(
-                    "| [{}]({}#{}) | {} | {} |\n",
-                    *child.title,
-                    goal.link_path.display(),
-                    anchor,
-                    timespan,
-                    what_and_why,
-                ));
-            }
         }
     }
+
+    output.push_str("```\n");
     output
 }
 
@@ -1499,6 +2779,22 @@ pub enum Status {
 }
 
 impl Status {
+    /// The values accepted in the `Status` row of the metadata table.
+    pub const VALID_VALUES: [(&'static str, Status); 3] = [
+        ("Accepted", Status::Accepted),
+        ("Proposed", Status::Proposed),
+        ("Not accepted", Status::NotAccepted),
+    ];
+
+    /// The status as written in the `Status` row.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Proposed => "Proposed",
+            Status::Accepted => "Accepted",
+            Status::NotAccepted => "Not accepted",
+        }
+    }
+
     /// True if this goal has not yet been rejected
     pub fn is_not_not_accepted(&self) -> bool {
         *self != Status::NotAccepted
@@ -1507,13 +2803,7 @@ impl Status {
     pub fn try_from(value: Spanned<&str>) -> Result<Spanned<Self>> {
         let value = value.trim();
 
-        let valid_values = [
-            ("Accepted", Status::Accepted),
-            ("Proposed", Status::Proposed),
-            ("Not accepted", Status::NotAccepted),
-        ];
-
-        for (valid_value, status) in valid_values {
+        for (valid_value, status) in Self::VALID_VALUES {
             if value == valid_value {
                 return Ok(value.map(|_| status));
             }
@@ -1522,7 +2812,10 @@ impl Status {
         spanned::bail!(
             value,
             "unrecognized status, expected one of {:?}",
-            valid_values.iter().map(|(s, _)| s).collect::<Vec<_>>(),
+            Self::VALID_VALUES
+                .iter()
+                .map(|(s, _)| s)
+                .collect::<Vec<_>>(),
         )
     }
 }
@@ -1548,6 +2841,32 @@ fn parse_themed_rows(table: &Table, key_name: &str) -> Themes {
     themes
 }
 
+/// Parse the `Timespan` row of a metadata table (if present and non-empty).
+fn parse_timespan_row(table: &Table) -> Result<Option<Spanned<Timespan>>> {
+    let Some(row) = table
+        .rows
+        .iter()
+        .find(|row| row[0].content.trim().eq_ignore_ascii_case("Timespan"))
+    else {
+        return Ok(None);
+    };
+
+    if row[1].trim().is_empty() {
+        return Ok(None);
+    }
+
+    let Some(timespan) = Timespan::parse(&row[1]) else {
+        spanned::bail!(
+            row[1],
+            "unrecognized timespan `{}`; expected something like `2026`, `2026-2027`, \
+             `2026H2 to 2027Q1`, or `2026-03-01..2026-06-30`",
+            row[1].trim(),
+        );
+    };
+
+    Ok(Some(Spanned::new(timespan, row[1].span.clone())))
+}
+
 fn extract_metadata(sections: &[Section]) -> Result<Option<Metadata>> {
     let Some(first_section) = sections.first() else {
         return Ok(None);
@@ -1672,11 +2991,7 @@ fn extract_metadata(sections: &[Section]) -> Result<Option<Metadata>> {
         .find(|row| row[0].content.trim().eq_ignore_ascii_case("What and why"))
         .map(|row| row[1].to_string());
 
-    let timespan = first_table
-        .rows
-        .iter()
-        .find(|row| row[0].content.trim().eq_ignore_ascii_case("Timespan"))
-        .map(|row| row[1].to_string());
+    let timespan = parse_timespan_row(first_table)?;
 
     let funding_poc = first_table
         .rows
@@ -1690,6 +3005,12 @@ fn extract_metadata(sections: &[Section]) -> Result<Option<Metadata>> {
         .map(|row| row[1].to_string())
         .filter(|s| !s.trim().is_empty());
 
+    let zulip_channels = parse_zulip_channels(first_table)?;
+    let other_tracking_issues = parse_other_tracking_issues(first_table)?;
+
+    let depends_on = parse_goal_references(first_table, "Depends on")?;
+    let blocks = parse_goal_references(first_table, "Blocks")?;
+
     Ok(Some(Metadata {
         title: title.clone(),
         short_title: if let Some(row) = short_title_row {
@@ -1708,9 +3029,138 @@ fn extract_metadata(sections: &[Section]) -> Result<Option<Metadata>> {
         what_and_why,
         timespan,
         funding_poc,
+        zulip_channels,
+        other_tracking_issues,
+        depends_on,
+        blocks,
     }))
 }
 
+/// Parse the comma-separated entries of the `Zulip channel` row (if present).
+fn parse_zulip_channels(table: &Table) -> Result<Vec<ZulipChannel>> {
+    let Some(row) = table
+        .rows
+        .iter()
+        .find(|row| row[0].content.trim().eq_ignore_ascii_case("Zulip channel"))
+    else {
+        return Ok(vec![]);
+    };
+
+    if is_placeholder(&row[1]) {
+        return Ok(vec![]);
+    }
+
+    let mut channels = vec![];
+    for entry in row[1].content.split(',') {
+        let Some(channel) = ZulipChannel::parse(entry) else {
+            spanned::bail!(
+                row[1],
+                "unrecognized Zulip channel `{}`; expected something like `#t-lang`, \
+                 `[#t-lang][channel]`, or a link to {}",
+                entry.trim(),
+                ZULIP_URL,
+            );
+        };
+
+        if let Some(url) = &channel.url {
+            if !url.starts_with(ZULIP_URL) {
+                spanned::bail!(
+                    row[1],
+                    "Zulip channel link `{}` must start with `{}`",
+                    url,
+                    ZULIP_URL
+                );
+            }
+        }
+
+        channels.push(channel);
+    }
+    Ok(channels)
+}
+
+/// Parse the comma-separated entries of the `Other tracking issues` row (if present).
+fn parse_other_tracking_issues(table: &Table) -> Result<Vec<IssueId>> {
+    let Some(row) = table.rows.iter().find(|row| {
+        row[0]
+            .content
+            .trim()
+            .eq_ignore_ascii_case("Other tracking issues")
+    }) else {
+        return Ok(vec![]);
+    };
+
+    if is_placeholder(&row[1]) {
+        return Ok(vec![]);
+    }
+
+    let mut issues = vec![];
+    for entry in row[1].content.split(',') {
+        let Some(issue_id) = IssueId::parse_reference(entry) else {
+            spanned::bail!(
+                row[1],
+                "unrecognized issue `{}`; expected something like `[rust-lang/rust#123]` \
+                 or `https://github.com/rust-lang/rust/issues/123`",
+                entry.trim(),
+            );
+        };
+
+        if !issues.contains(&issue_id) {
+            issues.push(issue_id);
+        }
+    }
+    Ok(issues)
+}
+
+/// Parse all rows from a metadata table where the first column matches `key_name`
+/// (case-insensitive) as comma-separated references to other goals.
+/// Each reference is either a link to a goal file (`[Title](./goal.md)` or `goal.md`)
+/// or a tracking issue (`[rust-lang/rust-project-goals#123]`).
+fn parse_goal_references(table: &Table, key_name: &str) -> Result<Vec<GoalReference>> {
+    let mut references = vec![];
+    for row in &table.rows {
+        if !row[0].content.trim().eq_ignore_ascii_case(key_name) {
+            continue;
+        }
+
+        for piece in row[1].content.split(',') {
+            let piece = piece.trim();
+            if piece.is_empty() {
+                continue;
+            }
+
+            let spanned_piece = Spanned::new(piece.to_string(), row[1].span.clone());
+
+            if re::is_just(&re::TRACKING_ISSUE, piece) {
+                references.push(GoalReference::Issue(spanned_piece.parse()?));
+                continue;
+            }
+
+            // `[Next solver](./next-solver.md)` → `./next-solver.md`
+            let target = match re::MARKDOWN_LINK_TARGET.captures(piece) {
+                Some(c) => c.name("target").unwrap().as_str(),
+                None => piece,
+            };
+
+            if !target.ends_with(".md") {
+                spanned::bail!(
+                    row[1],
+                    "`{}` entry `{}` is neither a link to a goal file nor a tracking issue \
+                     like `[rust-lang/rust-project-goals#123]`",
+                    key_name,
+                    piece,
+                );
+            }
+
+            let target = target.trim_start_matches("./").to_string();
+            references.push(GoalReference::File(Spanned::new(
+                target,
+                row[1].span.clone(),
+            )));
+        }
+    }
+    Ok(references)
+}
+
 /// Returns the first sentence of a string (up to and including the first `.` followed by whitespace or end of string).
 /// Stops at blank lines (paragraph boundaries) and collapses internal newlines to spaces.
 /// If no sentence boundary is found within the first paragraph, returns the whole first paragraph.
@@ -1760,6 +3210,15 @@ fn extract_funding(sections: &[Section]) -> Result<Vec<FundingItem>> {
     let mut items = vec![];
     for row in &table.rows {
         let cost = FundingCost::parse(row[1].trim()).unwrap_or(FundingCost::Tbd);
+        if let FundingCost::Amount { currency, .. } = &cost {
+            if Configuration::get().exchange_rate(currency).is_none() {
+                spanned::bail!(
+                    row[1],
+                    "no exchange rate for `{}`; add one to `[exchange_rates]` in `rust-project-goals.toml`",
+                    currency
+                );
+            }
+        }
         let sponsors = {
             let s = row[3].trim().to_string();
             if s.is_empty() {
@@ -1794,8 +3253,8 @@ fn extract_help_wanted(sections: &[Section]) -> Result<Vec<HelpWantedItem>> {
     for row in &table.rows {
         items.push(HelpWantedItem {
             task: row[0].to_string(),
-            experience_level: row[1].to_string(),
-            time_investment: row[2].to_string(),
+            experience_level: ExperienceLevel::parse(&row[1]),
+            time_investment: TimeInvestment::parse(&row[2]),
         });
     }
     Ok(items)
@@ -2022,26 +3481,62 @@ fn extract_team_support_from_section(
 }
 
 impl SupportLevel {
+    /// All support levels, from least to most involvement.
+    pub fn all() -> impl Iterator<Item = SupportLevel> {
+        (0..Configuration::get().support_levels.len()).map(|index| SupportLevel { index })
+    }
+
+    /// Look up a support level by name (case-insensitive).
+    pub fn named(name: &str) -> Option<SupportLevel> {
+        let name = name.trim();
+        Configuration::get()
+            .support_levels
+            .keys()
+            .position(|level| level.eq_ignore_ascii_case(name))
+            .map(|index| SupportLevel { index })
+    }
+
     fn from_str(s: &Spanned<String>) -> Result<Self> {
-        match s.trim().to_lowercase().as_str() {
-            "small" => Ok(SupportLevel::Small),
-            "medium" => Ok(SupportLevel::Medium),
-            "large" => Ok(SupportLevel::Large),
-            other => spanned::bail!(
+        match Self::named(s) {
+            Some(level) => Ok(level),
+            None => spanned::bail!(
                 s,
-                "unrecognized support level `{}`, expected one of: Small, Medium, Large",
-                other
+                "unrecognized support level `{}`, expected one of: {}",
+                s.trim().to_lowercase(),
+                Self::all()
+                    .map(|level| level.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
             ),
         }
     }
 
     /// Returns the display name for this support level.
     pub fn as_str(&self) -> &'static str {
-        match self {
-            SupportLevel::Small => "Small",
-            SupportLevel::Medium => "Medium",
-            SupportLevel::Large => "Large",
-        }
+        self.entry().0
+    }
+
+    /// The description and rules of this support level from the configuration.
+    pub fn details(&self) -> &'static SupportLevelDetails {
+        self.entry().1
+    }
+
+    /// Whether asks at this level need a champion from the team.
+    pub fn requires_champion(&self) -> bool {
+        self.details().champion_required
+    }
+
+    /// Whether teams asked for this level are shown in bold in goal tables.
+    pub fn is_bold(&self) -> bool {
+        self.details().bold
+    }
+
+    fn entry(&self) -> (&'static str, &'static SupportLevelDetails) {
+        let (name, details) = Configuration::get()
+            .support_levels
+            .get_index(self.index)
+            .unwrap();
+        (name.as_str(), details)
     }
 }
 
@@ -2051,19 +3546,15 @@ impl std::fmt::Display for SupportLevel {
     }
 }
 
-/// Represents a goal's categorization by its maximum team ask level.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum GoalSize {
-    /// At least one team has a Large ask
-    Large,
-    /// At least one team has a Medium ask, no Large asks
-    Medium,
-    /// Only Small asks
-    Small,
+impl std::fmt::Debug for SupportLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SupportLevel({})", self.as_str())
+    }
 }
 
 impl GoalDocument {
-    /// Returns the maximum support level across all team asks for this goal.
+    /// Returns the maximum support level across all team asks for this goal,
+    /// which is how goals are sized (e.g., by the `(((LARGE GOALS)))` directive).
     /// Returns None if this goal uses the old format or has no team support entries.
     pub fn max_support_level(&self) -> Option<SupportLevel> {
         match &self.team_involvement {
@@ -2071,16 +3562,6 @@ impl GoalDocument {
             TeamInvolvement::Asks(_) => None,
         }
     }
-
-    /// Categorizes the goal by its maximum team ask level.
-    /// Returns None if this goal uses the old format.
-    pub fn goal_size(&self) -> Option<GoalSize> {
-        self.max_support_level().map(|level| match level {
-            SupportLevel::Large => GoalSize::Large,
-            SupportLevel::Medium => GoalSize::Medium,
-            SupportLevel::Small => GoalSize::Small,
-        })
-    }
 }
 
 /// Format goals into tables with one row per team ask.
@@ -2096,89 +3577,107 @@ impl GoalDocument {
 /// ```
 ///
 /// - Goal and PoC only appear on the first row for each goal
-/// - Team name is **bold** for Large asks
-/// - Champion shows `@username`, `TBD` (for Large/Medium), or `*not needed*` (for Small)
-pub fn format_sized_goal_table(goals: &[&GoalDocument], size: GoalSize) -> Result<String> {
-    use std::fmt::Write;
+/// - Team name is **bold** for asks at a `bold` support level (e.g., Large)
+/// - Champion shows `@username`, `TBD` (for levels that require a champion), or `*n/a*`
+pub fn format_sized_goal_table(goals: &[&GoalDocument], size: SupportLevel) -> Result<String> {
+    let table = sized_goal_table(goals, size);
+    if table.is_empty() {
+        return Ok("*No goals in this category.*\n".to_string());
+    }
+    Ok(table.to_markdown())
+}
 
-    // Filter to goals of the requested size
-    let mut filtered_goals: Vec<_> = goals
-        .iter()
-        .filter(|g| g.goal_size() == Some(size))
-        .collect();
+/// The table of goals whose largest ask is of the given `size`, see [`format_sized_goal_table`].
+pub fn sized_goal_table(goals: &[&GoalDocument], size: SupportLevel) -> table::Table {
+    let goals: Vec<SizedGoalRows<'_>> = goals.iter().map(|g| SizedGoalRows::of(g)).collect();
+    sized_goal_rows_table(goals, size)
+}
 
-    if filtered_goals.is_empty() {
-        return Ok("*No goals in this category.*\n".to_string());
+/// What [`sized_goal_table`] shows of a goal.
+struct SizedGoalRows<'g> {
+    title: &'g str,
+    short_title: &'g str,
+    link_path: &'g Path,
+    point_of_contact: String,
+
+    /// The teams asked for support, with their largest ask and their champion (if any),
+    /// sorted by level (largest first) then alphabetically.
+    teams: Vec<(String, SupportLevel, Option<&'g str>)>,
+}
+
+impl<'g> SizedGoalRows<'g> {
+    fn of(goal: &'g GoalDocument) -> Self {
+        SizedGoalRows {
+            title: &goal.metadata.title.content,
+            short_title: &goal.metadata.short_title.content,
+            link_path: &goal.link_path,
+            point_of_contact: goal.point_of_contact_for_goal_list(),
+            teams: goal_team_rows(goal)
+                .into_iter()
+                .map(|(team, level)| {
+                    let champion = goal.metadata.champions.get(team);
+                    (
+                        team.name().to_lowercase(),
+                        level,
+                        champion.map(|c| c.content.as_str()),
+                    )
+                })
+                .collect(),
+        }
     }
+}
+
+fn sized_goal_rows_table(mut goals: Vec<SizedGoalRows<'_>>, size: SupportLevel) -> table::Table {
+    // Filter to goals whose largest ask is of the requested size
+    goals.retain(|g| g.teams.first().map(|&(_, level, _)| level) == Some(size));
 
     // Sort goals by title
-    filtered_goals.sort_by_key(|g| g.metadata.title.to_lowercase());
+    goals.sort_by_key(|g| g.title.to_lowercase());
 
     // Build the table
-    let mut table: Vec<Vec<Spanned<String>>> = vec![vec![
-        Spanned::here("Goal".to_string()),
-        Spanned::here("PoC".to_string()),
-        Spanned::here("Team".to_string()),
-        Spanned::here("Champion".to_string()),
-    ]];
-
-    for goal in filtered_goals {
-        // Get all team supports, sorted by level (Large first) then alphabetically
-        let team_rows = goal_team_rows(goal);
-
-        for (i, (team, level)) in team_rows.iter().enumerate() {
+    let mut table = table::Table::new(["Goal", "PoC", "Team", "Champion"]);
+
+    for goal in goals {
+        for (i, (team, level, champion)) in goal.teams.iter().enumerate() {
             let is_first_row = i == 0;
 
             // Goal and PoC only on first row
             let goal_cell = if is_first_row {
-                format!(
-                    "[{}]({})",
-                    goal.metadata.short_title.content,
-                    goal.link_path.display()
-                )
+                Cell::link(goal.short_title, goal.link_path.display().to_string())
             } else {
-                String::new()
+                Cell::Empty
             };
 
             let poc_cell = if is_first_row {
-                goal.point_of_contact_for_goal_list()
+                Cell::markdown(&goal.point_of_contact)
             } else {
-                String::new()
+                Cell::Empty
             };
 
-            // Team name: bold for Large, normal for Medium/Small
-            let team_cell = if *level == SupportLevel::Large {
-                format!("**{}**", team.name().to_lowercase())
+            let team_cell = Cell::text(team);
+            let team_cell = if level.is_bold() {
+                team_cell.strong()
             } else {
-                team.name().to_lowercase()
+                team_cell
             };
 
             // Champion: @username, ![TBD][], or *n/a*
-            let champion_cell = if let Some(champion) = goal.metadata.champions.get(team) {
-                champion.content.clone()
+            let champion_cell = if let Some(champion) = champion {
+                Cell::markdown(*champion)
+            } else if level.requires_champion() {
+                Cell::Badge("TBD".to_string())
             } else {
-                use SupportLevel::*;
-                match level {
-                    Large | Medium => "![TBD][]".to_string(),
-                    Small => "*n/a*".to_string(),
-                }
+                Cell::text("n/a").emphasis()
             };
 
-            table.push(vec![
-                Spanned::here(goal_cell),
-                Spanned::here(poc_cell),
-                Spanned::here(team_cell),
-                Spanned::here(champion_cell),
-            ]);
+            table.push_row(vec![goal_cell, poc_cell, team_cell, champion_cell]);
         }
     }
 
-    let mut output = String::new();
-    write!(output, "{}", util::format_table(&table))?;
-    Ok(output)
+    table
 }
 
-/// Get all team supports for a goal, sorted by level (Large first) then alphabetically.
+/// Get all team supports for a goal, sorted by level (largest first) then alphabetically.
 fn goal_team_rows(goal: &GoalDocument) -> Vec<(&'static TeamName, SupportLevel)> {
     let Some(supports) = goal.team_involvement.as_support() else {
         return vec![];
@@ -2197,7 +3696,7 @@ fn goal_team_rows(goal: &GoalDocument) -> Vec<(&'static TeamName, SupportLevel)>
             .or_insert(support.support_level);
     }
 
-    // Sort by level (Large > Medium > Small) then by team name
+    // Sort by level (largest first) then by team name
     let mut sorted: Vec<_> = team_levels.into_iter().collect();
     sorted.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.name().cmp(&b.0.name())));
 
@@ -2248,11 +3747,7 @@ fn parse_task_tree_child(section: &Section, parent_metadata: &Metadata) -> Resul
     // Parse child-specific fields from metadata table (if present)
     let (own_roadmap, own_timespan, own_what_and_why) = if let Some(table) = metadata_table {
         let roadmap = parse_themed_rows(table, "Roadmap");
-        let timespan = table
-            .rows
-            .iter()
-            .find(|row| row[0].content.trim().eq_ignore_ascii_case("Timespan"))
-            .map(|row| row[1].to_string());
+        let timespan = parse_timespan_row(table)?;
         let what_and_why = table
             .rows
             .iter()
@@ -2301,22 +3796,32 @@ fn parse_task_tree_child(section: &Section, parent_metadata: &Metadata) -> Resul
 /// Extract task items from a section's `| Task | Owner(s) | Notes |` table (if present).
 fn extract_task_items(section: &Section) -> Result<Vec<TaskItem>> {
     // Look for a task table with 3 columns: Task, Owner(s) [or team(s)], Notes
+    // or 4 columns: Task, Owner(s) [or team(s)], Status, Notes
     let task_table = section.tables.iter().find(|t| {
-        t.header.len() == 3
+        let n = t.header.len();
+        (n == 3 || (n == 4 && t.header[2].content.trim() == "Status"))
             && t.header[0].content.trim() == "Task"
-            && t.header[2].content.trim() == "Notes"
+            && t.header[n - 1].content.trim() == "Notes"
     });
 
     let Some(table) = task_table else {
         return Ok(vec![]);
     };
 
+    let has_status = table.header.len() == 4;
+
     let mut items = vec![];
     for row in &table.rows {
+        let status = if has_status {
+            TaskStatus::parse(&row[2])?
+        } else {
+            None
+        };
         items.push(TaskItem {
             task: row[0].clone(),
             owners: row[1].to_string(),
-            notes: row[2].to_string(),
+            status,
+            notes: row[row.len() - 1].to_string(),
         });
     }
 
@@ -2561,9 +4066,380 @@ impl Metadata {
     }
 }
 
+#[cfg(any(test, feature = "testing"))]
+impl GoalDocument {
+    /// An accepted goal at `path` (relative to `src`, e.g. `2026/foo.md`) with the point of
+    /// contact `@alice`, and without team asks, tasks, or funding.
+    pub fn for_test(path: &str, title: &str) -> GoalDocument {
+        let path = Path::new(path);
+        let title = Spanned::here(title.to_string());
+        GoalDocument {
+            path: Path::new("src").join(path),
+            link_path: Arc::new(PathBuf::from(path.file_name().unwrap())),
+            metadata: Metadata {
+                title: title.clone(),
+                short_title: title.clone(),
+                pocs: "@alice".to_string(),
+                status: Spanned::here(Status::Accepted),
+                tracking_issue: None,
+                table: Spanned::here(Table {
+                    header: vec![],
+                    rows: vec![],
+                }),
+                champions: BTreeMap::new(),
+                roadmap: Themes::default(),
+                highlight: Themes::default(),
+                needs: Themes::default(),
+                what_and_why: None,
+                timespan: None,
+                funding_poc: None,
+                zulip_channels: vec![],
+                other_tracking_issues: vec![],
+                depends_on: vec![],
+                blocks: vec![],
+            },
+            summary: String::new(),
+            goal_plans: vec![],
+            task_owners: BTreeSet::new(),
+            team_involvement: TeamInvolvement::Support(vec![]),
+            task_tree: TaskTree {
+                title,
+                tasks: vec![],
+                roadmap: Themes::default(),
+                timespan: None,
+                what_and_why: None,
+                children: vec![],
+            },
+            funding: vec![],
+            help_wanted: vec![],
+        }
+    }
+}
+
 fn owner_usernames(text: &str) -> Vec<&str> {
     text.split(char::is_whitespace)
         .filter_map(|owner| re::USERNAME.captures(owner))
         .map(|captures| captures.get(0).unwrap().as_str())
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestGoal {
+        link_path: PathBuf,
+        title: Spanned<String>,
+        tracking_issue: Option<IssueId>,
+        depends_on: Vec<GoalReference>,
+        blocks: Vec<GoalReference>,
+    }
+
+    fn goal(name: &str) -> TestGoal {
+        TestGoal {
+            link_path: PathBuf::from(format!("{name}.md")),
+            title: Spanned::here(name.to_string()),
+            tracking_issue: None,
+            depends_on: vec![],
+            blocks: vec![],
+        }
+    }
+
+    fn file(name: &str) -> GoalReference {
+        GoalReference::File(Spanned::here(format!("{name}.md")))
+    }
+
+    fn issue_id(number: u64) -> IssueId {
+        IssueId::new(Repository::new("rust-lang", "rust-project-goals"), number)
+    }
+
+    fn check(goals: &[TestGoal]) -> std::result::Result<(), String> {
+        let rows: Vec<DependencyRows<'_>> = goals
+            .iter()
+            .map(|goal| DependencyRows {
+                link_path: &goal.link_path,
+                title: &goal.title,
+                tracking_issue: goal.tracking_issue.as_ref(),
+                depends_on: &goal.depends_on,
+                blocks: &goal.blocks,
+            })
+            .collect();
+        check_dependencies(&rows).map_err(|e| e.to_string())
+    }
+
+    fn amount(currency: &str, low: u64, high: u64) -> FundingCost {
+        FundingCost::Amount {
+            currency: currency.to_string(),
+            low,
+            high,
+        }
+    }
+
+    #[test]
+    fn funding_cost_amounts() {
+        assert_eq!(
+            FundingCost::parse("$25,000"),
+            Some(amount("USD", 25_000, 25_000))
+        );
+        assert_eq!(
+            FundingCost::parse("$75K"),
+            Some(amount("USD", 75_000, 75_000))
+        );
+        assert_eq!(
+            FundingCost::parse("€1.5M"),
+            Some(amount("EUR", 1_500_000, 1_500_000))
+        );
+        assert_eq!(
+            FundingCost::parse("GBP 40,000"),
+            Some(amount("GBP", 40_000, 40_000))
+        );
+        assert_eq!(
+            FundingCost::parse("50K CHF"),
+            Some(amount("CHF", 50_000, 50_000))
+        );
+        assert_eq!(FundingCost::parse("tbd"), Some(FundingCost::Tbd));
+        assert_eq!(FundingCost::parse("ASK"), Some(FundingCost::Ask));
+    }
+
+    #[test]
+    fn funding_cost_ranges() {
+        assert_eq!(
+            FundingCost::parse("$50K–$80K"),
+            Some(amount("USD", 50_000, 80_000))
+        );
+        assert_eq!(
+            FundingCost::parse("€40,000 to €60,000"),
+            Some(amount("EUR", 40_000, 60_000))
+        );
+        // The currency only needs to be written once
+        assert_eq!(
+            FundingCost::parse("$50K-80K"),
+            Some(amount("USD", 50_000, 80_000))
+        );
+        assert_eq!(
+            amount("EUR", 50_000, 80_000).display(),
+            "€50,000–€80,000".to_string()
+        );
+    }
+
+    #[test]
+    fn funding_cost_malformed() {
+        for cost in [
+            "$50K–€80K",
+            "$80K–$50K",
+            "50,000",
+            "lots",
+            "$",
+            "usd 5",
+            "$12abc",
+            "",
+        ] {
+            assert_eq!(FundingCost::parse(cost), None, "{cost}");
+        }
+    }
+
+    #[test]
+    fn funding_cost_sums() {
+        assert_eq!(sum_funding_costs(&[]), None);
+        assert_eq!(
+            sum_funding_costs(&[FundingCost::Tbd, FundingCost::Ask]),
+            Some(FundingCost::Tbd)
+        );
+        assert_eq!(
+            sum_funding_costs(&[FundingCost::Ask]),
+            Some(FundingCost::Ask)
+        );
+        assert_eq!(
+            sum_funding_costs(&[
+                FundingCost::Tbd,
+                amount("EUR", 10_000, 20_000),
+                amount("EUR", 5_000, 5_000),
+            ]),
+            Some(amount("EUR", 15_000, 25_000))
+        );
+
+        // Mixed currencies are normalized to USD with the configured exchange rates
+        let euros = amount("EUR", 10_000, 10_000);
+        let Some(FundingCost::Amount { low, .. }) = euros.to_usd() else {
+            panic!("no exchange rate for EUR");
+        };
+        assert_eq!(
+            sum_funding_costs(&[amount("USD", 10_000, 10_000), euros]),
+            Some(amount("USD", 10_000 + low, 10_000 + low))
+        );
+    }
+
+    #[test]
+    fn dependencies_without_cycle() {
+        let mut a = goal("a");
+        a.depends_on.push(file("b"));
+        a.depends_on
+            .push(GoalReference::Issue(Spanned::here(issue_id(7))));
+        let mut b = goal("b");
+        b.blocks.push(file("c"));
+        let c = goal("c");
+        assert_eq!(check(&[a, b, c]), Ok(()));
+    }
+
+    #[test]
+    fn dependency_cycle() {
+        let mut a = goal("a");
+        a.depends_on.push(file("b"));
+        let mut b = goal("b");
+        b.tracking_issue = Some(issue_id(2));
+        // `a` blocks the goal whose tracking issue is #2, which is `b`
+        a.blocks
+            .push(GoalReference::Issue(Spanned::here(issue_id(2))));
+
+        let error = check(&[a, b]).unwrap_err();
+        assert!(error.contains("dependency cycle between goals"), "{error}");
+        assert!(
+            error.contains("a.md → b.md → a.md") || error.contains("b.md → a.md → b.md"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn self_dependency() {
+        let mut a = goal("a");
+        a.depends_on.push(file("a"));
+        let error = check(&[a]).unwrap_err();
+        assert!(error.contains("goal depends on itself"), "{error}");
+
+        let mut a = goal("a");
+        a.blocks.push(file("a"));
+        let error = check(&[a]).unwrap_err();
+        assert!(error.contains("goal blocks itself"), "{error}");
+    }
+
+    #[test]
+    fn dependency_on_unknown_goal() {
+        let mut a = goal("a");
+        a.depends_on.push(file("missing"));
+        let error = check(&[a]).unwrap_err();
+        assert!(error.contains("no goal file `missing.md`"), "{error}");
+    }
+
+    #[test]
+    fn cycle_through_issues() {
+        let (x, y) = (issue_id(1), issue_id(2));
+        let edges = BTreeSet::from([
+            (DependencyNode::Goal(0), DependencyNode::Issue(&x)),
+            (DependencyNode::Issue(&x), DependencyNode::Issue(&y)),
+            (DependencyNode::Issue(&y), DependencyNode::Goal(0)),
+        ]);
+        let cycle = find_dependency_cycle(&edges).unwrap();
+        assert_eq!(cycle.len(), 4);
+        assert_eq!(cycle.first(), cycle.last());
+
+        let edges = BTreeSet::from([
+            (DependencyNode::Goal(0), DependencyNode::Goal(1)),
+            (DependencyNode::Goal(1), DependencyNode::Issue(&x)),
+            (DependencyNode::Goal(0), DependencyNode::Issue(&x)),
+        ]);
+        assert_eq!(find_dependency_cycle(&edges), None);
+    }
+
+    /// A goal asking each of `teams` for support at a level, given as `(team, level, champion)`
+    /// from the largest level to the smallest.
+    fn sized_goal<'g>(
+        title: &'g str,
+        teams: &[(&str, SupportLevel, Option<&'g str>)],
+    ) -> SizedGoalRows<'g> {
+        SizedGoalRows {
+            title,
+            short_title: title,
+            link_path: Path::new("goal.md"),
+            point_of_contact: "@poc".to_string(),
+            teams: teams
+                .iter()
+                .map(|&(team, level, champion)| (team.to_string(), level, champion))
+                .collect(),
+        }
+    }
+
+    /// The goal and team columns of the rows of `table`.
+    fn goals_and_teams(table: &table::Table) -> Vec<(Cell, Cell)> {
+        table
+            .groups
+            .iter()
+            .flat_map(|group| &group.rows)
+            .map(|row| (row[0].clone(), row[2].clone()))
+            .collect()
+    }
+
+    #[test]
+    fn sized_goal_table_uses_the_largest_ask() {
+        let levels: Vec<SupportLevel> = SupportLevel::all().collect();
+        let (smallest, largest) = (levels[0], levels[levels.len() - 1]);
+        assert_ne!(smallest, largest, "expected several support levels");
+
+        let goals = || {
+            vec![
+                sized_goal(
+                    "Zebra",
+                    &[("lang", largest, None), ("libs", smallest, None)],
+                ),
+                sized_goal("apple", &[("cargo", smallest, None)]),
+                sized_goal("Mango", &[("compiler", largest, None)]),
+            ]
+        };
+
+        let team = |name: &str, level: SupportLevel| {
+            if level.is_bold() {
+                Cell::text(name).strong()
+            } else {
+                Cell::text(name)
+            }
+        };
+
+        assert_eq!(
+            goals_and_teams(&sized_goal_rows_table(goals(), largest)),
+            vec![
+                (Cell::link("Mango", "goal.md"), team("compiler", largest)),
+                (Cell::link("Zebra", "goal.md"), team("lang", largest)),
+                (Cell::Empty, team("libs", smallest)),
+            ]
+        );
+        assert_eq!(
+            goals_and_teams(&sized_goal_rows_table(goals(), smallest)),
+            vec![(Cell::link("apple", "goal.md"), team("cargo", smallest))]
+        );
+        assert!(sized_goal_rows_table(vec![], largest).is_empty());
+    }
+
+    #[test]
+    fn sized_goal_table_champions() {
+        for level in SupportLevel::all() {
+            let table = sized_goal_rows_table(
+                vec![
+                    sized_goal("With champion", &[("lang", level, Some("@alice"))]),
+                    sized_goal("Without champion", &[("lang", level, None)]),
+                ],
+                level,
+            );
+            let champions: Vec<&Cell> = table.groups[0].rows.iter().map(|row| &row[3]).collect();
+
+            let missing = if level.requires_champion() {
+                Cell::Badge("TBD".to_string())
+            } else {
+                Cell::text("n/a").emphasis()
+            };
+            assert_eq!(
+                champions,
+                vec![&Cell::markdown("@alice"), &missing],
+                "{level}"
+            );
+        }
+    }
+
+    #[test]
+    fn roadmap_goal_rows() {
+        let mut goal = GoalDocument::for_test("2026/rtn.md", "Return type notation");
+        goal.metadata.what_and_why = Some("Bounds like `T: Tr<u8 | u16>`".to_string());
+        assert_eq!(
+            format_roadmap_goal_rows(&[&goal], "Async"),
+            "| [Return type notation](rtn.md) | 2026 | Bounds like `T: Tr<u8 \\| u16>` |\n"
+        );
+    }
+}
@@ -1,4 +1,8 @@
+pub mod atom;
+pub mod badge;
 pub mod config;
+pub mod explorer;
+pub mod feeds;
 pub mod format_champions;
 pub mod format_team_ask;
 pub mod format_team_support;
@@ -6,7 +10,12 @@ pub mod gh;
 pub mod goal;
 pub mod markdown_processor;
 pub mod markwaydown;
+pub mod opportunities;
+mod parse_cache;
 pub mod re;
+pub mod table;
 pub mod team;
+pub mod timespan;
+pub mod update_items;
 pub mod util;
 pub use spanned;
@@ -1,6 +1,10 @@
 use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Range;
 use std::rc::Rc;
 
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use regex::{Captures, Regex};
+
 use crate::config::GoalsConfig;
 use crate::{re, team};
 
@@ -43,37 +47,24 @@ impl MarkdownProcessor {
         Ok(content)
     }
 
-    /// Replace @username with [Display Name][] and add link definitions
+    /// Replace @username with [Display Name][] and add link definitions.
+    /// Only plain text is considered, so code like the field projection syntax
+    /// (`foo.@bar.@baz`) is left alone.
     fn link_users(
         &self,
         content: String,
         state: &mut MarkdownProcessorState,
     ) -> anyhow::Result<String> {
-        // TODO: using a regex to pick out the usernames has risk for false positives.
-        //
-        // E.g. it was picking up the field projection syntax
-        // (`foo.@bar.@baz`) as github usernames and either finding
-        // those and using their names or returning gibberish that
-        // made the underlying comment indistinguishable.
-        //
-        // This is a pretty messy situation, but I think the best
-        // option is to only look for GH usernames in non-code
-        // portions of the Markdown content.
-        //
-        // As a workaround, we're only rendering the Project members'
-        // usernames so far and leaving everything else as-is.
-        let usernames: BTreeSet<String> = re::USERNAME
-            .find_iter(&content)
-            .map(|m| m.as_str().to_string())
-            .filter(|username| !self.config.ignore_users.contains(username))
-            .collect();
-
-        let mut content = content;
-        for username in &usernames {
-            if let Ok(display_name) = self.get_display_name(username, state) {
-                content = content.replace(username, &format!("[{}][]", display_name));
+        let mut usernames = BTreeSet::new();
+        let mut content = replace_in_text(&content, &re::USERNAME, |captures| {
+            let username = &captures[0];
+            if self.config.ignore_users.iter().any(|u| u == username) {
+                return None;
             }
-        }
+            let display_name = self.get_display_name(username, state).ok()?;
+            usernames.insert(username.to_string());
+            Some(format!("[{}][]", display_name))
+        });
 
         // Add link definitions
         content.push_str("\n\n");
@@ -99,33 +90,40 @@ impl MarkdownProcessor {
         Ok(content)
     }
 
-    /// Apply linkifier patterns to expand [pattern][] -> [pattern](url)
+    /// Apply linkifier patterns to expand [pattern][] -> [pattern](url).
+    /// Only plain text is considered, so code and existing links are left alone.
     fn linkify(&self, content: String) -> anyhow::Result<String> {
         let mut content = content;
         for (regex, url_template) in &self.config.linkifiers {
-            content = regex
-                .replace_all(&content, |captures: &regex::Captures<'_>| -> String {
-                    // The capture should be [pattern] format
-                    assert!(captures[0].starts_with("[") && captures[0].ends_with("]"));
-
-                    let mut result = String::new();
-                    result.push_str(&captures[0]); // [pattern]
-                    result.push('(');
-                    captures.expand(url_template, &mut result);
-                    result.push(')');
-                    result
-                })
-                .to_string();
+            content = replace_in_text(&content, regex, |captures| {
+                // The capture should be [pattern] format
+                assert!(captures[0].starts_with("[") && captures[0].ends_with("]"));
+
+                let mut result = String::new();
+                result.push_str(&captures[0]); // [pattern]
+                result.push('(');
+                captures.expand(url_template, &mut result);
+                result.push(')');
+                Some(result)
+            });
         }
         Ok(content)
     }
 
-    /// Insert configured link definitions
+    /// Insert configured link and badge definitions
     fn insert_links(&self, mut content: String) -> anyhow::Result<String> {
         content.push_str("\n\n");
         for (name, url) in &self.config.links {
             content.push_str(&format!("[{}]: {}\n", name, url));
         }
+        // Links come first, so they take precedence over badges of the same name.
+        // Badge URIs are long, so only the badges the page refers to are defined.
+        let lowercase = content.to_lowercase();
+        for (name, uri) in &self.config.badges {
+            if lowercase.contains(&format!("[{}]", name.to_lowercase())) {
+                content.push_str(&format!("[{}]: {}\n", name, uri));
+            }
+        }
         Ok(content)
     }
 
@@ -165,3 +163,123 @@ impl MarkdownProcessor {
         Ok(display_name_rc)
     }
 }
+
+/// Replace the matches of `regex` in the plain text of `content` (see [`text_ranges`]) with
+/// the result of `replace`, or leave them as they are if it returns `None`. Everything else,
+/// including code, HTML and links, is copied byte for byte.
+fn replace_in_text(
+    content: &str,
+    regex: &Regex,
+    mut replace: impl FnMut(&Captures<'_>) -> Option<String>,
+) -> String {
+    let mut output = String::with_capacity(content.len());
+    let mut copied = 0;
+    for range in text_ranges(content) {
+        for captures in regex.captures_iter(&content[range.clone()]) {
+            let m = captures.get(0).unwrap();
+            if let Some(replacement) = replace(&captures) {
+                output.push_str(&content[copied..range.start + m.start()]);
+                output.push_str(&replacement);
+                copied = range.start + m.end();
+            }
+        }
+    }
+    output.push_str(&content[copied..]);
+    output
+}
+
+/// Byte ranges of the plain text of `content`: text that is not in code, HTML, or a link.
+/// The parser splits text at characters like `[`, so adjacent text is merged into one range
+/// (e.g., a `[#123]` that is not a link yet).
+fn text_ranges(content: &str) -> Vec<Range<usize>> {
+    // The extensions mdbook enables
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_HEADING_ATTRIBUTES;
+
+    let mut ranges: Vec<Range<usize>> = vec![];
+    let mut excluded = 0;
+    for (event, range) in Parser::new_ext(content, options).into_offset_iter() {
+        match event {
+            Event::Start(
+                Tag::CodeBlock(_) | Tag::HtmlBlock | Tag::Link { .. } | Tag::Image { .. },
+            ) => {
+                excluded += 1;
+            }
+            Event::End(TagEnd::CodeBlock | TagEnd::HtmlBlock | TagEnd::Link | TagEnd::Image) => {
+                excluded -= 1;
+            }
+            Event::Text(_) if excluded == 0 => match ranges.last_mut() {
+                Some(last) if last.end == range.start => last.end = range.end,
+                _ => ranges.push(range),
+            },
+            _ => {}
+        }
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn processor() -> MarkdownProcessor {
+        let mut config = GoalsConfig::default();
+        config
+            .users
+            .insert("@alice".to_string(), "Alice".to_string());
+        config.linkifiers.push((
+            Regex::new(r"\[#([0-9]+)\]").unwrap(),
+            "https://github.com/rust-lang/rust/issues/$1".to_string(),
+        ));
+        MarkdownProcessor::new(config)
+    }
+
+    #[test]
+    fn link_users_skips_code() {
+        let content = "Thanks @alice!\n\nProjections like `foo.@alice` and\n\n```rust\nlet x = foo.@alice;\n```\n";
+        let output = processor()
+            .link_users(content.to_string(), &mut MarkdownProcessorState::default())
+            .unwrap();
+        assert_eq!(
+            output,
+            "Thanks [Alice][]!\n\nProjections like `foo.@alice` and\n\n```rust\nlet x = foo.@alice;\n```\n\n\n[Alice]: https://github.com/alice\n"
+        );
+    }
+
+    #[test]
+    fn link_users_skips_unknown_and_ignored_users() {
+        let mut processor = processor();
+        processor.config.ignore_users.push("@alice".to_string());
+        let content = "cc @alice";
+        let output = processor
+            .link_users(content.to_string(), &mut MarkdownProcessorState::default())
+            .unwrap();
+        assert_eq!(output, "cc @alice\n\n");
+    }
+
+    #[test]
+    fn linkify_skips_code_and_links() {
+        let content = "See [#123], `[#123]`, [#123](https://example.com), and\n\n    [#123]\n\n<div>[#123]</div>\n";
+        let output = processor().linkify(content.to_string()).unwrap();
+        assert_eq!(
+            output,
+            "See [#123](https://github.com/rust-lang/rust/issues/123), `[#123]`, [#123](https://example.com), and\n\n    [#123]\n\n<div>[#123]</div>\n"
+        );
+    }
+
+    #[test]
+    fn insert_links_defines_only_used_badges() {
+        let mut processor = processor();
+        processor.config.badges = vec![
+            ("TBD".to_string(), "data:tbd".to_string()),
+            ("Help wanted".to_string(), "data:help".to_string()),
+        ];
+        let output = processor
+            .insert_links("Champion: ![tbd][]".to_string())
+            .unwrap();
+        assert_eq!(output, "Champion: ![tbd][]\n\n[TBD]: data:tbd\n");
+    }
+}
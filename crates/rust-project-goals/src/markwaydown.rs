@@ -4,7 +4,7 @@ use std::{fmt::Display, path::Path};
 
 use spanned::{Error, Result, Spanned};
 
-use crate::util;
+use crate::{parse_cache, util};
 
 /// A "section" is a piece of markdown that begins with `##` and which extends until the next section.
 /// Note that we don't track the hierarchical structure of sections in particular.
@@ -29,10 +29,9 @@ pub struct Table {
     pub rows: Vec<Vec<Spanned<String>>>,
 }
 
+/// Parse the markdown file at `path`. Results are [cached](crate::parse_cache) across runs.
 pub fn parse(path: impl AsRef<Path>) -> Result<Vec<Section>> {
-    let path = path.as_ref();
-    let text = Spanned::read_str_from_file(path).transpose()?;
-    parse_text(text.as_ref().map(|s| s.as_ref()))
+    parse_cache::parse(path.as_ref())
 }
 
 pub fn parse_text(text: Spanned<&str>) -> Result<Vec<Section>> {
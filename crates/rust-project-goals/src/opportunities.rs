@@ -0,0 +1,450 @@
+//! Contributor opportunities: the tasks that goals are looking for help with.
+//! They are gathered from the `## Help wanted` section of goal documents and from
+//! "Help wanted:" lines in status updates on the goals' tracking issues.
+
+use std::path::PathBuf;
+
+use rust_project_goals_json::{ContributorOpportunities, ContributorOpportunity};
+use spanned::Result;
+
+use crate::atom;
+use crate::gh::issues::ExistingGithubIssue;
+use crate::goal::{ExperienceLevel, GoalDocument, TimeInvestment};
+use crate::re::HELP_WANTED;
+use crate::table::{Cell, Table};
+use crate::util::{slug, BOOK_URL};
+
+/// A task a contributor could pick up.
+#[derive(Debug, Clone)]
+pub struct Opportunity {
+    pub milestone: String,
+    pub goal_title: String,
+
+    /// Path of the goal document relative to its milestone directory.
+    pub goal_link: PathBuf,
+
+    pub roadmaps: Vec<String>,
+    pub task: String,
+    pub experience_level: ExperienceLevel,
+    pub time_investment: TimeInvestment,
+    pub source: OpportunitySource,
+}
+
+/// Where an [`Opportunity`] was found.
+#[derive(Debug, Clone)]
+pub enum OpportunitySource {
+    /// A row in the goal document's `## Help wanted` table.
+    Goal,
+
+    /// A "Help wanted:" line in a status update on the tracking issue.
+    Update {
+        url: String,
+        author: String,
+        created_at: String,
+    },
+}
+
+impl Opportunity {
+    /// URL of the goal page in the published book.
+    pub fn goal_url(&self) -> String {
+        format!(
+            "{BOOK_URL}/{}/{}",
+            self.milestone,
+            self.goal_link.with_extension("html").display()
+        )
+    }
+
+    /// URL where the opportunity is described.
+    pub fn url(&self) -> String {
+        match &self.source {
+            OpportunitySource::Goal => format!("{}#help-wanted", self.goal_url()),
+            OpportunitySource::Update { url, .. } => url.clone(),
+        }
+    }
+}
+
+/// Collect the opportunities of the given goals. `issues` are the tracking issues of the
+/// milestone; for each goal, the help wanted requests of its most recent status update
+/// that has any are included. Pass an empty slice to only use the goal documents.
+pub fn opportunities(
+    milestone: &str,
+    goals: &[&GoalDocument],
+    issues: &[ExistingGithubIssue],
+) -> Vec<Opportunity> {
+    let mut opportunities = vec![];
+
+    for goal in goals {
+        let opportunity = |task: String, experience_level, time_investment, source| Opportunity {
+            milestone: milestone.to_string(),
+            goal_title: goal.metadata.title.to_string(),
+            goal_link: goal.link_path.to_path_buf(),
+            roadmaps: goal
+                .task_tree
+                .all_roadmap_themes()
+                .iter()
+                .map(|t| t.trim().to_string())
+                .collect(),
+            task,
+            experience_level,
+            time_investment,
+            source,
+        };
+
+        for item in &goal.help_wanted {
+            opportunities.push(opportunity(
+                item.task.trim().to_string(),
+                item.experience_level.clone(),
+                item.time_investment.clone(),
+                OpportunitySource::Goal,
+            ));
+        }
+
+        let Some(tracking_issue) = &goal.metadata.tracking_issue else {
+            continue;
+        };
+        let Some(issue) = issues.iter().find(|i| i.number == tracking_issue.number) else {
+            continue;
+        };
+        let latest = issue
+            .comments
+            .iter()
+            .filter(|c| !c.should_hide_from_reports())
+            .map(|c| (c, help_wanted_requests(&c.body)))
+            .filter(|(_, requests)| !requests.is_empty())
+            .max_by(|(a, _), (b, _)| a.created_at.cmp(&b.created_at));
+        if let Some((comment, requests)) = latest {
+            for request in requests {
+                let (task, experience_level, time_investment) = parse_request(&request);
+                opportunities.push(opportunity(
+                    task,
+                    experience_level,
+                    time_investment,
+                    OpportunitySource::Update {
+                        url: comment.url.clone(),
+                        author: comment.author.clone(),
+                        created_at: comment.created_at.clone(),
+                    },
+                ));
+            }
+        }
+    }
+
+    opportunities
+}
+
+/// Extract the ["help wanted"](`HELP_WANTED`) requests from a comment. Each request is the
+/// rest of the "Help wanted:" line along with the following lines up to the next blank line.
+pub fn help_wanted_requests(body: &str) -> Vec<String> {
+    use std::fmt::Write;
+
+    let mut help_wanted: Vec<String> = vec![];
+    let mut lines = body.split('\n').peekable();
+
+    // Look for a line that says "Help wanted" at the front.
+    // Then extract the rest of that line along with subsequent lines until we find a blank line.
+    while lines.peek().is_some() {
+        for line in lines.by_ref() {
+            if let Some(c) = HELP_WANTED.captures(line) {
+                let text = c["text"].trim().to_string();
+                if !text.is_empty() {
+                    help_wanted.push(text);
+                    break;
+                }
+            }
+        }
+
+        for line in lines.by_ref() {
+            if line.trim().is_empty() {
+                break;
+            } else {
+                let last = help_wanted.len() - 1;
+                writeln!(&mut help_wanted[last], "{line}").unwrap();
+            }
+        }
+    }
+
+    help_wanted
+}
+
+/// Split the optional annotation off the front of a help wanted request, e.g.
+/// `(beginner, 2 weeks) Port the lint to the new API`. Each comma-separated part of the
+/// annotation must be an experience level or a duration; otherwise the parenthesized text
+/// is taken to be part of the task and both come back unspecified.
+pub fn parse_request(request: &str) -> (String, ExperienceLevel, TimeInvestment) {
+    let request = request.trim();
+    let unannotated = || {
+        (
+            request.to_string(),
+            ExperienceLevel::Unspecified,
+            TimeInvestment::Unspecified,
+        )
+    };
+
+    let Some((annotation, task)) = request
+        .strip_prefix('(')
+        .and_then(|rest| rest.split_once(')'))
+    else {
+        return unannotated();
+    };
+
+    let mut experience_level = ExperienceLevel::Unspecified;
+    let mut time_investment = TimeInvestment::Unspecified;
+    for part in annotation.split(',').map(str::trim) {
+        if let Some(level) = ExperienceLevel::parse_level(part) {
+            experience_level = level;
+        } else if let duration @ TimeInvestment::Duration { .. } = TimeInvestment::parse(part) {
+            time_investment = duration;
+        } else {
+            return unannotated();
+        }
+    }
+
+    (task.trim().to_string(), experience_level, time_investment)
+}
+
+/// A filter on opportunities, as written in `(((HELP WANTED: ...)))`: comma-separated
+/// `key=value` pairs, e.g. `level=beginner|intermediate, max-weeks=4, roadmap=Rust for Linux`.
+#[derive(Debug, Default)]
+pub struct OpportunityFilter {
+    /// If non-empty, only opportunities at one of these levels match.
+    pub levels: Vec<ExperienceLevel>,
+
+    /// Only opportunities expected to take at most this many weeks match.
+    pub max_weeks: Option<u32>,
+
+    /// Only opportunities of goals in this roadmap match.
+    pub roadmap: Option<String>,
+}
+
+impl OpportunityFilter {
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut filter = OpportunityFilter::default();
+
+        for pair in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let Some((key, value)) = pair.split_once('=') else {
+                spanned::bail_here!(
+                    "expected `key=value` in help wanted filter, found `{}`",
+                    pair
+                );
+            };
+            let value = value.trim();
+            match key.trim() {
+                "level" => {
+                    for level in value.split('|') {
+                        let Some(level) = ExperienceLevel::parse_level(level) else {
+                            spanned::bail_here!(
+                                "unknown experience level `{}`, expected `beginner`, `intermediate`, or `advanced`",
+                                level
+                            );
+                        };
+                        filter.levels.push(level);
+                    }
+                }
+                "max-weeks" => {
+                    let Ok(weeks) = value.parse() else {
+                        spanned::bail_here!("expected a number of weeks, found `{}`", value);
+                    };
+                    filter.max_weeks = Some(weeks);
+                }
+                "roadmap" => filter.roadmap = Some(value.to_string()),
+                key => {
+                    spanned::bail_here!(
+                        "unknown help wanted filter `{}`, expected `level`, `max-weeks`, or `roadmap`",
+                        key
+                    );
+                }
+            }
+        }
+
+        Ok(filter)
+    }
+
+    pub fn matches(&self, opportunity: &Opportunity) -> bool {
+        if !self.levels.is_empty() && !self.levels.contains(&opportunity.experience_level) {
+            return false;
+        }
+
+        if let Some(max_weeks) = self.max_weeks {
+            match opportunity.time_investment.max_weeks() {
+                Some(weeks) if weeks <= max_weeks => {}
+                _ => return false,
+            }
+        }
+
+        if let Some(roadmap) = &self.roadmap {
+            if !opportunity.roadmaps.iter().any(|r| r == roadmap.trim()) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Format opportunities as a markdown table. Goal links are relative to the milestone directory.
+pub fn format_opportunities(opportunities: &[&Opportunity]) -> String {
+    if opportunities.is_empty() {
+        return "*No matching opportunities.*\n".to_string();
+    }
+
+    let mut table = Table::new(["Goal", "Task", "Experience level", "Time investment"]);
+    for opportunity in opportunities {
+        let mut task = Cell::markdown(opportunity.task.replace('\n', " ").trim());
+        if let OpportunitySource::Update { url, .. } = &opportunity.source {
+            task = task.and(Cell::markdown(format!("([update]({url}))")));
+        }
+        table.push_row(vec![
+            Cell::link(
+                &opportunity.goal_title,
+                opportunity.goal_link.display().to_string(),
+            ),
+            task,
+            Cell::text(opportunity.experience_level.to_string()),
+            Cell::text(opportunity.time_investment.to_string()),
+        ]);
+    }
+    table.to_markdown()
+}
+
+/// Convert opportunities to the JSON API representation.
+pub fn opportunities_json(
+    milestone: &str,
+    opportunities: &[&Opportunity],
+) -> ContributorOpportunities {
+    ContributorOpportunities {
+        milestone: milestone.to_string(),
+        opportunities: opportunities
+            .iter()
+            .map(|o| ContributorOpportunity {
+                goal: o.goal_title.clone(),
+                goal_url: o.goal_url(),
+                roadmaps: o.roadmaps.clone(),
+                task: o.task.clone(),
+                level: o.experience_level.key().map(|k| k.to_string()),
+                experience_level: o.experience_level.to_string(),
+                max_weeks: o.time_investment.max_weeks(),
+                time_investment: o.time_investment.to_string(),
+                source: match o.source {
+                    OpportunitySource::Goal => "goal".to_string(),
+                    OpportunitySource::Update { .. } => "update".to_string(),
+                },
+                url: o.url(),
+            })
+            .collect(),
+    }
+}
+
+/// Build an Atom feed of opportunities. `updated` (an RFC 3339 timestamp) is used for
+/// the feed and for opportunities from goal documents, which carry no date of their own.
+pub fn opportunities_feed(
+    milestone: &str,
+    opportunities: &[&Opportunity],
+    updated: &str,
+) -> atom::Feed {
+    let contributors_url = format!("{BOOK_URL}/{milestone}/contributors.html");
+    atom::Feed {
+        id: contributors_url.clone(),
+        title: format!("Rust project goals {milestone}: help wanted"),
+        link: contributors_url,
+        updated: updated.to_string(),
+        entries: opportunities
+            .iter()
+            .map(|o| {
+                let (updated, author) = match &o.source {
+                    OpportunitySource::Goal => (updated.to_string(), None),
+                    OpportunitySource::Update {
+                        author, created_at, ..
+                    } => (created_at.clone(), Some(author.clone())),
+                };
+                let first_line = o.task.lines().next().unwrap_or_default();
+                atom::Entry {
+                    id: format!("{}#{}", o.url(), slug(first_line)),
+                    title: format!("{}: {}", o.goal_title, first_line),
+                    link: o.url(),
+                    updated,
+                    author,
+                    content: format!(
+                        "<p>{}</p><p>Experience level: {}. Time investment: {}.</p>",
+                        atom::escape(&o.task),
+                        atom::escape(&o.experience_level.to_string()),
+                        atom::escape(&o.time_investment.to_string()),
+                    ),
+                }
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goal::TimeUnit;
+
+    #[test]
+    fn annotated_request() {
+        assert_eq!(
+            parse_request("(beginner, 2 weeks) Port the lint to the new API\n"),
+            (
+                "Port the lint to the new API".to_string(),
+                ExperienceLevel::Beginner,
+                TimeInvestment::Duration {
+                    min: 2,
+                    max: 2,
+                    unit: TimeUnit::Weeks,
+                    part_time: false,
+                },
+            )
+        );
+        assert_eq!(
+            parse_request("(Intermediate) Review the RFC"),
+            (
+                "Review the RFC".to_string(),
+                ExperienceLevel::Intermediate,
+                TimeInvestment::Unspecified,
+            )
+        );
+    }
+
+    #[test]
+    fn unannotated_request() {
+        for request in ["Review the RFC", "(see #123) Review the RFC", "(beginner"] {
+            assert_eq!(
+                parse_request(request),
+                (
+                    request.to_string(),
+                    ExperienceLevel::Unspecified,
+                    TimeInvestment::Unspecified,
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn opportunities_table() {
+        let opportunity = |task: &str, source| Opportunity {
+            milestone: "2025h2".to_string(),
+            goal_title: "Faster builds".to_string(),
+            goal_link: PathBuf::from("faster-builds.md"),
+            roadmaps: vec![],
+            task: task.to_string(),
+            experience_level: ExperienceLevel::Beginner,
+            time_investment: TimeInvestment::Unspecified,
+            source,
+        };
+        let from_goal = opportunity("Port the lint", OpportunitySource::Goal);
+        let from_update = opportunity(
+            "Compare `a | b`\nwith `a || b`",
+            OpportunitySource::Update {
+                url: "https://example.com/1".to_string(),
+                author: "alice".to_string(),
+                created_at: "2025-08-01T00:00:00Z".to_string(),
+            },
+        );
+        let table = format_opportunities(&[&from_goal, &from_update]);
+        let rows: Vec<&str> = table.lines().skip(2).collect();
+        assert_eq!(rows.len(), 2);
+        assert!(rows[0].starts_with("| [Faster builds](faster-builds.md) | Port the lint "));
+        assert!(rows[1]
+            .contains("| Compare `a \\| b` with `a \\|\\| b` ([update](https://example.com/1)) |"));
+    }
+}
@@ -0,0 +1,303 @@
+//! A persistent cache of parsed markdown files, shared by everything that loads goals
+//! (`cargo rpg check`, the mdbook preprocessor on each rebuild, the other `cargo rpg`
+//! commands, ...). There is one entry per file in the `goal-cache` directory of the target
+//! directory: `$CARGO_TARGET_DIR` if set, otherwise the `target` directory of the book that
+//! contains the file. An entry is reused if the file's modification time and size are
+//! unchanged or, failing that, if the hash of its contents is. Entries are also keyed by
+//! the crate version and a hash of the parser's source, so they are dropped whenever the
+//! parser changes.
+//!
+//! The cache is best effort: entries that cannot be read or written are ignored.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+use spanned::{Result, Span, Spanned};
+
+use crate::markwaydown::{self, Section, Table};
+use crate::util;
+
+/// Parse the markdown file at `path`, reusing the cached result if the file is unchanged.
+/// Files outside of a book are not cached unless `CARGO_TARGET_DIR` is set.
+pub fn parse(path: &Path) -> Result<Vec<Section>> {
+    match cache_dir(path) {
+        Some(cache_dir) => parse_in(&cache_dir, path),
+        None => {
+            let text = Spanned::read_str_from_file(path).transpose()?;
+            markwaydown::parse_text(text.as_ref().map(|s| s.as_ref()))
+        }
+    }
+}
+
+fn cache_dir(path: &Path) -> Option<PathBuf> {
+    let target_dir = match std::env::var_os("CARGO_TARGET_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => util::book_root(path)?.join("target"),
+    };
+    Some(target_dir.join("goal-cache"))
+}
+
+fn parse_in(cache_dir: &Path, path: &Path) -> Result<Vec<Section>> {
+    let entry_path = cache_dir.join(format!("{:016x}.json", hash(&path)));
+    let cached = read_entry(&entry_path).filter(|entry| entry.parser == parser_hash());
+    let (modified, len) = match std::fs::metadata(path) {
+        Ok(metadata) => (metadata.modified().ok(), metadata.len()),
+        Err(_) => (None, 0),
+    };
+
+    if let Some(entry) = &cached {
+        if modified.is_some() && entry.modified == modified && entry.len == len {
+            return Ok(entry.sections(path));
+        }
+    }
+
+    let text = Spanned::read_str_from_file(path).transpose()?;
+    let content_hash = hash(&text.content);
+
+    // The file was touched (e.g., by a `git checkout`) but its contents are the same.
+    if let Some(mut entry) = cached.filter(|entry| entry.hash == content_hash) {
+        entry.modified = modified;
+        entry.len = len;
+        write_entry(&entry_path, &entry);
+        return Ok(entry.sections(path));
+    }
+
+    let sections = markwaydown::parse_text(text.as_ref().map(|s| s.as_ref()))?;
+    write_entry(
+        &entry_path,
+        &Entry {
+            parser: parser_hash(),
+            modified,
+            len,
+            hash: content_hash,
+            sections: sections.iter().map(CachedSection::new).collect(),
+        },
+    );
+    Ok(sections)
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    /// [`parser_hash`] when the entry was written.
+    parser: u64,
+    modified: Option<SystemTime>,
+    len: u64,
+
+    /// Hash of the file's contents.
+    hash: u64,
+
+    sections: Vec<CachedSection>,
+}
+
+/// A [`Section`] with its spans reduced to byte ranges within the file.
+#[derive(Serialize, Deserialize)]
+struct CachedSection {
+    level: usize,
+    title: CachedText,
+    text: CachedText,
+    tables: Vec<CachedTable>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedTable {
+    bytes: (usize, usize),
+    header: Vec<CachedText>,
+    rows: Vec<Vec<CachedText>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedText {
+    content: String,
+    bytes: (usize, usize),
+}
+
+impl Entry {
+    fn sections(&self, path: &Path) -> Vec<Section> {
+        self.sections.iter().map(|s| s.section(path)).collect()
+    }
+}
+
+impl CachedSection {
+    fn new(section: &Section) -> Self {
+        CachedSection {
+            level: section.level,
+            title: CachedText::new(&section.title),
+            text: CachedText::new(&section.text),
+            tables: section
+                .tables
+                .iter()
+                .map(|table| CachedTable {
+                    bytes: (table.span.bytes.start, table.span.bytes.end),
+                    header: table.header.iter().map(CachedText::new).collect(),
+                    rows: table
+                        .rows
+                        .iter()
+                        .map(|row| row.iter().map(CachedText::new).collect())
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+
+    fn section(&self, path: &Path) -> Section {
+        Section {
+            level: self.level,
+            title: self.title.spanned(path),
+            text: self.text.spanned(path),
+            tables: self
+                .tables
+                .iter()
+                .map(|table| {
+                    Spanned::new(
+                        Table {
+                            header: table.header.iter().map(|c| c.spanned(path)).collect(),
+                            rows: table
+                                .rows
+                                .iter()
+                                .map(|row| row.iter().map(|c| c.spanned(path)).collect())
+                                .collect(),
+                        },
+                        span(path, table.bytes),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+impl CachedText {
+    fn new(text: &Spanned<String>) -> Self {
+        CachedText {
+            content: text.content.clone(),
+            bytes: (text.span.bytes.start, text.span.bytes.end),
+        }
+    }
+
+    fn spanned(&self, path: &Path) -> Spanned<String> {
+        Spanned::new(self.content.clone(), span(path, self.bytes))
+    }
+}
+
+fn span(path: &Path, (start, end): (usize, usize)) -> Span {
+    Span {
+        file: path.display().to_string().into(),
+        bytes: start..end,
+    }
+}
+
+fn read_entry(entry_path: &Path) -> Option<Entry> {
+    let json = std::fs::read_to_string(entry_path).ok()?;
+    serde_json::from_str(&json).ok()
+}
+
+/// Write the entry to a temporary file first, so concurrent readers (e.g., `cargo rpg check`
+/// running during `mdbook serve`) never see half an entry.
+fn write_entry(entry_path: &Path, entry: &Entry) {
+    let Some(dir) = entry_path.parent() else {
+        return;
+    };
+    let Ok(json) = serde_json::to_string(entry) else {
+        return;
+    };
+    let tmp_path = entry_path.with_extension(format!(
+        "{}-{:?}.tmp",
+        std::process::id(),
+        std::thread::current().id()
+    ));
+    let written = std::fs::create_dir_all(dir)
+        .and_then(|()| std::fs::write(&tmp_path, json))
+        .and_then(|()| std::fs::rename(&tmp_path, entry_path));
+    if written.is_err() {
+        let _ = std::fs::remove_file(&tmp_path);
+    }
+}
+
+/// Identifies the parser and the entry format: the crate version and the source of the
+/// modules that produce and store [`Section`]s.
+fn parser_hash() -> u64 {
+    hash(&(
+        env!("CARGO_PKG_VERSION"),
+        include_str!("markwaydown.rs"),
+        include_str!("parse_cache.rs"),
+    ))
+}
+
+/// A hash that is the same in every process (unlike the randomly seeded one of `HashMap`).
+/// It may change between Rust releases, which merely empties the cache.
+fn hash(value: &impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GOAL: &str = "\
+# Title
+
+| Metadata | |
+| --- | --- |
+| Status | Proposed |
+
+## Summary
+
+Text.
+";
+
+    /// The contents and byte ranges of `sections`, which is all the cache preserves.
+    fn contents(sections: &[Section]) -> String {
+        let text = |t: &Spanned<String>| format!("{:?} {:?}", t.content, t.span.bytes);
+        let mut output = String::new();
+        for section in sections {
+            output.push_str(&format!(
+                "{} {} {}\n",
+                section.level,
+                text(&section.title),
+                text(&section.text)
+            ));
+            for table in &section.tables {
+                output.push_str(&format!("{:?}\n", table.span.bytes));
+                for row in std::iter::once(&table.header).chain(&table.rows) {
+                    for cell in row {
+                        output.push_str(&text(cell));
+                    }
+                    output.push('\n');
+                }
+            }
+        }
+        output
+    }
+
+    #[test]
+    fn cached_sections_match_parsed_sections() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_dir = dir.path().join("cache");
+        let path = dir.path().join("goal.md");
+        std::fs::write(&path, GOAL).unwrap();
+
+        let parsed = parse_in(&cache_dir, &path).unwrap();
+        assert_eq!(std::fs::read_dir(&cache_dir).unwrap().count(), 1);
+
+        let cached = parse_in(&cache_dir, &path).unwrap();
+        assert_eq!(contents(&parsed), contents(&cached));
+        assert!(contents(&cached).contains("\"Proposed\""));
+    }
+
+    #[test]
+    fn changed_file_is_reparsed() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_dir = dir.path().join("cache");
+        let path = dir.path().join("goal.md");
+        std::fs::write(&path, GOAL).unwrap();
+        parse_in(&cache_dir, &path).unwrap();
+
+        std::fs::write(&path, GOAL.replace("Proposed", "Not accepted")).unwrap();
+        let sections = parse_in(&cache_dir, &path).unwrap();
+        assert_eq!(sections[0].tables[0].rows[0][1].content, "Not accepted");
+    }
+}
@@ -39,21 +39,6 @@ lazy_static! {
         Regex::new(r"\(\(\(GOALS NOT ACCEPTED\)\)\)").unwrap();
 }
 
-// List of large goals (goals with at least one Large team ask)
-lazy_static! {
-    pub static ref LARGE_GOALS_LIST: Regex = Regex::new(r"\(\(\(LARGE GOALS\)\)\)").unwrap();
-}
-
-// List of medium goals (goals with at least one Medium ask, no Large asks)
-lazy_static! {
-    pub static ref MEDIUM_GOALS_LIST: Regex = Regex::new(r"\(\(\(MEDIUM GOALS\)\)\)").unwrap();
-}
-
-// List of small goals (goals with only Small asks)
-lazy_static! {
-    pub static ref SMALL_GOALS_LIST: Regex = Regex::new(r"\(\(\(SMALL GOALS\)\)\)").unwrap();
-}
-
 // Marker to create goal subchapters without rendering a table
 lazy_static! {
     pub static ref GOAL_CHAPTERS: Regex = Regex::new(r"\(\(\(GOAL CHAPTERS\)\)\)").unwrap();
@@ -141,6 +126,27 @@ lazy_static! {
     pub static ref FUNDING_LEGEND: Regex = Regex::new(r"\(\(\(FUNDING LEGEND\)\)\)").unwrap();
 }
 
+// Sponsor acknowledgements across all milestones
+lazy_static! {
+    pub static ref SPONSORS: Regex = Regex::new(r"\(\(\(SPONSORS\)\)\)").unwrap();
+}
+
+// Contributor opportunities, optionally filtered (e.g. `level=beginner`)
+lazy_static! {
+    pub static ref HELP_WANTED_LIST: Regex =
+        Regex::new(r"\(\(\(HELP WANTED(?::\s*(.+?))?\s*\)\)\)").unwrap();
+}
+
+// Status updates of the milestone's goals, with options (e.g. `group=champion, team=lang`)
+lazy_static! {
+    pub static ref UPDATES: Regex = Regex::new(r"\(\(\(UPDATES(?::\s*(.+?))?\s*\)\)\)").unwrap();
+}
+
+// Filterable, sortable explorer of the milestone's goals
+lazy_static! {
+    pub static ref GOAL_EXPLORER: Regex = Regex::new(r"\(\(\(GOAL EXPLORER\)\)\)").unwrap();
+}
+
 // Roadmap goal rows (no headers) filtered by roadmap name.
 // Used inside manually authored markdown tables: `| (((ROADMAP ROWS: Theme))) |`
 lazy_static! {
@@ -148,6 +154,18 @@ lazy_static! {
         Regex::new(r"\|\s*\(\(\(ROADMAP ROWS:\s*(.+?)\s*\)\)\)\s*\|").unwrap();
 }
 
+// Mermaid Gantt chart of goals (and subgoals) filtered by roadmap name
+lazy_static! {
+    pub static ref ROADMAP_GANTT_FILTERED: Regex =
+        Regex::new(r"\(\(\(ROADMAP GANTT:\s*(.+?)\s*\)\)\)").unwrap();
+}
+
+// Mermaid graph of dependencies between goals, optionally filtered by roadmap theme
+lazy_static! {
+    pub static ref DEPENDENCY_GRAPH: Regex =
+        Regex::new(r"\(\(\(DEPENDENCY GRAPH(?::\s*(.+?))?\s*\)\)\)").unwrap();
+}
+
 lazy_static! {
     /// GitHub username.
     ///
@@ -202,6 +220,13 @@ lazy_static! {
             .unwrap();
 }
 
+// List of the goals whose largest team ask is at a support level, e.g. `(((LARGE GOALS)))`
+// for `Large`. The `level` is checked against the configured support levels by the caller.
+lazy_static! {
+    pub static ref SIZED_GOALS_LIST: Regex =
+        Regex::new(r"\(\(\((?P<level>[^()]+?) GOALS\)\)\)").unwrap();
+}
+
 /// True if the entire string `s` matches `re`
 pub fn is_just(re: &Regex, s: &str) -> bool {
     let output = re.replace(s, "X");
@@ -215,9 +240,40 @@ lazy_static! {
             .unwrap();
 }
 
+// Time investment of a help wanted item, e.g. `2 weeks` or `1-3 months part-time`
+lazy_static! {
+    pub static ref TIME_INVESTMENT: Regex = Regex::new(
+        r"^(?i:~|about\s+)?(?P<min>\d+)(?:\s*(?:-|–|to)\s*(?P<max>\d+))?\s*(?P<unit>(?i:hours?|days?|weeks?|months?|years?))\b(?P<rest>.*)$"
+    )
+    .unwrap();
+}
+
 /// If a comment begins with this text, it will be considered a summary.
 pub const TLDR: &str = "TL;DR:";
 
+lazy_static! {
+    /// A line of an update comment that starts a structured item, like `Blocked on: ...`,
+    /// `- Next: ...`, or `**Decision needed:** ...`
+    pub static ref UPDATE_ITEM: Regex = Regex::new(
+        r"^[*-]?\s*(?:\*\*)?(?P<kind>(?i:blocked on|next steps?|next|decisions? needed|shipped))(?::\*\*|\*\*:|:)\s*(?P<text>.*)$"
+    )
+    .unwrap();
+}
+
+lazy_static! {
+    /// A heading of an update comment whose contents are structured items, like `## Blockers`
+    pub static ref UPDATE_ITEM_HEADING: Regex = Regex::new(
+        r"^#{1,6}\s+(?P<kind>(?i:blocked on|blockers|next steps?|next|decisions? needed|shipped))\s*:?\s*$"
+    )
+    .unwrap();
+}
+
+lazy_static! {
+    /// A markdown list item, like `- text` or `1. text`
+    pub static ref LIST_ITEM: Regex =
+        Regex::new(r"^\s*(?:[*+-]|\d+[.)])\s+(?P<text>.*)$").unwrap();
+}
+
 lazy_static! {
     /// Metadata table rows like `[lang] champion` indicate the champion for the lang team
     pub static ref CHAMPION_METADATA: Regex =
@@ -232,6 +288,20 @@ lazy_static! {
             .unwrap();
 }
 
+lazy_static! {
+    /// Matches a markdown link like `[link text](url)` and captures the link target.
+    pub static ref MARKDOWN_LINK_TARGET: Regex =
+        Regex::new(r"^\[[^\]]+\]\((?P<target>[^)]+)\)$")
+            .unwrap();
+}
+
+lazy_static! {
+    /// Matches a reference-style markdown link like `[link text][ref]` or `[link text]`.
+    pub static ref MARKDOWN_REFERENCE_LINK: Regex =
+        Regex::new(r"^\[(?P<text>[^\]]+)\](?:\[[^\]]*\])?$")
+            .unwrap();
+}
+
 /// If `s` is a markdown link like `[text](url)`, return the link text.
 /// Otherwise return the original string (trimmed).
 pub fn strip_markdown_link(s: &str) -> &str {
@@ -258,6 +328,18 @@ lazy_static! {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_sized_goals_list_regex() {
+        let caps = SIZED_GOALS_LIST.captures("(((LARGE GOALS)))").unwrap();
+        assert_eq!(&caps["level"], "LARGE");
+
+        let caps = SIZED_GOALS_LIST.captures("(((SMALL GOALS)))").unwrap();
+        assert_eq!(&caps["level"], "SMALL");
+
+        assert!(!SIZED_GOALS_LIST.is_match("(((GOALS)))"));
+        assert!(!SIZED_GOALS_LIST.is_match("(((ROADMAP GOALS: Foo)))"));
+    }
+
     #[test]
     fn test_reports_regex_start_and_end_date() {
         assert!(REPORTS.is_match("(((REPORTS)))"));
@@ -277,4 +359,29 @@ mod tests {
         let caps = REPORTS.captures("(((REPORTS: 2025-09-01)))").unwrap();
         assert_eq!(caps.get(1).unwrap().as_str(), "2025-09-01");
     }
+
+    #[test]
+    fn test_updates_regex() {
+        let caps = UPDATES.captures("(((UPDATES)))").unwrap();
+        assert!(caps.get(1).is_none());
+
+        let caps = UPDATES
+            .captures("(((UPDATES: group=champion, team=lang|compiler )))")
+            .unwrap();
+        assert_eq!(
+            caps.get(1).unwrap().as_str(),
+            "group=champion, team=lang|compiler"
+        );
+    }
+
+    #[test]
+    fn test_dependency_graph_regex() {
+        let caps = DEPENDENCY_GRAPH.captures("(((DEPENDENCY GRAPH)))").unwrap();
+        assert!(caps.get(1).is_none());
+
+        let caps = DEPENDENCY_GRAPH
+            .captures("(((DEPENDENCY GRAPH: Just add async)))")
+            .unwrap();
+        assert_eq!(caps.get(1).unwrap().as_str(), "Just add async");
+    }
 }
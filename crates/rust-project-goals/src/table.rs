@@ -0,0 +1,492 @@
+//! Tables of goals, teams, champions, funding, ... that are built once and rendered as
+//! markdown (for the book), HTML, or CSV (for spreadsheets).
+
+use std::fmt::Write;
+use std::path::Path;
+
+use pulldown_cmark::{BrokenLink, CowStr, Event, Options, Parser, Tag, TagEnd};
+use spanned::Spanned;
+
+use crate::{badge, config::Configuration, util};
+
+/// A table with headers, rows that may be split into labelled groups, and footnotes
+/// for text that is too long to show in a cell.
+#[derive(Clone, Debug, Default)]
+pub struct Table {
+    pub headers: Vec<Cell>,
+    pub groups: Vec<RowGroup>,
+    pub footnotes: Vec<Footnote>,
+
+    /// The column in which group labels are shown in markdown and CSV.
+    pub group_column: usize,
+
+    /// The other cells of group label rows in markdown, empty if not given.
+    pub group_cells: Vec<Cell>,
+}
+
+/// A run of rows, under a label unless it is the first group of the table.
+#[derive(Clone, Debug, Default)]
+pub struct RowGroup {
+    pub label: Option<Cell>,
+    pub rows: Vec<Vec<Cell>>,
+}
+
+/// Long text from a cell, with a link to where it came from.
+#[derive(Clone, Debug)]
+pub struct Footnote {
+    pub text: String,
+    pub link: String,
+}
+
+/// The content of a table cell. Markdown is inline markdown, as found in goal documents.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Cell {
+    #[default]
+    Empty,
+    Text(String),
+    Markdown(String),
+    Link {
+        text: String,
+        url: String,
+    },
+    /// A configured badge, by name (see [`crate::badge`]).
+    Badge(String),
+    /// Raw HTML, with a plain text version for CSV.
+    Html {
+        html: String,
+        text: String,
+    },
+    /// A reference to a footnote of the table, starting from 1.
+    Footnote(usize),
+    Strong(Box<Cell>),
+    Emphasis(Box<Cell>),
+    /// Several cells shown next to each other.
+    Many(Vec<Cell>),
+}
+
+impl Table {
+    /// Maximum text length before [`Table::footnote`] moves it to a footnote.
+    const MAX_INLINE_LEN: usize = 22;
+
+    pub fn new(headers: impl IntoIterator<Item = impl Into<Cell>>) -> Self {
+        Self {
+            headers: headers.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    pub fn columns(&self) -> usize {
+        self.headers.len()
+    }
+
+    /// True if the table has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.groups.iter().all(|g| g.rows.is_empty())
+    }
+
+    /// Add a row to the current group.
+    pub fn push_row(&mut self, row: Vec<Cell>) {
+        assert_eq!(
+            row.len(),
+            self.columns(),
+            "row has the wrong number of columns"
+        );
+        if self.groups.is_empty() {
+            self.groups.push(RowGroup::default());
+        }
+        self.groups.last_mut().unwrap().rows.push(row);
+    }
+
+    /// Start a new group of rows labelled `label`.
+    pub fn push_group(&mut self, label: impl Into<Cell>) {
+        self.groups.push(RowGroup {
+            label: Some(label.into()),
+            rows: vec![],
+        });
+    }
+
+    /// If the markdown `text` is short enough, returns it as a cell. Otherwise, stores it as
+    /// a footnote linking to `link` and returns a reference like `\*1`.
+    pub fn footnote(&mut self, text: &str, link: &Path) -> Cell {
+        if text.len() > Self::MAX_INLINE_LEN {
+            self.footnotes.push(Footnote {
+                text: text.to_string(),
+                link: link.display().to_string(),
+            });
+            Cell::Footnote(self.footnotes.len())
+        } else {
+            Cell::markdown(text)
+        }
+    }
+
+    /// Render as a markdown table with aligned columns, followed by the footnotes.
+    pub fn to_markdown(&self) -> String {
+        let mut rows = vec![self.headers.iter().map(Cell::to_markdown).collect()];
+        for group in &self.groups {
+            if let Some(label) = &group.label {
+                let mut row: Vec<String> = if self.group_cells.is_empty() {
+                    vec![String::new(); self.columns()]
+                } else {
+                    self.group_cells.iter().map(Cell::to_markdown).collect()
+                };
+                row[self.group_column] = label.clone().strong().to_markdown();
+                rows.push(row);
+            }
+            rows.extend(
+                group
+                    .rows
+                    .iter()
+                    .map(|row| row.iter().map(Cell::to_markdown).collect()),
+            );
+        }
+        let rows: Vec<Vec<Spanned<String>>> = rows
+            .into_iter()
+            .map(|row: Vec<String>| row.into_iter().map(Spanned::here).collect())
+            .collect();
+
+        let mut output = util::format_table(&rows);
+        for (footnote, index) in self.footnotes.iter().zip(1..) {
+            write!(
+                output,
+                "\n\n\\*{index}: {} ([from here]({}))\n",
+                footnote.text, footnote.link
+            )
+            .unwrap();
+        }
+        output
+    }
+
+    /// Render as an HTML table, followed by the footnotes.
+    pub fn to_html(&self) -> String {
+        let mut output = String::from("<table>\n<thead>\n<tr>");
+        for header in &self.headers {
+            write!(output, "<th>{}</th>", header.to_html()).unwrap();
+        }
+        output.push_str("</tr>\n</thead>\n<tbody>\n");
+        for group in &self.groups {
+            if let Some(label) = &group.label {
+                writeln!(
+                    output,
+                    "<tr><th colspan=\"{}\">{}</th></tr>",
+                    self.columns(),
+                    label.to_html()
+                )
+                .unwrap();
+            }
+            for row in &group.rows {
+                output.push_str("<tr>");
+                for cell in row {
+                    write!(output, "<td>{}</td>", cell.to_html()).unwrap();
+                }
+                output.push_str("</tr>\n");
+            }
+        }
+        output.push_str("</tbody>\n</table>\n");
+        for (footnote, index) in self.footnotes.iter().zip(1..) {
+            writeln!(
+                output,
+                "<p>*{index}: {} (<a href=\"{}\">from here</a>)</p>",
+                inline_html(&footnote.text),
+                escape(&footnote.link)
+            )
+            .unwrap();
+        }
+        output
+    }
+
+    /// Render as CSV, one line per row and group label. Footnotes are written out in
+    /// their cells, as spreadsheets have no room for them below the table.
+    pub fn to_csv(&self) -> String {
+        let mut output = String::new();
+        let mut write_row = |cells: &mut dyn Iterator<Item = String>| {
+            let row: Vec<String> = cells.map(|s| csv_cell(&s)).collect();
+            writeln!(output, "{}", row.join(",")).unwrap();
+        };
+
+        write_row(&mut self.headers.iter().map(|c| c.to_text(self)));
+        for group in &self.groups {
+            if let Some(label) = &group.label {
+                let mut row = vec![String::new(); self.columns()];
+                row[self.group_column] = label.to_text(self);
+                write_row(&mut row.into_iter());
+            }
+            for row in &group.rows {
+                write_row(&mut row.iter().map(|c| c.to_text(self)));
+            }
+        }
+        output
+    }
+}
+
+impl Cell {
+    pub fn text(text: impl Into<String>) -> Self {
+        Cell::Text(text.into())
+    }
+
+    pub fn markdown(text: impl Into<String>) -> Self {
+        Cell::Markdown(text.into())
+    }
+
+    pub fn link(text: impl Into<String>, url: impl Into<String>) -> Self {
+        Cell::Link {
+            text: text.into(),
+            url: url.into(),
+        }
+    }
+
+    /// The configured badge `name`, or just its name if there is no such badge.
+    pub fn badge(name: impl Into<String>) -> Self {
+        let name = name.into();
+        if Configuration::get()
+            .badges
+            .keys()
+            .any(|n| n.eq_ignore_ascii_case(&name))
+        {
+            Cell::Badge(name)
+        } else {
+            Cell::Text(name)
+        }
+    }
+
+    pub fn strong(self) -> Self {
+        Cell::Strong(Box::new(self))
+    }
+
+    pub fn emphasis(self) -> Self {
+        Cell::Emphasis(Box::new(self))
+    }
+
+    /// This cell followed by `other`.
+    pub fn and(self, other: Cell) -> Self {
+        match self {
+            Cell::Empty => other,
+            cell if other == Cell::Empty => cell,
+            Cell::Many(mut cells) => {
+                cells.push(other);
+                Cell::Many(cells)
+            }
+            cell => Cell::Many(vec![cell, other]),
+        }
+    }
+
+    pub fn to_markdown(&self) -> String {
+        match self {
+            Cell::Empty => String::new(),
+            Cell::Text(text) | Cell::Markdown(text) => text.replace('|', "\\|"),
+            Cell::Link { text, url } => format!("[{}]({url})", text.replace('|', "\\|")),
+            Cell::Badge(name) => format!("![{name}][]"),
+            Cell::Html { html, .. } => html.clone(),
+            Cell::Footnote(index) => format!("\\*{index}"),
+            Cell::Strong(cell) => format!("**{}**", cell.to_markdown()),
+            Cell::Emphasis(cell) => format!("*{}*", cell.to_markdown()),
+            Cell::Many(cells) => join(cells, Cell::to_markdown),
+        }
+    }
+
+    pub fn to_html(&self) -> String {
+        match self {
+            Cell::Empty => String::new(),
+            Cell::Text(text) => escape(text),
+            Cell::Markdown(text) => inline_html(text),
+            Cell::Link { text, url } => {
+                format!("<a href=\"{}\">{}</a>", escape(url), inline_html(text))
+            }
+            Cell::Badge(name) => badge::html(name),
+            Cell::Html { html, .. } => html.clone(),
+            Cell::Footnote(index) => format!("<sup>*{index}</sup>"),
+            Cell::Strong(cell) => format!("<strong>{}</strong>", cell.to_html()),
+            Cell::Emphasis(cell) => format!("<em>{}</em>", cell.to_html()),
+            Cell::Many(cells) => join(cells, Cell::to_html),
+        }
+    }
+
+    /// The cell as plain text, with footnotes of `table` written out.
+    pub fn to_text(&self, table: &Table) -> String {
+        match self {
+            Cell::Empty => String::new(),
+            Cell::Text(text) => text.clone(),
+            Cell::Markdown(text) | Cell::Link { text, .. } => markdown_to_text(text),
+            Cell::Badge(name) => name.clone(),
+            Cell::Html { text, .. } => text.clone(),
+            Cell::Footnote(index) => markdown_to_text(&table.footnotes[index - 1].text),
+            Cell::Strong(cell) | Cell::Emphasis(cell) => cell.to_text(table),
+            Cell::Many(cells) => join(cells, |c| c.to_text(table)),
+        }
+    }
+}
+
+impl From<&str> for Cell {
+    fn from(text: &str) -> Self {
+        Cell::text(text)
+    }
+}
+
+impl From<String> for Cell {
+    fn from(text: String) -> Self {
+        Cell::Text(text)
+    }
+}
+
+fn join(cells: &[Cell], op: impl Fn(&Cell) -> String) -> String {
+    cells.iter().map(op).collect::<Vec<_>>().join(" ")
+}
+
+/// Parse inline markdown. Reference links like `[Help wanted][]` are resolved later by
+/// mdbook, so they are resolved here to the configured links and badges.
+fn parse_markdown(text: &str) -> impl Iterator<Item = Event<'_>> {
+    let resolve = |link: BrokenLink<'_>| {
+        let config = Configuration::get();
+        let name = link.reference.to_string();
+        let url = match config
+            .markdown
+            .links
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(&name))
+        {
+            Some((_, url)) => url.clone(),
+            None => match config
+                .badges
+                .iter()
+                .find(|(n, _)| n.eq_ignore_ascii_case(&name))
+            {
+                Some((n, details)) => badge::uri(n, details, &config.badge_style),
+                None => String::new(),
+            },
+        };
+        Some((CowStr::from(url), CowStr::from("")))
+    };
+    Parser::new_with_broken_link_callback(text, Options::empty(), Some(resolve))
+}
+
+/// Render inline markdown as HTML, without the paragraph the parser puts around it.
+fn inline_html(text: &str) -> String {
+    let mut output = String::new();
+    let events = parse_markdown(text).filter(|event| {
+        !matches!(
+            event,
+            Event::Start(Tag::Paragraph) | Event::End(TagEnd::Paragraph)
+        )
+    });
+    pulldown_cmark::html::push_html(&mut output, events);
+    output
+}
+
+/// The text of inline markdown, without formatting, links, or HTML tags.
+fn markdown_to_text(text: &str) -> String {
+    let mut output = String::new();
+    for event in parse_markdown(text) {
+        match event {
+            Event::Text(text) | Event::Code(text) => output.push_str(&text),
+            Event::SoftBreak | Event::HardBreak => output.push(' '),
+            _ => {}
+        }
+    }
+    output
+}
+
+/// A quoted CSV cell, with quotes doubled as in RFC 4180.
+fn csv_cell(text: &str) -> String {
+    format!("\"{}\"", text.replace('"', "\"\""))
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> Table {
+        let mut table = Table::new(["Goal", "Level", "Notes"]);
+        table.push_row(vec![
+            Cell::link("`Foo`", "foo.md"),
+            "Large".into(),
+            Cell::markdown("*soon*"),
+        ]);
+        table.push_group(Cell::link("Bar", "bar.md"));
+        let notes = table.footnote("Needs reviews of a rather large PR", Path::new("bar.md"));
+        table.push_row(vec![
+            Cell::Empty,
+            "Small".into(),
+            Cell::text("✅").and(notes),
+        ]);
+        table
+    }
+
+    #[test]
+    fn markdown() {
+        assert_eq!(
+            table().to_markdown(),
+            "\
+| Goal              | Level | Notes   |
+| :--               | :--   | :--     |
+| [`Foo`](foo.md)   | Large | *soon*  |
+| **[Bar](bar.md)** |       |         |
+|                   | Small | ✅ \\*1   |
+
+
+\\*1: Needs reviews of a rather large PR ([from here](bar.md))
+"
+        );
+    }
+
+    #[test]
+    fn html() {
+        assert_eq!(
+            table().to_html(),
+            "\
+<table>
+<thead>
+<tr><th>Goal</th><th>Level</th><th>Notes</th></tr>
+</thead>
+<tbody>
+<tr><td><a href=\"foo.md\"><code>Foo</code></a></td><td>Large</td><td><em>soon</em></td></tr>
+<tr><th colspan=\"3\"><a href=\"bar.md\">Bar</a></th></tr>
+<tr><td></td><td>Small</td><td>✅ <sup>*1</sup></td></tr>
+</tbody>
+</table>
+<p>*1: Needs reviews of a rather large PR (<a href=\"bar.md\">from here</a>)</p>
+"
+        );
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(
+            table().to_csv(),
+            "\
+\"Goal\",\"Level\",\"Notes\"
+\"Foo\",\"Large\",\"soon\"
+\"Bar\",\"\",\"\"
+\"\",\"Small\",\"✅ Needs reviews of a rather large PR\"
+"
+        );
+    }
+
+    #[test]
+    fn csv_quotes() {
+        let mut table = Table::new(["Goal"]);
+        table.push_row(vec![Cell::text("The \"best\" goal,\nreally")]);
+        assert_eq!(
+            table.to_csv(),
+            "\"Goal\"\n\"The \"\"best\"\" goal,\nreally\"\n"
+        );
+    }
+
+    #[test]
+    fn markdown_group_cells() {
+        let mut table = table();
+        table.group_cells = vec![Cell::Empty, "-".into(), "--".into()];
+        assert!(
+            table
+                .to_markdown()
+                .contains("| **[Bar](bar.md)** | -     | --      |"),
+            "{}",
+            table.to_markdown()
+        );
+    }
+}
@@ -81,6 +81,18 @@ pub fn get_team_name(team_name: &str) -> Result<Option<&'static TeamName>> {
 }
 
 impl TeamName {
+    /// The name of the team, like `"compiler"`. Unlike [`TeamName::data`], this doesn't
+    /// need the team data.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// A team name for tests, without fetching the teams.
+    #[cfg(any(test, feature = "testing"))]
+    pub fn for_test(name: &str) -> &'static TeamName {
+        Box::leak(Box::new(TeamName(name.to_string())))
+    }
+
     /// Get the data for this team.
     pub fn data(&self) -> &'static v1::Team {
         get_teams().unwrap().get(self).unwrap()
@@ -0,0 +1,228 @@
+use chrono::NaiveDate;
+use std::fmt::Display;
+
+/// A period of time at the granularity the goal author wrote it,
+/// e.g. `2026`, `2026H1`, `2026Q3`, or `2026-03-15`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Period {
+    Year(i32),
+
+    /// A half-year (1 or 2), as used by milestones like `2025h2`.
+    Half(i32, u32),
+
+    /// A quarter (1 through 4).
+    Quarter(i32, u32),
+
+    Date(NaiveDate),
+}
+
+impl Period {
+    /// Parse a period like `2026`, `2026h1`, `2026 H2`, `2026q3`, `2026-Q3`, or `2026-03-15`.
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+
+        if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+            return Some(Period::Date(date));
+        }
+
+        let lower = s.to_lowercase();
+        let (year, rest) = lower.split_at(
+            lower
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(lower.len()),
+        );
+        if year.len() != 4 {
+            return None;
+        }
+        let year: i32 = year.parse().ok()?;
+        let rest = rest.trim_start_matches([' ', '-']);
+
+        if rest.is_empty() {
+            return Some(Period::Year(year));
+        }
+
+        let mut chars = rest.chars();
+        let kind = chars.next()?;
+        let n: u32 = chars.as_str().parse().ok()?;
+        match kind {
+            'h' if (1..=2).contains(&n) => Some(Period::Half(year, n)),
+            'q' if (1..=4).contains(&n) => Some(Period::Quarter(year, n)),
+            _ => None,
+        }
+    }
+
+    /// The first day of this period.
+    pub fn first_day(&self) -> NaiveDate {
+        match *self {
+            Period::Year(year) => NaiveDate::from_ymd_opt(year, 1, 1).unwrap(),
+            Period::Half(year, half) => {
+                NaiveDate::from_ymd_opt(year, (half - 1) * 6 + 1, 1).unwrap()
+            }
+            Period::Quarter(year, quarter) => {
+                NaiveDate::from_ymd_opt(year, (quarter - 1) * 3 + 1, 1).unwrap()
+            }
+            Period::Date(date) => date,
+        }
+    }
+
+    /// The first day *after* this period.
+    pub fn end_exclusive(&self) -> NaiveDate {
+        let (year, months) = match *self {
+            Period::Year(year) => (year, 12),
+            Period::Half(year, half) => (year, half * 6),
+            Period::Quarter(year, quarter) => (year, quarter * 3),
+            Period::Date(date) => return date.succ_opt().unwrap(),
+        };
+        if months == 12 {
+            NaiveDate::from_ymd_opt(year + 1, 1, 1).unwrap()
+        } else {
+            NaiveDate::from_ymd_opt(year, months + 1, 1).unwrap()
+        }
+    }
+}
+
+impl Display for Period {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Period::Year(year) => write!(f, "{year}"),
+            Period::Half(year, half) => write!(f, "{year}H{half}"),
+            Period::Quarter(year, quarter) => write!(f, "{year}Q{quarter}"),
+            Period::Date(date) => write!(f, "{}", date.format("%Y-%m-%d")),
+        }
+    }
+}
+
+/// A structured `Timespan` metadata value: an inclusive range of periods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Timespan {
+    pub start: Period,
+    pub end: Period,
+}
+
+impl Timespan {
+    /// Parse a timespan like `2026`, `2026-2027`, `2026–2027`, `2026H2 to 2027Q1`,
+    /// or `2026-03-01..2026-06-30`. Returns `None` if the text is not understood
+    /// or if the end comes before the start.
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+
+        let (start, end) = if let Some(period) = Period::parse(s) {
+            (period, period)
+        } else {
+            let (start, end) = ["–", "—", "..", " to "]
+                .iter()
+                .find_map(|sep| s.split_once(sep))
+                .or_else(|| {
+                    // A plain hyphen is only a separator between non-date periods, e.g. `2026-2027`.
+                    s.match_indices('-')
+                        .map(|(i, _)| (&s[..i], &s[i + 1..]))
+                        .find(|(a, b)| Period::parse(a).is_some() && Period::parse(b).is_some())
+                })?;
+            (Period::parse(start)?, Period::parse(end)?)
+        };
+
+        if end.end_exclusive() <= start.first_day() {
+            return None;
+        }
+
+        Some(Timespan { start, end })
+    }
+
+    /// The timespan covered by a milestone directory like `2026` or `2025h2`.
+    pub fn from_milestone(milestone: &str) -> Option<Self> {
+        let period = Period::parse(milestone)?;
+        Some(Timespan {
+            start: period,
+            end: period,
+        })
+    }
+
+    /// The first day of this timespan.
+    pub fn first_day(&self) -> NaiveDate {
+        self.start.first_day()
+    }
+
+    /// The first day *after* this timespan.
+    pub fn end_exclusive(&self) -> NaiveDate {
+        self.end.end_exclusive()
+    }
+
+    /// True if `other` lies entirely within this timespan.
+    pub fn contains(&self, other: &Timespan) -> bool {
+        self.first_day() <= other.first_day() && other.end_exclusive() <= self.end_exclusive()
+    }
+
+    /// True if this timespan and `other` share at least one day.
+    pub fn overlaps(&self, other: &Timespan) -> bool {
+        self.first_day() < other.end_exclusive() && other.first_day() < self.end_exclusive()
+    }
+}
+
+impl Display for Timespan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.start == self.end {
+            write!(f, "{}", self.start)
+        } else {
+            write!(f, "{}–{}", self.start, self.end)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_periods() {
+        assert_eq!(Period::parse("2026"), Some(Period::Year(2026)));
+        assert_eq!(Period::parse("2025h2"), Some(Period::Half(2025, 2)));
+        assert_eq!(Period::parse("2026 H1"), Some(Period::Half(2026, 1)));
+        assert_eq!(Period::parse("2026-Q3"), Some(Period::Quarter(2026, 3)));
+        assert_eq!(
+            Period::parse("2026-03-15"),
+            Some(Period::Date(NaiveDate::from_ymd_opt(2026, 3, 15).unwrap()))
+        );
+        assert_eq!(Period::parse("2026h3"), None);
+        assert_eq!(Period::parse("soon"), None);
+    }
+
+    #[test]
+    fn test_parse_timespans() {
+        let span = Timespan::parse("2026-2027").unwrap();
+        assert_eq!(span.start, Period::Year(2026));
+        assert_eq!(span.end, Period::Year(2027));
+        assert_eq!(Timespan::parse("2026–2027"), Some(span));
+        assert_eq!(span.to_string(), "2026–2027");
+
+        let span = Timespan::parse("2026H2 to 2027Q1").unwrap();
+        assert_eq!(
+            span.first_day(),
+            NaiveDate::from_ymd_opt(2026, 7, 1).unwrap()
+        );
+        assert_eq!(
+            span.end_exclusive(),
+            NaiveDate::from_ymd_opt(2027, 4, 1).unwrap()
+        );
+
+        let span = Timespan::parse("2026-03-01..2026-06-30").unwrap();
+        assert_eq!(
+            span.end_exclusive(),
+            NaiveDate::from_ymd_opt(2026, 7, 1).unwrap()
+        );
+
+        assert_eq!(Timespan::parse("2027-2026"), None);
+    }
+
+    #[test]
+    fn test_contains_and_overlaps() {
+        let milestone = Timespan::from_milestone("2026").unwrap();
+        let multi_year = Timespan::parse("2026-2028").unwrap();
+        let half = Timespan::parse("2026h2").unwrap();
+        let later = Timespan::parse("2027").unwrap();
+
+        assert!(multi_year.contains(&half));
+        assert!(!half.contains(&multi_year));
+        assert!(milestone.overlaps(&multi_year));
+        assert!(!milestone.overlaps(&later));
+    }
+}
@@ -0,0 +1,203 @@
+//! Structured items in the update comments of tracking issues: what a goal is blocked on,
+//! its next steps, the decisions it needs from teams, and what shipped.
+
+use crate::re::{LIST_ITEM, UPDATE_ITEM, UPDATE_ITEM_HEADING};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UpdateItemKind {
+    /// `Blocked on:`, or a `Blockers` heading
+    Blocker,
+
+    /// `Next:` or `Next steps:`
+    NextStep,
+
+    /// `Decision needed:`
+    DecisionNeeded,
+
+    /// `Shipped:`
+    Shipped,
+}
+
+impl UpdateItemKind {
+    fn parse(label: &str) -> Option<Self> {
+        match label.to_lowercase().as_str() {
+            "blocked on" | "blockers" => Some(UpdateItemKind::Blocker),
+            "next" | "next step" | "next steps" => Some(UpdateItemKind::NextStep),
+            "decision needed" | "decisions needed" => Some(UpdateItemKind::DecisionNeeded),
+            "shipped" => Some(UpdateItemKind::Shipped),
+            _ => None,
+        }
+    }
+}
+
+/// Extract the structured items from an update comment. An item is either
+///
+/// * a line starting with a prefix like `Blocked on:` (possibly in bold or in a list),
+///   along with the following lines up to the next blank line, or
+/// * a list item or paragraph under a heading like `## Blockers` (up to the next heading),
+///   or under a prefix with no text of its own (up to the next blank line).
+pub fn update_items(body: &str) -> Vec<(UpdateItemKind, String)> {
+    let mut items: Vec<(UpdateItemKind, String)> = vec![];
+
+    // Kind of the items in the current section, and whether it ends at the next blank line
+    let mut section: Option<(UpdateItemKind, bool)> = None;
+
+    // Whether the next line continues the last item
+    let mut continues = false;
+
+    let mut in_code_block = false;
+
+    for line in body.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+        }
+
+        if line.trim().is_empty() {
+            continues = false;
+            if let Some((_, true)) = section {
+                section = None;
+            }
+            continue;
+        }
+
+        if !in_code_block && line.starts_with('#') {
+            section = UPDATE_ITEM_HEADING
+                .captures(line)
+                .and_then(|c| UpdateItemKind::parse(&c["kind"]))
+                .map(|kind| (kind, false));
+            continues = false;
+            continue;
+        }
+
+        if !in_code_block {
+            if let Some(c) = UPDATE_ITEM.captures(line) {
+                if let Some(kind) = UpdateItemKind::parse(&c["kind"]) {
+                    let text = c["text"].trim();
+                    if text.is_empty() {
+                        section = Some((kind, true));
+                        continues = false;
+                    } else {
+                        items.push((kind, text.to_string()));
+                        continues = true;
+                    }
+                    continue;
+                }
+            }
+        }
+
+        if let Some((kind, _)) = section {
+            if !in_code_block {
+                if let Some(c) = LIST_ITEM.captures(line) {
+                    // Nested list items continue their parent
+                    if !(continues && line.starts_with(char::is_whitespace)) {
+                        items.push((kind, c["text"].trim().to_string()));
+                        continues = true;
+                        continue;
+                    }
+                }
+            }
+            if !continues {
+                items.push((kind, line.trim().to_string()));
+                continues = true;
+                continue;
+            }
+        }
+
+        if continues {
+            let (_, text) = items.last_mut().unwrap();
+            text.push('\n');
+            text.push_str(line.trim());
+        }
+    }
+
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use UpdateItemKind::*;
+
+    #[test]
+    fn prefixes() {
+        let body = "\
+Some progress this month.
+
+Blocked on: the new trait solver
+landing in nightly.
+- **Next:** write the RFC
+**Decision needed:** whether to stabilize `foo`
+
+Shipped: `cargo foo` in 1.90
+";
+        assert_eq!(
+            update_items(body),
+            vec![
+                (
+                    Blocker,
+                    "the new trait solver\nlanding in nightly.".to_string()
+                ),
+                (NextStep, "write the RFC".to_string()),
+                (DecisionNeeded, "whether to stabilize `foo`".to_string()),
+                (Shipped, "`cargo foo` in 1.90".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn headings_and_lists() {
+        let body = "\
+## Blockers
+
+- review of #123
+- a decision on the syntax
+  for closures
+
+### Next steps
+
+Implement the lint.
+
+## Other notes
+
+- not an item
+
+Shipped:
+1. the parser
+2. the docs
+
+- not an item either
+";
+        assert_eq!(
+            update_items(body),
+            vec![
+                (Blocker, "review of #123".to_string()),
+                (
+                    Blocker,
+                    "a decision on the syntax\nfor closures".to_string()
+                ),
+                (NextStep, "Implement the lint.".to_string()),
+                (Shipped, "the parser".to_string()),
+                (Shipped, "the docs".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn code_blocks_are_not_items() {
+        let body = "\
+## Next
+
+```sh
+# Next: not a heading
+cargo build
+```
+";
+        assert_eq!(
+            update_items(body),
+            vec![(
+                NextStep,
+                "```sh\n# Next: not a heading\ncargo build\n```".to_string()
+            )]
+        );
+    }
+}
@@ -7,17 +7,22 @@ use std::{
 use spanned::{Result, Spanned};
 use walkdir::WalkDir;
 
+use crate::table::Cell;
+
 pub const ARROW: &str = "↳";
 
 pub const MILESTONE_REGEX: &'static str = r"^\d{4}([hH][12])?$";
 
-/// Format a goal title cell for use in markdown tables.
+/// Where the goals book is published.
+pub const BOOK_URL: &str = "https://rust-lang.github.io/goals";
+
+/// Format a goal title cell for use in tables.
 /// If `subgoal_title` is Some, renders as `↳ subgoal`, otherwise as `[title](link)`.
-pub fn goal_title_cell(title: &str, link: &Path, subgoal_title: Option<&str>) -> String {
+pub fn goal_title_cell(title: &str, link: &Path, subgoal_title: Option<&str>) -> Cell {
     if let Some(subgoal) = subgoal_title {
-        format!("{} {}", ARROW, subgoal)
+        Cell::markdown(format!("{} {}", ARROW, subgoal))
     } else {
-        format!("[{}]({})", title, link.display())
+        Cell::link(title, link.display().to_string())
     }
 }
 
@@ -136,6 +141,24 @@ pub fn comma(s: &BTreeSet<String>) -> String {
     s.iter().map(|s| &s[..]).collect::<Vec<_>>().join(",")
 }
 
+/// Lowercase, dash-separated version of `s`, used to give things stable IDs.
+pub fn slug(s: &str) -> String {
+    s.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// The root of the book that contains `path`: the closest of `path` and its ancestors with a
+/// `book.toml`. A relative `path` is resolved against the current directory.
+pub fn book_root(path: &Path) -> Option<PathBuf> {
+    let path = std::env::current_dir().ok()?.join(path);
+    path.ancestors()
+        .find(|dir| dir.join("book.toml").is_file())
+        .map(Path::to_path_buf)
+}
+
 /// Runs `op` in another thread. Useful for making blocking calls to `request`
 /// without making tokio upset.
 pub fn in_thread<R>(op: impl FnOnce() -> R + Send) -> R
@@ -145,45 +168,22 @@ where
     std::thread::scope(|scope| scope.spawn(|| op()).join().unwrap())
 }
 
-/// Tracks footnotes for long text in markdown tables.
-///
-/// When a text value is too long to display inline in a table cell,
-/// it is replaced with a numbered reference like `\*1` and the full
-/// text is accumulated for rendering after the table.
-pub struct Footnotes {
-    entries: Vec<String>,
-}
-
-impl Footnotes {
-    /// Maximum text length before converting to a footnote reference.
-    const MAX_INLINE_LEN: usize = 22;
-
-    pub fn new() -> Self {
-        Self {
-            entries: Vec::new(),
-        }
-    }
-
-    /// If `text` is short enough, returns it as-is. Otherwise, stores a footnote
-    /// and returns a reference like `\*1`.
-    pub fn maybe_footnote(&mut self, text: &str, link: &Path) -> String {
-        if text.len() > Self::MAX_INLINE_LEN {
-            let index = self.entries.len() + 1;
-            self.entries.push(format!(
-                "\\*{index}: {text} ([from here]({link}))",
-                link = link.display()
-            ));
-            format!("\\*{index}")
-        } else {
-            text.to_string()
-        }
-    }
-
-    /// Write all accumulated footnotes to the output.
-    pub fn write_to(&self, output: &mut String) -> std::fmt::Result {
-        for footnote in &self.entries {
-            write!(output, "\n\n{}\n", footnote)?;
-        }
-        Ok(())
-    }
+/// Applies `op` to each of `items` on a few scoped threads, returning the results in order.
+pub fn par_map<T, R>(items: &[T], op: impl Fn(&T) -> R + Sync) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+{
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk_size = items.len().div_ceil(threads).max(1);
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = items
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(|| chunk.iter().map(&op).collect::<Vec<_>>()))
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    })
 }
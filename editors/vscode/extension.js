@@ -0,0 +1,40 @@
+// Starts the goal document language server (`crates/rust-project-goals-lsp`)
+// for the markdown files in the repository.
+
+const path = require("path");
+const vscode = require("vscode");
+const { LanguageClient } = require("vscode-languageclient/node");
+
+let client;
+
+async function activate() {
+  const [config] = await vscode.workspace.findFiles("rust-project-goals.toml", null, 1);
+  if (!config) {
+    return;
+  }
+
+  // `cargo run` needs to find the workspace.
+  const cwd = path.dirname(config.fsPath);
+  const serverPath = vscode.workspace.getConfiguration("rustProjectGoals").get("serverPath");
+  const server = serverPath
+    ? { command: serverPath, options: { cwd } }
+    : {
+        command: "cargo",
+        args: ["run", "-q", "--bin", "rust-project-goals-lsp"],
+        options: { cwd },
+      };
+
+  client = new LanguageClient(
+    "rustProjectGoals",
+    "Rust project goals",
+    { run: server, debug: server },
+    { documentSelector: [{ scheme: "file", language: "markdown" }] },
+  );
+  await client.start();
+}
+
+function deactivate() {
+  return client && client.stop();
+}
+
+module.exports = { activate, deactivate };